@@ -1,7 +1,8 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use runtime_units::units::{CubeRootScaledLengthUnit, LengthUnit};
 use runtime_units::{Length, CubeRootScaledLength};
-use runtime_units::traits::FixedQuantity;
+use runtime_units::traits::{FixedQuantity, Unit};
+use runtime_units::{slice, Units};
 fn runtime_units_benchmarks(c: &mut Criterion) {
     c.bench_function("try_convert_bench_standard", |b| {
         b.iter(|| {
@@ -48,6 +49,34 @@ fn runtime_units_benchmarks_rational_same_type(c: &mut Criterion) {
         })
     });
 }
+fn runtime_units_benchmarks_slices(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert_slice");
+    for size in [1_000, 1_000_000] {
+        let input: Vec<f64> = (0..size).map(|i| i as f64).collect();
+        let mut output = vec![0.0; size];
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("in_place", size), &input, |b, input| {
+            let mut values = input.clone();
+            b.iter(|| slice::convert_slice_in_place(&mut values, Units::Length(LengthUnit::foot), Units::Length(LengthUnit::meter)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("into", size), &input, |b, input| {
+            b.iter(|| slice::convert_slice_into(input, &mut output, LengthUnit::foot, LengthUnit::meter).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("unit_in_place", size), &input, |b, input| {
+            let mut values = input.clone();
+            b.iter(|| LengthUnit::foot.convert_slice_in_place(&mut values, LengthUnit::meter).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("strided_in_place", size), &input, |b, input| {
+            let mut values = input.clone();
+            b.iter(|| slice::convert_strided_in_place(&mut values, 0, 4, LengthUnit::foot, LengthUnit::meter).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("length_vec", size), &input, |b, input| {
+            b.iter(|| runtime_units::LengthVec::new(input.clone(), LengthUnit::foot).to_meter())
+        });
+    }
+    group.finish();
+}
 criterion_group!(try_convert_bench_standard, runtime_units_benchmarks, runtime_units_benchmarks_same_type);
 criterion_group!(try_convert_bench_rational, runtime_units_benchmarks_rational, runtime_units_benchmarks_rational_same_type);
-criterion_main!(try_convert_bench_rational, try_convert_bench_standard);
\ No newline at end of file
+criterion_group!(convert_slice_bench, runtime_units_benchmarks_slices);
+criterion_main!(try_convert_bench_rational, try_convert_bench_standard, convert_slice_bench);
\ No newline at end of file
@@ -0,0 +1,152 @@
+//! Dimensional analysis of sets of units, based on the matrix of the powers of their base dimensions.
+//!
+//! [`dimensionless_groups`] computes the null space of this matrix, i.e. the Buckingham-Pi groups of a set of variables
+//! (e.g. the Reynolds number for a density, velocity, length and dynamic viscosity). [`solve`] finds the exponents that
+//! express a target dimension from a set of units (e.g. force and area yield a pressure with exponents `[1, -1]`).
+//! Only the dimensions of the units are considered: multipliers, offsets and kinds are ignored.
+use num_rational::Ratio;
+
+use crate::errors::RuntimeUnitError;
+use crate::units_base::{BaseDimension, UnitBase, UnitDefinition};
+
+/// Exact rational exponent of a unit.
+pub type Exponent = Ratio<i64>;
+
+/// Powers of a base unit as an exact rational.
+fn power(base: UnitBase, dimension: BaseDimension) -> Exponent
+{
+    let power = base.power(dimension);
+    Exponent::new(power.numerator() as i64, power.denominator() as i64)
+}
+
+/// Matrix of the powers of each base dimension (rows) of each unit (columns), followed by the `target` column if any.
+fn exponent_matrix(units: &[UnitDefinition], target: Option<UnitBase>) -> Vec<Vec<Exponent>>
+{
+    BaseDimension::ALL.iter().map(|&dimension|
+    {
+        units.iter().map(|unit| power(unit.base, dimension)).chain(target.map(|target| power(target, dimension))).collect()
+    }).collect()
+}
+
+/// Reduce a `matrix` to its reduced row echelon form, considering the first `columns` columns for pivots. Returns the
+/// pivot column of each non-zero row.
+fn row_reduce(matrix: &mut [Vec<Exponent>], columns: usize) -> Vec<usize>
+{
+    let mut pivots = Vec::new();
+    for column in 0..columns
+    {
+        let row = pivots.len();
+        let Some(pivot) = (row..matrix.len()).find(|&r| matrix[r][column] != Exponent::from_integer(0)) else { continue };
+        matrix.swap(row, pivot);
+        let scale = matrix[row][column];
+        matrix[row].iter_mut().for_each(|value| *value /= scale);
+        for other in 0..matrix.len()
+        {
+            let factor = matrix[other][column];
+            if other != row && factor != Exponent::from_integer(0)
+            {
+                for c in 0..matrix[row].len()
+                {
+                    let value = matrix[row][c];
+                    matrix[other][c] -= factor * value;
+                }
+            }
+        }
+        pivots.push(column);
+    }
+    pivots
+}
+
+fn gcd(a: i64, b: i64) -> i64
+{
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+/// Scale a vector of rational exponents to the smallest equivalent integers, with a positive first non-zero exponent 
+/// (e.g. `[-1/2, 1]` to `[1, -2]`).
+fn to_integers(exponents: &[Exponent]) -> Vec<i64>
+{
+    let lcm = exponents.iter().fold(1, |lcm, exponent| lcm / gcd(lcm, *exponent.denom()) * exponent.denom());
+    let integers: Vec<i64> = exponents.iter().map(|exponent| (exponent * lcm).to_integer()).collect();
+    let mut divisor = integers.iter().fold(0, |divisor, &value| gcd(divisor, value)).max(1);
+    if integers.iter().find(|&&value| value != 0).is_some_and(|value| value.is_negative())
+    {
+        divisor = -divisor;
+    }
+    integers.iter().map(|value| value / divisor).collect()
+}
+
+///
+/// Number of independent dimensions spanned by a set of `units` (the rank of their exponent matrix).
+///
+pub fn rank(units: &[UnitDefinition]) -> usize
+{
+    row_reduce(&mut exponent_matrix(units, None), units.len()).len()
+}
+
+///
+/// Compute the dimensionless groups (Buckingham-Pi groups) of a set of `units`. Each group holds the integer exponent of
+/// each unit, in order, such that the product of the units raised to these exponents is dimensionless. Exponents are the
+/// smallest such integers, with a positive first non-zero exponent. The number of groups is the number of units less the
+/// rank of their exponent matrix.
+///
+/// For instance, the units of a density, velocity, length and dynamic viscosity yield the single group `[1, 1, 1, -1]`,
+/// the Reynolds number.
+///
+pub fn dimensionless_groups(units: &[UnitDefinition]) -> Vec<Vec<i64>>
+{
+    let mut matrix = exponent_matrix(units, None);
+    let pivots = row_reduce(&mut matrix, units.len());
+    (0..units.len()).filter(|column| !pivots.contains(column)).map(|free|
+    {
+        let mut group = vec![Exponent::from_integer(0); units.len()];
+        group[free] = Exponent::from_integer(1);
+        for (row, &pivot) in pivots.iter().enumerate()
+        {
+            group[pivot] = -matrix[row][free];
+        }
+        to_integers(&group)
+    }).collect()
+}
+
+///
+/// Solve for the exponents of a set of `units` whose product has the dimensions of `target` (e.g. the units of a force
+/// and an area yield `[1, -1]` for a pressure). Where several solutions exist, the exponents of the units not needed
+/// are zero; other solutions differ by the `dimensionless_groups` of the units. Fails with `IncompatibleDimensions` if
+/// no combination has the dimensions of `target`, from the dimensions of the partial solution.
+///
+pub fn solve(units: &[UnitDefinition], target: UnitBase) -> Result<Vec<Exponent>, RuntimeUnitError>
+{
+    let mut matrix = exponent_matrix(units, Some(target));
+    let pivots = row_reduce(&mut matrix, units.len());
+    let mut exponents = vec![Exponent::from_integer(0); units.len()];
+    for (row, &pivot) in pivots.iter().enumerate()
+    {
+        exponents[pivot] = matrix[row][units.len()];
+    }
+    if matrix.iter().skip(pivots.len()).any(|row| row[units.len()] != Exponent::from_integer(0))
+    {
+        return Err(RuntimeUnitError::IncompatibleDimensions { from: combine(units, &exponents)?.base, to: target });
+    }
+    Ok(exponents)
+}
+
+///
+/// Compute the product of `units` raised to the given `exponents` (e.g. the result of `solve` or `dimensionless_groups`).
+///
+pub fn combine(units: &[UnitDefinition], exponents: &[Exponent]) -> Result<UnitDefinition, RuntimeUnitError>
+{
+    crate::slice::check_lengths(exponents.len(), units.len())?;
+    units.iter().zip(exponents).try_fold(UnitDefinition::dimensionless(), |product, (unit, exponent)|
+    {
+        let factor = if exponent.is_integer()
+        {
+            unit.checked_powi(i8::try_from(exponent.to_integer()).map_err(|_| RuntimeUnitError::ExponentOverflow { numerator: *exponent.numer(), denominator: 1 })?)?
+        }
+        else
+        {
+            unit.checked_powf(*exponent.numer() as f64 / *exponent.denom() as f64)?
+        };
+        product.checked_mul(factor)
+    })
+}
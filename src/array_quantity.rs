@@ -1,4 +1,4 @@
-use crate::{errors::RuntimeUnitError, units_base::UnitDefinition};
+use crate::{errors::RuntimeUnitError, traits::{CheckedAdditive, Numeric}, units_base::UnitDefinition};
 use core::ops::{AddAssign, Div, DivAssign, Mul, MulAssign, SubAssign };
 use std::ops::{Deref, DerefMut};
 #[cfg(feature="serde")]
@@ -7,29 +7,29 @@ use serde_with::serde_as;
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc ="Data structure to hold a unit and array of data"]
-pub struct ArrayQuantity<const N: usize>
+pub struct ArrayQuantity<const N: usize, T = f64>
 {
     pub unit: UnitDefinition,
-    #[cfg_attr(feature="serde", serde_as(as = "[_; N]"))]
-    pub values: [f64; N]
+    #[cfg_attr(feature="serde", serde_as(as = "[_; N]"), serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+    pub values: [T; N]
 }
 
-impl<const N: usize> Deref for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> Deref for ArrayQuantity<N, T>
 {
-    type Target = [f64; N];
-    fn deref(&self) -> &[f64; N] {
+    type Target = [T; N];
+    fn deref(&self) -> &[T; N] {
         &self.values
     }        
 }
 
-impl<const N: usize> DerefMut for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> DerefMut for ArrayQuantity<N, T>
 {
-    fn deref_mut(&mut self) -> &mut [f64; N] {
+    fn deref_mut(&mut self) -> &mut [T; N] {
         &mut self.values
     }
 }   
 // Defining ArbitraryQuantity for our ArrayQuantity
-impl<const N: usize> crate::traits::ArbitraryQuantity for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> crate::traits::ArbitraryQuantity for ArrayQuantity<N, T>
 {
     fn unit(&self) -> UnitDefinition {
         self.unit
@@ -43,35 +43,22 @@ impl<const N: usize> crate::traits::ArbitraryQuantity for ArrayQuantity<N>
     }
 
     fn convert_mut(&mut self, unit: UnitDefinition) {
-        let factor = self.unit.convert_unchecked(unit);
-        for val in self.values.as_mut_slice().iter_mut()
-        {
-            *val *= factor;
-        }            
+        let (scale, shift) = self.unit.affine_conversion_unchecked(unit);
+        crate::slice::scale_in_place(&mut self.values, scale, shift);
+        self.unit = unit;
     }
     
     fn try_convert(&self, unit: UnitDefinition) -> Result<Self, crate::errors::RuntimeUnitError> {
-        if self.unit.base != unit.base
-        {
-            Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from base units of {} to {}", self.unit.unit_string(), unit.unit_string())))
-        }
-        else
-        {
-            Ok(self.convert(unit))
-        }
-
+        self.unit.try_convert(unit)?;
+        crate::slice::check_representable(&self.values, self.unit, unit)?;
+        Ok(self.convert(unit))
     }
     
     fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), crate::errors::RuntimeUnitError> {
-        if self.unit.base != unit.base
-        {
-            Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from base units of {} to {}", self.unit.unit_string(), unit.unit_string())))
-        }
-        else
-        {
-            self.convert_mut(unit);
-            Ok(())
-        }
+        self.unit.try_convert(unit)?;
+        crate::slice::check_representable(&self.values, self.unit, unit)?;
+        self.convert_mut(unit);
+        Ok(())
     }
     
     fn unit_mut(&mut self) -> &mut UnitDefinition {
@@ -82,24 +69,24 @@ impl<const N: usize> crate::traits::ArbitraryQuantity for ArrayQuantity<N>
 
 
 
-impl<const N: usize> Div<f64> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> Div<f64> for ArrayQuantity<N, T>
 {
-    type Output = ArrayQuantity<N>;
+    type Output = ArrayQuantity<N, T>;
 
     fn div(self, rhs: f64) -> Self::Output {
         let mut result = self;
         for val in result.values.as_mut_slice()
         {  
-           *val /= rhs;
+           *val = val.div_f64(rhs);
         }
         result
     }
 }
-impl<const N: usize> Div<ArrayQuantity<N>> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> Div<ArrayQuantity<N, T>> for ArrayQuantity<N, T>
 {
-    type Output = ArrayQuantity<N>;
+    type Output = ArrayQuantity<N, T>;
 
-    fn div(self, rhs: ArrayQuantity<N>) -> Self::Output {
+    fn div(self, rhs: ArrayQuantity<N, T>) -> Self::Output {
         let mut result = self;
         if rhs.values.len() != self.values.len()
         {
@@ -107,16 +94,16 @@ impl<const N: usize> Div<ArrayQuantity<N>> for ArrayQuantity<N>
         }
         for (val, &rhs) in result.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val /= rhs;
+           *val = *val / rhs;
         }
         result
     }
 }
-impl<const N: usize> Mul<ArrayQuantity<N>> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> Mul<ArrayQuantity<N, T>> for ArrayQuantity<N, T>
 {
-    type Output = ArrayQuantity<N>;
+    type Output = ArrayQuantity<N, T>;
 
-    fn mul(self, rhs: ArrayQuantity<N>) -> Self::Output {
+    fn mul(self, rhs: ArrayQuantity<N, T>) -> Self::Output {
         let mut result = self;
         if rhs.values.len() != self.values.len()
         {
@@ -124,86 +111,107 @@ impl<const N: usize> Mul<ArrayQuantity<N>> for ArrayQuantity<N>
         }
         for (val, &rhs) in result.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val *= rhs;
+           *val = *val * rhs;
         }
         result
     }
 }
-impl<const N: usize> Mul<f64> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> Mul<f64> for ArrayQuantity<N, T>
 {
-    type Output = ArrayQuantity<N>;
+    type Output = ArrayQuantity<N, T>;
 
     fn mul(self, rhs: f64) -> Self::Output {
         let mut result = self;
         for val in result.values.as_mut_slice()
         {  
-           *val *= rhs;
+           *val = val.mul_f64(rhs);
         }
         result
     }
 }
-impl<const N: usize> DivAssign<f64> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> DivAssign<f64> for ArrayQuantity<N, T>
 {
 
     fn div_assign(&mut self, rhs: f64) {        
         for val in self.values.as_mut_slice()
         {  
-           *val /= rhs;
+           *val = val.div_f64(rhs);
         }
     }
 }
-impl<const N: usize> MulAssign<f64> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> MulAssign<f64> for ArrayQuantity<N, T>
 {
 
     fn mul_assign(&mut self, rhs: f64) {        
         for val in self.values.as_mut_slice()
         {  
-           *val *= rhs;
+           *val = val.mul_f64(rhs);
         }
     }
 }
-impl<const N: usize> DivAssign<ArrayQuantity<N>> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> DivAssign<ArrayQuantity<N, T>> for ArrayQuantity<N, T>
 {
 
-    fn div_assign(&mut self, rhs: ArrayQuantity<N>) {            
+    fn div_assign(&mut self, rhs: ArrayQuantity<N, T>) {            
         if rhs.values.len() != self.values.len()
         {
             panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
         }
         for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val /= rhs;
+           *val = *val / rhs;
         }
     }
 }
-impl<const N: usize> AddAssign<ArrayQuantity<N>> for ArrayQuantity<N>
+impl<const N: usize, T: Numeric> CheckedAdditive for ArrayQuantity<N, T>
 {
+    fn checked_add(mut self, rhs: ArrayQuantity<N, T>) -> Result<Self, RuntimeUnitError> {
+        self.try_add_assign(rhs)?;
+        Ok(self)
+    }
 
-    fn add_assign(&mut self, rhs: ArrayQuantity<N>) {            
-        if rhs.values.len() != self.values.len()
-        {
-            panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-        }
-        let factor = rhs.unit.try_convert(self.unit).unwrap_or_else(|_| panic!("Addition failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
+    fn checked_sub(mut self, rhs: ArrayQuantity<N, T>) -> Result<Self, RuntimeUnitError> {
+        self.try_sub_assign(rhs)?;
+        Ok(self)
+    }
+
+    fn try_add_assign(&mut self, rhs: ArrayQuantity<N, T>) -> Result<(), RuntimeUnitError> {
+        crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
+        let factor = rhs.unit.try_convert(self.unit.interval())?;
         for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val += rhs*factor;
+           *val = *val + rhs.mul_f64(factor);
         }
+        Ok(())
     }
-}
-impl<const N: usize> SubAssign<ArrayQuantity<N>> for ArrayQuantity<N>
-{
 
-    fn sub_assign(&mut self, rhs: ArrayQuantity<N>) {            
-        if rhs.values.len() != self.values.len()
-        {
-            panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-        }
-        let factor = rhs.unit.try_convert(self.unit).unwrap_or_else(|_| panic!("Subtraction failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
+    fn try_sub_assign(&mut self, rhs: ArrayQuantity<N, T>) -> Result<(), RuntimeUnitError> {
+        crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
+        // The difference of two absolute quantities (e.g. temperatures) is relative.
+        let (target, unit) = if rhs.unit.is_absolute() { (self.unit, self.unit.interval()) } else { (self.unit.interval(), self.unit) };
+        rhs.unit.try_convert(target)?;
+        let (scale, shift) = rhs.unit.affine_conversion_unchecked(target);
         for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val -= rhs*factor;
+           *val = *val - rhs.scale(scale, shift);
         }
+        self.unit = unit;
+        Ok(())
+    }
+}
+
+impl<const N: usize, T: Numeric> AddAssign<ArrayQuantity<N, T>> for ArrayQuantity<N, T>
+{
+    /// Panics on incompatible units or lengths, see [`CheckedAdditive::try_add_assign`].
+    fn add_assign(&mut self, rhs: ArrayQuantity<N, T>) {            
+        self.try_add_assign(rhs).unwrap_or_else(|error| panic!("{error}"));
+    }
+}
+impl<const N: usize, T: Numeric> SubAssign<ArrayQuantity<N, T>> for ArrayQuantity<N, T>
+{
+    /// Panics on incompatible units or lengths, see [`CheckedAdditive::try_sub_assign`].
+    fn sub_assign(&mut self, rhs: ArrayQuantity<N, T>) {            
+        self.try_sub_assign(rhs).unwrap_or_else(|error| panic!("{error}"));
     }
 }
 
@@ -0,0 +1,175 @@
+//! Conversion of quantities to and from Apache Arrow arrays (with the `arrow` feature), with units stored in the metadata
+//! of their fields.
+//!
+//! The unit of a field is stored under [`UNIT_METADATA_KEY`]. Units of a `QuantitiesVec` are stored as their canonical name
+//! (e.g. `TemperatureInterval::degree_celsius`, see [`Units::canonical_name`]), so that reading the field recovers the exact
+//! `Units` variant. Units of a `VecQuantity` are stored by canonical name if they match a named unit exactly, or as a unit
+//! expression of the multiplier and base units otherwise (e.g. `1000 kg*m^2*s^-3`), which does not keep offsets or kinds.
+//!
+//! Fields are nullable: `NaN` values are written as nulls, and nulls are read as `NaN`. Tables are converted to and from a
+//! `RecordBatch`, which can be exchanged through Arrow IPC or Parquet.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::types::{Float32Type, Float64Type};
+use arrow_array::{Array, ArrayRef, ArrowPrimitiveType, Float32Array, Float64Array, PrimitiveArray, RecordBatch, RecordBatchOptions};
+use arrow_schema::{Field, Schema};
+
+use crate::errors::RuntimeUnitError;
+use crate::table::QuantityTable;
+use crate::traits::Numeric;
+use crate::units_base::UnitDefinition;
+use crate::vector_quantity::VecQuantity;
+use crate::{QuantitiesVec, Units};
+
+/// Key of the unit in the metadata of an Arrow field.
+pub const UNIT_METADATA_KEY: &str = "runtime_units.unit";
+
+/// Value types stored in Arrow arrays of floating point numbers.
+pub trait ArrowFloat: Numeric
+{
+    /// Arrow type of the values (e.g. `Float64Type`).
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+impl ArrowFloat for f32
+{
+    type ArrowType = Float32Type;
+}
+
+impl ArrowFloat for f64
+{
+    type ArrowType = Float64Type;
+}
+
+/// Canonical string of a `unit`: the canonical name of the matching named unit, or a unit expression otherwise.
+pub fn unit_to_metadata(unit: UnitDefinition) -> String
+{
+    match Units::find(unit, 0.0).filter(|&named| UnitDefinition::from(named) == unit)
+    {
+        Some(named) => named.canonical_name(),
+        None if unit.base == UnitDefinition::dimensionless().base => format!("{}", unit.multiplier),
+        None => format!("{} {}", unit.multiplier, unit.base),
+    }
+}
+
+/// Parse the canonical string of a unit, see [`unit_to_metadata`].
+pub fn unit_from_metadata(metadata: &str) -> Result<UnitDefinition, RuntimeUnitError>
+{
+    match Units::from_canonical_name(metadata)
+    {
+        Ok(unit) => Ok(unit.into()),
+        Err(_) => crate::parser::parse_unit(metadata),
+    }
+}
+
+/// Retrieve the unit stored in the metadata of a `field`.
+pub fn field_unit(field: &Field) -> Result<UnitDefinition, RuntimeUnitError>
+{
+    unit_from_metadata(field_metadata(field)?)
+}
+
+fn field_metadata(field: &Field) -> Result<&str, RuntimeUnitError>
+{
+    field.metadata().get(UNIT_METADATA_KEY).map(String::as_str)
+        .ok_or_else(|| RuntimeUnitError::MissingUnit { field: field.name().to_owned() })
+}
+
+/// Create a nullable field `name` with the given `unit` metadata, see [`array`].
+fn field<T: ArrowFloat>(name: &str, unit: String) -> Field
+{
+    Field::new(name, T::ArrowType::DATA_TYPE, true).with_metadata(HashMap::from([(UNIT_METADATA_KEY.to_owned(), unit)]))
+}
+
+/// Create an array of `values`, with `NaN` written as null.
+fn array<T: ArrowFloat>(values: &[T]) -> PrimitiveArray<T::ArrowType>
+{
+    values.iter().map(|&value| (!value.to_f64().is_nan()).then_some(value)).collect()
+}
+
+/// Read the values of a `Float64Array` or `Float32Array`, with nulls read as `NaN`.
+fn values<T: Numeric>(field: &Field, array: &dyn Array) -> Result<Vec<T>, RuntimeUnitError>
+{
+    fn read<P: ArrowPrimitiveType, T: Numeric>(array: &PrimitiveArray<P>, to_f64: impl Fn(P::Native) -> f64) -> Vec<T>
+    {
+        (0..array.len()).map(|index| T::from_f64(if array.is_null(index) { f64::NAN } else { to_f64(array.value(index)) })).collect()
+    }
+    if let Some(array) = array.as_any().downcast_ref::<Float64Array>()
+    {
+        Ok(read(array, |value| value))
+    }
+    else if let Some(array) = array.as_any().downcast_ref::<Float32Array>()
+    {
+        Ok(read(array, f64::from))
+    }
+    else
+    {
+        Err(RuntimeUnitError::UnsupportedType { field: field.name().to_owned(), data_type: array.data_type().to_string() })
+    }
+}
+
+impl<T: ArrowFloat> VecQuantity<T>
+{
+    /// Convert to an Arrow array and a nullable field `name` holding the unit in its metadata, with `NaN` written as null
+    pub fn to_arrow(&self, name: &str) -> (Field, PrimitiveArray<T::ArrowType>)
+    {
+        (field::<T>(name, unit_to_metadata(self.unit)), array(&self.values))
+    }
+
+    /// Read an Arrow `Float64Array` or `Float32Array` with the unit stored in the metadata of its `field`
+    pub fn try_from_arrow(field: &Field, array: &dyn Array) -> Result<Self, RuntimeUnitError>
+    {
+        Ok(VecQuantity { unit: field_unit(field)?, values: values(field, array)? })
+    }
+}
+
+impl QuantitiesVec
+{
+    /// Convert to an Arrow `Float64Array` and a nullable field `name` holding the canonical name of the unit in its
+    /// metadata, with `NaN` written as null
+    pub fn to_arrow(&self, name: &str) -> (Field, Float64Array)
+    {
+        (field::<f64>(name, self.unit().canonical_name()), array(&self.values()))
+    }
+
+    ///
+    /// Read an Arrow `Float64Array` or `Float32Array` with the unit stored in the metadata of its `field`. Canonical names
+    /// recover the exact `Units` variant, and other units are converted as in [`VecQuantity::try_into_quantities_vec`].
+    ///
+    pub fn try_from_arrow(field: &Field, array: &dyn Array) -> Result<Self, RuntimeUnitError>
+    {
+        let values = values(field, array)?;
+        match Units::from_canonical_name(field_metadata(field)?)
+        {
+            Ok(unit) => Ok(QuantitiesVec::new(values, unit)),
+            Err(_) => VecQuantity { unit: field_unit(field)?, values }.try_into_quantities_vec(),
+        }
+    }
+}
+
+impl QuantityTable
+{
+    /// Convert to an Arrow `RecordBatch` of `Float64Array` columns, with units in the metadata of the fields
+    pub fn to_record_batch(&self) -> RecordBatch
+    {
+        let (fields, columns): (Vec<Field>, Vec<ArrayRef>) = self.names()
+            .map(|name|
+            {
+                let (field, array) = self.column(name).expect("Column names are in the table").to_arrow(name);
+                (field, Arc::new(array) as ArrayRef)
+            })
+            .unzip();
+        // The row count is given for tables without columns.
+        let options = RecordBatchOptions::new().with_row_count(Some(self.row_count()));
+        RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), columns, &options).expect("Columns of a table have the same number of rows and match their nullable fields")
+    }
+
+    /// Read an Arrow `RecordBatch`, where every field holds its unit in its metadata
+    pub fn try_from_record_batch(batch: &RecordBatch) -> Result<Self, RuntimeUnitError>
+    {
+        batch.schema().fields().iter().zip(batch.columns()).try_fold(QuantityTable::new(), |table, (field, array)|
+        {
+            table.with_column(field.name().as_str(), VecQuantity::<f64>::try_from_arrow(field, array.as_ref())?)
+        })
+    }
+}
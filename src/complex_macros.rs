@@ -0,0 +1,214 @@
+#[macro_export]
+macro_rules! create_complex_quantity {
+    // Scalar quantities of every kind are operands of complex quantities (e.g. a `ComplexQuantity` times a `Length`).
+    (@scalar $quantity:ident) =>
+    {
+        impl $crate::traits::IsComplexQuantity for $quantity
+        {
+            fn complex_value(&self) -> $crate::complex_quantity::Complex
+            {
+                self.value.into()
+            }
+
+            fn unit(&self) -> UnitDefinition
+            {
+                self.unit.into()
+            }
+        }
+    };
+    ($quantity:ident) =>
+    {
+        paste::paste!
+        {
+        use $crate::complex_quantity::{Complex, ComplexQuantity};
+        #[derive(Copy, Clone, Debug)]
+        #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+        #[doc = "Complex value (e.g. a phasor) and [`" [<$quantity Unit>]"`]."]
+        pub struct [<Complex $quantity>]
+        {
+            pub(crate) value: Complex,
+            pub(crate) unit: [<$quantity Unit>]
+        }
+
+        impl [<Complex $quantity>]
+        {
+            #[doc = "Create a new [`" [<Complex $quantity>]"`]."]
+            pub fn new(value: Complex, unit: [<$quantity Unit>]) -> Self
+            {
+                Self { value, unit }
+            }
+
+            #[doc = "Create a new [`" [<Complex $quantity>]"`] from its magnitude and phase."]
+            #[cfg(feature="Angle")]
+            pub fn from_polar(magnitude: $quantity, phase: $crate::Angle) -> Self
+            {
+                Self { value: ComplexQuantity::from_polar(magnitude, phase).value, unit: magnitude.unit }
+            }
+
+            #[doc = "Retrieve the complex value associated with this [`" [<Complex $quantity>]"`]."]
+            #[inline]
+            pub fn value(&self) -> Complex
+            {
+                self.value
+            }
+
+            #[doc = "Retrieve the unit associated with this [`" [<Complex $quantity>]"`]."]
+            #[inline]
+            pub fn unit(&self) -> [<$quantity Unit>]
+            {
+                self.unit
+            }
+
+            #[doc = "Real part of this [`" [<Complex $quantity>]"`]."]
+            #[inline]
+            pub fn real(&self) -> $quantity
+            {
+                $quantity::new(self.value.re, self.unit)
+            }
+
+            #[doc = "Imaginary part of this [`" [<Complex $quantity>]"`]."]
+            #[inline]
+            pub fn imag(&self) -> $quantity
+            {
+                $quantity::new(self.value.im, self.unit)
+            }
+
+            #[doc = "Magnitude of this [`" [<Complex $quantity>]"`]."]
+            #[inline]
+            pub fn magnitude(&self) -> $quantity
+            {
+                $quantity::new(self.value.norm(), self.unit)
+            }
+
+            #[doc = "Phase of this [`" [<Complex $quantity>]"`], in radians."]
+            #[cfg(feature="Angle")]
+            #[inline]
+            pub fn phase(&self) -> $crate::Angle
+            {
+                $crate::Angle::radian(self.value.arg())
+            }
+
+            #[doc = "Complex conjugate of this [`" [<Complex $quantity>]"`]."]
+            #[inline]
+            pub fn conj(&self) -> Self
+            {
+                Self { value: self.value.conj(), unit: self.unit }
+            }
+
+            #[doc = "Convert to another [`" [<$quantity Unit>]"`] (offsets of absolute units are not applied)."]
+            pub fn convert(&self, unit: [<$quantity Unit>]) -> Self
+            {
+                Self { value: self.value * UnitDefinition::from(self.unit).convert_unchecked(unit.into()), unit }
+            }
+        }
+
+        impl From<[<Complex $quantity>]> for ComplexQuantity
+        {
+            fn from(quantity: [<Complex $quantity>]) -> Self
+            {
+                ComplexQuantity::new(quantity.value, quantity.unit.into())
+            }
+        }
+
+        impl TryFrom<ComplexQuantity> for [<Complex $quantity>]
+        {
+            type Error = RuntimeUnitError;
+            fn try_from(quantity: ComplexQuantity) -> Result<Self, Self::Error>
+            {
+                Ok(Self { value: quantity.value, unit: [<$quantity Unit>]::try_from(quantity.unit)? })
+            }
+        }
+
+        impl From<$quantity> for [<Complex $quantity>]
+        {
+            fn from(quantity: $quantity) -> Self
+            {
+                Self { value: quantity.value.into(), unit: quantity.unit }
+            }
+        }
+
+        impl $crate::traits::IsComplexQuantity for [<Complex $quantity>]
+        {
+            fn complex_value(&self) -> Complex
+            {
+                self.value
+            }
+
+            fn unit(&self) -> UnitDefinition
+            {
+                self.unit.into()
+            }
+        }
+
+        impl core::ops::Add for [<Complex $quantity>]
+        {
+            type Output = [<Complex $quantity>];
+
+            /// Panics on absolute units (e.g. degrees Celsius).
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { value: (ComplexQuantity::from(self) + ComplexQuantity::from(rhs)).value, unit: self.unit }
+            }
+        }
+
+        impl core::ops::Sub for [<Complex $quantity>]
+        {
+            type Output = [<Complex $quantity>];
+
+            /// Panics on absolute units (e.g. degrees Celsius).
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { value: (ComplexQuantity::from(self) - ComplexQuantity::from(rhs)).value, unit: self.unit }
+            }
+        }
+
+        impl core::ops::Mul<f64> for [<Complex $quantity>]
+        {
+            type Output = [<Complex $quantity>];
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                Self { value: self.value * rhs, unit: self.unit }
+            }
+        }
+
+        impl core::ops::Div<f64> for [<Complex $quantity>]
+        {
+            type Output = [<Complex $quantity>];
+
+            fn div(self, rhs: f64) -> Self::Output {
+                Self { value: self.value / rhs, unit: self.unit }
+            }
+        }
+
+        impl<Q: $crate::traits::IsComplexQuantity> core::ops::Mul<Q> for [<Complex $quantity>]
+        {
+            type Output = ComplexQuantity;
+
+            fn mul(self, rhs: Q) -> Self::Output {
+                ComplexQuantity::from(self) * rhs
+            }
+        }
+
+        impl<Q: $crate::traits::IsComplexQuantity> core::ops::Div<Q> for [<Complex $quantity>]
+        {
+            type Output = ComplexQuantity;
+
+            fn div(self, rhs: Q) -> Self::Output {
+                ComplexQuantity::from(self) / rhs
+            }
+        }
+
+        impl PartialEq for [<Complex $quantity>]
+        {
+            fn eq(&self, other: &Self) -> bool {
+                ComplexQuantity::from(*self) == ComplexQuantity::from(*other)
+            }
+        }
+
+        impl core::fmt::Display for [<Complex $quantity>]
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "({}) {}", self.value, self.unit.abbreviation())
+            }
+        }
+        }
+    }
+}
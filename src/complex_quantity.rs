@@ -0,0 +1,473 @@
+//! Complex-valued quantities, e.g. impedances, phasors or complex permittivities.
+//!
+//! `ComplexQuantity` holds a `Complex` value and a `UnitDefinition`, and electrical quantities and `Angle` have a typed
+//! variant holding their unit enumeration (e.g. `ComplexElectricalResistance`). Conversions scale both parts by the unit multipliers: offsets
+//! of absolute units (e.g. degrees Celsius) are not applied.
+use core::fmt::Display;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::errors::RuntimeUnitError;
+use crate::quantity::Quantity;
+use crate::traits::{ArbitraryQuantity, CheckedAdditive, IsComplexQuantity};
+use crate::units_base::UnitDefinition;
+
+#[doc = "A complex number with `f64` real and imaginary parts."]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Complex
+{
+    pub re: f64,
+    pub im: f64
+}
+
+impl Complex
+{
+    /// Imaginary unit
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    ///
+    /// Create a new `Complex` from its real and imaginary parts
+    ///
+    pub const fn new(re: f64, im: f64) -> Self
+    {
+        Self { re, im }
+    }
+
+    /// Create a new `Complex` from its magnitude and phase (in radians)
+    pub fn from_polar(magnitude: f64, phase: f64) -> Self
+    {
+        Self { re: magnitude * phase.cos(), im: magnitude * phase.sin() }
+    }
+
+    /// Magnitude (modulus)
+    #[inline]
+    pub fn norm(&self) -> f64
+    {
+        self.re.hypot(self.im)
+    }
+
+    /// Phase (argument) in radians, within -π..=π
+    #[inline]
+    pub fn arg(&self) -> f64
+    {
+        self.im.atan2(self.re)
+    }
+
+    /// Complex conjugate
+    #[inline]
+    pub fn conj(&self) -> Self
+    {
+        Self { re: self.re, im: -self.im }
+    }
+
+    /// Reciprocal
+    #[inline]
+    pub fn inv(&self) -> Self
+    {
+        let norm_sqr = self.re * self.re + self.im * self.im;
+        Self { re: self.re / norm_sqr, im: -self.im / norm_sqr }
+    }
+
+    /// Raise to an integer power
+    pub fn powi(&self, value: i32) -> Self
+    {
+        Self::from_polar(self.norm().powi(value), self.arg() * value as f64)
+    }
+
+    /// Raise to a floating point power (principal value)
+    pub fn powf(&self, value: f64) -> Self
+    {
+        Self::from_polar(self.norm().powf(value), self.arg() * value)
+    }
+}
+
+impl From<f64> for Complex
+{
+    fn from(re: f64) -> Self
+    {
+        Self { re, im: 0.0 }
+    }
+}
+
+impl Add for Complex
+{
+    type Output = Complex;
+
+    fn add(self, rhs: Complex) -> Self::Output {
+        Complex { re: self.re + rhs.re, im: self.im + rhs.im }
+    }
+}
+
+impl Sub for Complex
+{
+    type Output = Complex;
+
+    fn sub(self, rhs: Complex) -> Self::Output {
+        Complex { re: self.re - rhs.re, im: self.im - rhs.im }
+    }
+}
+
+impl Mul for Complex
+{
+    type Output = Complex;
+
+    fn mul(self, rhs: Complex) -> Self::Output {
+        Complex { re: self.re * rhs.re - self.im * rhs.im, im: self.re * rhs.im + self.im * rhs.re }
+    }
+}
+
+impl Div for Complex
+{
+    type Output = Complex;
+
+    fn div(self, rhs: Complex) -> Self::Output {
+        let norm_sqr = rhs.re * rhs.re + rhs.im * rhs.im;
+        Complex { re: (self.re * rhs.re + self.im * rhs.im) / norm_sqr, im: (self.im * rhs.re - self.re * rhs.im) / norm_sqr }
+    }
+}
+
+impl Mul<f64> for Complex
+{
+    type Output = Complex;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Complex { re: self.re * rhs, im: self.im * rhs }
+    }
+}
+
+impl Div<f64> for Complex
+{
+    type Output = Complex;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Complex { re: self.re / rhs, im: self.im / rhs }
+    }
+}
+
+impl Neg for Complex
+{
+    type Output = Complex;
+
+    fn neg(self) -> Self::Output {
+        Complex { re: -self.re, im: -self.im }
+    }
+}
+
+impl Display for Complex
+{
+    /// Displays e.g. `3+4i` or `3-4i`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        write!(f, "{}{}{}i", self.re, sign, self.im.abs())
+    }
+}
+
+#[doc = "A complex quantity of a unit, supports converting from one unit to another."]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug)]
+pub struct ComplexQuantity
+{
+    pub(crate) value: Complex,
+    pub(crate) unit: UnitDefinition
+}
+
+impl ComplexQuantity
+{
+    ///
+    /// Create a new instance of `ComplexQuantity` with a given `value` and `unit`
+    ///
+    pub fn new(value: Complex, unit: UnitDefinition) -> Self
+    {
+        Self { value, unit }
+    }
+
+    ///
+    /// Create a new instance of `ComplexQuantity` from its `magnitude` and `phase` (e.g. a phasor)
+    ///
+    #[cfg(feature="Angle")]
+    pub fn from_polar(magnitude: impl Into<Quantity>, phase: crate::Angle) -> Self
+    {
+        use crate::traits::FixedQuantity;
+        let magnitude = magnitude.into();
+        Self { value: Complex::from_polar(magnitude.value, phase.convert(crate::units::AngleUnit::radian).value()), unit: magnitude.unit }
+    }
+
+    /// Complex value in the units of this quantity
+    #[inline]
+    pub fn value(&self) -> Complex
+    {
+        self.value
+    }
+
+    /// Real part (e.g. the resistance of an impedance)
+    #[inline]
+    pub fn real(&self) -> Quantity
+    {
+        Quantity::new(self.value.re, self.unit)
+    }
+
+    /// Imaginary part (e.g. the reactance of an impedance)
+    #[inline]
+    pub fn imag(&self) -> Quantity
+    {
+        Quantity::new(self.value.im, self.unit)
+    }
+
+    /// Magnitude, in the units of this quantity
+    #[inline]
+    pub fn magnitude(&self) -> Quantity
+    {
+        Quantity::new(self.value.norm(), self.unit)
+    }
+
+    /// Phase, in radians
+    #[cfg(feature="Angle")]
+    #[inline]
+    pub fn phase(&self) -> crate::Angle
+    {
+        crate::Angle::radian(self.value.arg())
+    }
+
+    /// Complex conjugate
+    #[inline]
+    pub fn conj(&self) -> ComplexQuantity
+    {
+        Self { value: self.value.conj(), unit: self.unit }
+    }
+
+    /// Reciprocal (e.g. the admittance of an impedance)
+    #[inline]
+    pub fn inv(&self) -> ComplexQuantity
+    {
+        Self { value: self.value.inv(), unit: self.unit.inv() }
+    }
+
+    #[inline]
+    pub fn powi(&self, value: i8) -> ComplexQuantity
+    {
+        Self { value: self.value.powi(value as i32), unit: self.unit.powi(value) }
+    }
+
+    #[inline]
+    pub fn powf(&self, value: f64) -> ComplexQuantity
+    {
+        Self { value: self.value.powf(value), unit: self.unit.powf(value) }
+    }
+}
+
+impl From<Quantity> for ComplexQuantity
+{
+    /// A complex quantity without imaginary part.
+    fn from(quantity: Quantity) -> Self
+    {
+        Self { value: quantity.value.into(), unit: quantity.unit }
+    }
+}
+
+impl IsComplexQuantity for ComplexQuantity
+{
+    fn complex_value(&self) -> Complex
+    {
+        self.value
+    }
+
+    fn unit(&self) -> UnitDefinition
+    {
+        self.unit
+    }
+}
+
+impl IsComplexQuantity for Quantity
+{
+    fn complex_value(&self) -> Complex
+    {
+        self.value.into()
+    }
+
+    fn unit(&self) -> UnitDefinition
+    {
+        self.unit
+    }
+}
+
+impl ArbitraryQuantity for ComplexQuantity
+{
+    fn unit(&self) -> UnitDefinition {
+        self.unit
+    }
+
+    fn unit_mut(&mut self) -> &mut UnitDefinition {
+        &mut self.unit
+    }
+
+    fn try_convert(&self, unit: UnitDefinition) -> Result<Self, RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        Ok(self.convert(unit))
+    }
+
+    fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        self.convert_mut(unit);
+        Ok(())
+    }
+
+    fn convert(&self, unit: UnitDefinition) -> Self {
+        Self { value: self.value * self.unit.convert_unchecked(unit), unit }
+    }
+
+    fn convert_mut(&mut self, unit: UnitDefinition) {
+        *self = self.convert(unit);
+    }
+}
+
+impl CheckedAdditive for ComplexQuantity
+{
+    /// Add two complex quantities (result in the units of this quantity). Quantities of absolute units cannot be added.
+    fn checked_add(self, rhs: ComplexQuantity) -> Result<Self, RuntimeUnitError> {
+        let rhs = self.additive_operand(rhs)?;
+        Ok(Self { value: self.value + rhs, unit: self.unit })
+    }
+
+    /// Subtract two complex quantities (result in the units of this quantity). Quantities of absolute units cannot be subtracted.
+    fn checked_sub(self, rhs: ComplexQuantity) -> Result<Self, RuntimeUnitError> {
+        let rhs = self.additive_operand(rhs)?;
+        Ok(Self { value: self.value - rhs, unit: self.unit })
+    }
+
+    fn try_add_assign(&mut self, rhs: ComplexQuantity) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_add(rhs)?;
+        Ok(())
+    }
+
+    fn try_sub_assign(&mut self, rhs: ComplexQuantity) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_sub(rhs)?;
+        Ok(())
+    }
+}
+
+impl ComplexQuantity
+{
+    /// Value of `rhs` in the units of this quantity, for an addition or a subtraction.
+    fn additive_operand(&self, rhs: ComplexQuantity) -> Result<Complex, RuntimeUnitError>
+    {
+        Ok(rhs.value * self.unit.additive_factor(rhs.unit)?)
+    }
+}
+
+impl Add<ComplexQuantity> for ComplexQuantity
+{
+    type Output = ComplexQuantity;
+
+    /// Add two complex quantities, see [`CheckedAdditive::checked_add`]. Panics on incompatible units.
+    fn add(self, rhs: ComplexQuantity) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Sub<ComplexQuantity> for ComplexQuantity
+{
+    type Output = ComplexQuantity;
+
+    /// Subtract two complex quantities, see [`CheckedAdditive::checked_sub`]. Panics on incompatible units.
+    fn sub(self, rhs: ComplexQuantity) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl AddAssign for ComplexQuantity
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for ComplexQuantity
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Value measured from the zero of the scale of an absolute unit (e.g. 293.15 for 20 °C), for products and quotients.
+fn relative_value(quantity: &impl IsComplexQuantity) -> Complex
+{
+    quantity.complex_value() + Complex::from(quantity.unit().offset.unwrap_or_default())
+}
+
+impl<Q: IsComplexQuantity> Mul<Q> for ComplexQuantity
+{
+    type Output = ComplexQuantity;
+
+    fn mul(self, rhs: Q) -> Self::Output {
+        Self { value: relative_value(&self) * relative_value(&rhs), unit: self.unit * rhs.unit() }
+    }
+}
+
+impl<Q: IsComplexQuantity> Div<Q> for ComplexQuantity
+{
+    type Output = ComplexQuantity;
+
+    fn div(self, rhs: Q) -> Self::Output {
+        Self { value: relative_value(&self) / relative_value(&rhs), unit: self.unit / rhs.unit() }
+    }
+}
+
+impl MulAssign for ComplexQuantity
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for ComplexQuantity
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<f64> for ComplexQuantity
+{
+    type Output = ComplexQuantity;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self { value: self.value * rhs, unit: self.unit }
+    }
+}
+
+impl Div<f64> for ComplexQuantity
+{
+    type Output = ComplexQuantity;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self { value: self.value / rhs, unit: self.unit }
+    }
+}
+
+impl MulAssign<f64> for ComplexQuantity
+{
+    fn mul_assign(&mut self, rhs: f64) {
+        self.value = self.value * rhs;
+    }
+}
+
+impl DivAssign<f64> for ComplexQuantity
+{
+    fn div_assign(&mut self, rhs: f64) {
+        self.value = self.value / rhs;
+    }
+}
+
+impl PartialEq for ComplexQuantity
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.unit.is_convertible(other.unit) && self.convert(other.unit).value == other.value
+    }
+}
+
+impl Display for ComplexQuantity
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) {}", self.value, self.unit)
+    }
+}
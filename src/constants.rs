@@ -0,0 +1,200 @@
+//! Physical constants (CODATA 2022 recommended values), with their units and standard uncertainties.
+//!
+//! Each constant is available as a [`Constant`] holding its value and uncertainty in SI base units, as a generic
+//! `Quantity` (see [`Constant::quantity`]), and, where the matching quantity is enabled, as a typed quantity (e.g.
+//! [`speed_of_light`] as a `Velocity`). Constants can be looked up by name or symbol with [`find`], and appear in
+//! unit expressions parsed by [`crate::parser::parse_unit`] (e.g. `"kg*speed_of_light^2"`).
+use crate::quantity::Quantity;
+use crate::units_base::UnitDefinition;
+
+/// A physical constant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constant
+{
+    /// Name of the constant, used for lookups (e.g. `"speed_of_light"`).
+    pub name: &'static str,
+    /// Symbol of the constant, also used for lookups (e.g. `"c"`).
+    pub symbol: &'static str,
+    /// Value in SI base units.
+    pub value: f64,
+    /// Standard uncertainty of the value in SI base units, zero for exact constants.
+    pub uncertainty: f64,
+    /// Powers of the SI base units, in the order of `UnitDefinition::new` (m, kg, s, A, K, mol, cd).
+    pub dimensions: [i8; 7],
+}
+
+impl Constant
+{
+    /// SI base unit of this constant (e.g. m/s for the speed of light).
+    pub fn unit(&self) -> UnitDefinition
+    {
+        let [meter, kilogram, second, ampere, kelvin, mole, candela] = self.dimensions;
+        UnitDefinition::new(1.0, meter, kilogram, second, ampere, kelvin, mole, candela)
+    }
+
+    /// Value of this constant as a `Quantity` in SI base units.
+    pub fn quantity(&self) -> Quantity
+    {
+        Quantity::new(self.value, self.unit())
+    }
+
+    /// Unit whose multiplier is the value of this constant, used to include constants in unit expressions.
+    pub fn as_unit(&self) -> UnitDefinition
+    {
+        UnitDefinition { multiplier: self.value, ..self.unit() }
+    }
+
+    /// Check whether the value of this constant is exact (e.g. constants defining the SI units).
+    pub fn is_exact(&self) -> bool
+    {
+        self.uncertainty == 0.0
+    }
+
+    /// Standard uncertainty relative to the value.
+    pub fn relative_uncertainty(&self) -> f64
+    {
+        self.uncertainty / self.value.abs()
+    }
+}
+
+/// Speed of light in vacuum, *c*.
+pub const SPEED_OF_LIGHT: Constant = Constant { name: "speed_of_light", symbol: "c", value: 299_792_458.0, uncertainty: 0.0, dimensions: [1, 0, -1, 0, 0, 0, 0] };
+/// Planck constant, *h*.
+pub const PLANCK_CONSTANT: Constant = Constant { name: "planck_constant", symbol: "h", value: 6.626_070_15E-34, uncertainty: 0.0, dimensions: [2, 1, -1, 0, 0, 0, 0] };
+/// Reduced Planck constant, *ħ = h/2π*.
+pub const REDUCED_PLANCK_CONSTANT: Constant = Constant { name: "reduced_planck_constant", symbol: "ħ", value: 1.054_571_817E-34, uncertainty: 0.0, dimensions: [2, 1, -1, 0, 0, 0, 0] };
+/// Boltzmann constant, *k*.
+pub const BOLTZMANN_CONSTANT: Constant = Constant { name: "boltzmann_constant", symbol: "k_B", value: 1.380_649E-23, uncertainty: 0.0, dimensions: [2, 1, -2, 0, -1, 0, 0] };
+/// Avogadro constant, *N*<sub>A</sub>.
+pub const AVOGADRO_CONSTANT: Constant = Constant { name: "avogadro_constant", symbol: "N_A", value: 6.022_140_76E23, uncertainty: 0.0, dimensions: [0, 0, 0, 0, 0, -1, 0] };
+/// Elementary charge, *e*.
+pub const ELEMENTARY_CHARGE: Constant = Constant { name: "elementary_charge", symbol: "e", value: 1.602_176_634E-19, uncertainty: 0.0, dimensions: [0, 0, 1, 1, 0, 0, 0] };
+/// Molar gas constant, *R*.
+pub const MOLAR_GAS_CONSTANT: Constant = Constant { name: "molar_gas_constant", symbol: "R", value: 8.314_462_618, uncertainty: 0.0, dimensions: [2, 1, -2, 0, -1, -1, 0] };
+/// Faraday constant, *F*.
+pub const FARADAY_CONSTANT: Constant = Constant { name: "faraday_constant", symbol: "F", value: 96_485.332_12, uncertainty: 0.0, dimensions: [0, 0, 1, 1, 0, -1, 0] };
+/// Stefan-Boltzmann constant, *σ*.
+pub const STEFAN_BOLTZMANN_CONSTANT: Constant = Constant { name: "stefan_boltzmann_constant", symbol: "σ", value: 5.670_374_419E-8, uncertainty: 0.0, dimensions: [0, 1, -3, 0, -4, 0, 0] };
+/// Standard acceleration of gravity, *g*<sub>n</sub> (conventional value).
+pub const STANDARD_GRAVITY: Constant = Constant { name: "standard_gravity", symbol: "g_n", value: 9.806_65, uncertainty: 0.0, dimensions: [1, 0, -2, 0, 0, 0, 0] };
+/// Newtonian constant of gravitation, *G*.
+pub const GRAVITATIONAL_CONSTANT: Constant = Constant { name: "gravitational_constant", symbol: "G", value: 6.674_30E-11, uncertainty: 0.000_15E-11, dimensions: [3, -1, -2, 0, 0, 0, 0] };
+/// Vacuum electric permittivity, *ε*<sub>0</sub>.
+pub const VACUUM_ELECTRIC_PERMITTIVITY: Constant = Constant { name: "vacuum_electric_permittivity", symbol: "ε_0", value: 8.854_187_818_8E-12, uncertainty: 0.000_000_001_4E-12, dimensions: [-3, -1, 4, 2, 0, 0, 0] };
+/// Vacuum magnetic permeability, *μ*<sub>0</sub>.
+pub const VACUUM_MAGNETIC_PERMEABILITY: Constant = Constant { name: "vacuum_magnetic_permeability", symbol: "μ_0", value: 1.256_637_061_27E-6, uncertainty: 0.000_000_000_20E-6, dimensions: [1, 1, -2, -2, 0, 0, 0] };
+/// Electron mass, *m*<sub>e</sub>.
+pub const ELECTRON_MASS: Constant = Constant { name: "electron_mass", symbol: "m_e", value: 9.109_383_713_9E-31, uncertainty: 0.000_000_002_8E-31, dimensions: [0, 1, 0, 0, 0, 0, 0] };
+/// Proton mass, *m*<sub>p</sub>.
+pub const PROTON_MASS: Constant = Constant { name: "proton_mass", symbol: "m_p", value: 1.672_621_925_95E-27, uncertainty: 0.000_000_000_52E-27, dimensions: [0, 1, 0, 0, 0, 0, 0] };
+/// Atomic mass constant, *m*<sub>u</sub>.
+pub const ATOMIC_MASS_CONSTANT: Constant = Constant { name: "atomic_mass_constant", symbol: "m_u", value: 1.660_539_068_92E-27, uncertainty: 0.000_000_000_52E-27, dimensions: [0, 1, 0, 0, 0, 0, 0] };
+/// Bohr radius, *a*<sub>0</sub>.
+pub const BOHR_RADIUS: Constant = Constant { name: "bohr_radius", symbol: "a_0", value: 5.291_772_105_44E-11, uncertainty: 0.000_000_000_82E-11, dimensions: [1, 0, 0, 0, 0, 0, 0] };
+/// Fine-structure constant, *α*.
+pub const FINE_STRUCTURE_CONSTANT: Constant = Constant { name: "fine_structure_constant", symbol: "α", value: 7.297_352_564_3E-3, uncertainty: 0.000_000_001_1E-3, dimensions: [0, 0, 0, 0, 0, 0, 0] };
+
+/// All constants of this module.
+pub const ALL: [Constant; 18] = [SPEED_OF_LIGHT, PLANCK_CONSTANT, REDUCED_PLANCK_CONSTANT, BOLTZMANN_CONSTANT, AVOGADRO_CONSTANT,
+    ELEMENTARY_CHARGE, MOLAR_GAS_CONSTANT, FARADAY_CONSTANT, STEFAN_BOLTZMANN_CONSTANT, STANDARD_GRAVITY, GRAVITATIONAL_CONSTANT,
+    VACUUM_ELECTRIC_PERMITTIVITY, VACUUM_MAGNETIC_PERMEABILITY, ELECTRON_MASS, PROTON_MASS, ATOMIC_MASS_CONSTANT, BOHR_RADIUS,
+    FINE_STRUCTURE_CONSTANT];
+
+///
+/// Find a constant by its name (e.g. `"speed_of_light"`) or symbol (e.g. `"c"`).
+///
+pub fn find(name: &str) -> Option<Constant>
+{
+    ALL.iter().find(|constant| constant.name == name || constant.symbol == name).copied()
+}
+
+/// Speed of light in vacuum, *c*.
+#[cfg(any(feature="Velocity", feature="All"))]
+pub fn speed_of_light() -> crate::Velocity
+{
+    crate::Velocity::meter_per_second(SPEED_OF_LIGHT.value)
+}
+
+/// Planck constant, *h*.
+#[cfg(any(feature="Action", feature="All"))]
+pub fn planck_constant() -> crate::Action
+{
+    crate::Action::joule_second(PLANCK_CONSTANT.value)
+}
+
+/// Reduced Planck constant, *ħ*.
+#[cfg(any(feature="Action", feature="All"))]
+pub fn reduced_planck_constant() -> crate::Action
+{
+    crate::Action::joule_second(REDUCED_PLANCK_CONSTANT.value)
+}
+
+/// Boltzmann constant, *k*.
+#[cfg(any(feature="HeatCapacity", feature="All"))]
+pub fn boltzmann_constant() -> crate::HeatCapacity
+{
+    crate::HeatCapacity::joule_per_kelvin(BOLTZMANN_CONSTANT.value)
+}
+
+/// Elementary charge, *e*.
+#[cfg(any(feature="ElectricCharge", feature="All"))]
+pub fn elementary_charge() -> crate::ElectricCharge
+{
+    crate::ElectricCharge::coulomb(ELEMENTARY_CHARGE.value)
+}
+
+/// Molar gas constant, *R*.
+#[cfg(any(feature="MolarHeatCapacity", feature="All"))]
+pub fn molar_gas_constant() -> crate::MolarHeatCapacity
+{
+    crate::MolarHeatCapacity::joule_per_kelvin_mole(MOLAR_GAS_CONSTANT.value)
+}
+
+/// Standard acceleration of gravity, *g*<sub>n</sub>.
+#[cfg(any(feature="Acceleration", feature="All"))]
+pub fn standard_gravity() -> crate::Acceleration
+{
+    crate::Acceleration::meter_per_second_squared(STANDARD_GRAVITY.value)
+}
+
+/// Vacuum electric permittivity, *ε*<sub>0</sub>.
+#[cfg(any(feature="ElectricPermittivity", feature="All"))]
+pub fn vacuum_electric_permittivity() -> crate::ElectricPermittivity
+{
+    crate::ElectricPermittivity::farad_per_meter(VACUUM_ELECTRIC_PERMITTIVITY.value)
+}
+
+/// Vacuum magnetic permeability, *μ*<sub>0</sub>.
+#[cfg(any(feature="MagneticPermeability", feature="All"))]
+pub fn vacuum_magnetic_permeability() -> crate::MagneticPermeability
+{
+    crate::MagneticPermeability::henry_per_meter(VACUUM_MAGNETIC_PERMEABILITY.value)
+}
+
+/// Electron mass, *m*<sub>e</sub>.
+#[cfg(any(feature="Mass", feature="All"))]
+pub fn electron_mass() -> crate::Mass
+{
+    crate::Mass::kilogram(ELECTRON_MASS.value)
+}
+
+/// Proton mass, *m*<sub>p</sub>.
+#[cfg(any(feature="Mass", feature="All"))]
+pub fn proton_mass() -> crate::Mass
+{
+    crate::Mass::kilogram(PROTON_MASS.value)
+}
+
+/// Atomic mass constant, *m*<sub>u</sub>.
+#[cfg(any(feature="Mass", feature="All"))]
+pub fn atomic_mass_constant() -> crate::Mass
+{
+    crate::Mass::kilogram(ATOMIC_MASS_CONSTANT.value)
+}
+
+/// Bohr radius, *a*<sub>0</sub>.
+#[cfg(any(feature="Length", feature="All"))]
+pub fn bohr_radius() -> crate::Length
+{
+    crate::Length::meter(BOHR_RADIUS.value)
+}
@@ -0,0 +1,443 @@
+//! Reading and writing tables of quantities as CSV, with the unit of each column in its header.
+//!
+//! Headers are parsed as `time [s]`, `pressure (kPa)` or `flow_rate/L·min⁻¹`, where the unit is an abbreviation or name of
+//! `Units`, or any unit expression understood by [`crate::parser::parse_unit`]. A header without a unit is dimensionless,
+//! as is a header whose text after a slash is not a unit (`a/b`). Names are resolved as by `parse_unit`: a temperature
+//! scale is absolute (`temperature (°C)`, `temperature [K]`), and names shared by units of different dimensions fail with
+//! `RuntimeUnitError::AmbiguousUnit`, except that a unit is preferred to a physical constant of the same symbol (`time [h]`
+//! is in hours, not in Planck constants). Fields may be quoted (with `""` for a literal quote), and empty fields are read
+//! as `NaN`.
+//!
+//! [`CsvWriter`] writes headers in the same conventions, keeping the unit written in the header a column was read from
+//! (e.g. `psi` rather than `lbf/in²`, or `L·min⁻¹`), and can convert columns on write: to SI base units, or to a target unit
+//! per column. Temperature intervals named like a temperature scale (`K`, `°C`) are read back as absolute temperatures.
+use std::io::{BufRead, Write};
+
+use crate::errors::RuntimeUnitError;
+use crate::table::QuantityTable;
+use crate::traits::ArbitraryQuantity;
+use crate::units_base::{QuantityKind, UnitDefinition};
+use crate::vector_quantity::VecQuantity;
+use crate::{QuantitiesVec, Units};
+
+/// Convention used to write the unit of a column in its header.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum HeaderStyle
+{
+    /// `time [s]`
+    #[default]
+    Brackets,
+    /// `time (s)`
+    Parentheses,
+    /// `time/s`
+    Slash,
+}
+
+///
+/// Split a column header into its name and unit (e.g. `"time [s]"`, `"pressure (kPa)"` or `"flow_rate/L·min⁻¹"`). A
+/// header without a unit is dimensionless.
+///
+pub fn parse_header(header: &str) -> Result<(String, UnitDefinition), RuntimeUnitError>
+{
+    parse_named_header(header).map(|header| (header.name, header.unit))
+}
+
+/// Column header, with the named unit if the header names one of `Units`, and the text of its unit if any.
+struct Header
+{
+    name: String,
+    unit: UnitDefinition,
+    named: Option<Units>,
+    text: Option<String>,
+}
+
+/// Split a column header into its name and unit, with the named unit if the header names one of `Units`.
+fn parse_named_header(header: &str) -> Result<Header, RuntimeUnitError>
+{
+    let unitless = || Header { name: header.trim().to_owned(), unit: UnitDefinition::dimensionless(), named: None, text: None };
+    let (name, text) = split_header(header);
+    let Some(text) = text else { return Ok(unitless()) };
+    match parse_header_unit(text)
+    {
+        Ok((unit, named)) => Ok(Header { name: name.to_owned(), unit, named, text: Some(text.to_owned()) }),
+        // A slash may be part of the name of a column without a unit (e.g. `a/b`).
+        Err(_) if !header.trim_end().ends_with([']', ')']) => Ok(unitless()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Split a column header into its name and the text of its unit, if any.
+fn split_header(header: &str) -> (&str, Option<&str>)
+{
+    let header = header.trim();
+    let split = if let Some(rest) = header.strip_suffix(']')
+    {
+        rest.rfind('[').map(|start| (&rest[..start], &rest[start + 1..]))
+    }
+    else if let Some(rest) = header.strip_suffix(')')
+    {
+        matching_parenthesis(rest).map(|start| (&rest[..start], &rest[start + 1..]))
+    }
+    else
+    {
+        header.split_once('/')
+    };
+    match split
+    {
+        Some((name, unit)) if !name.trim().is_empty() => (name.trim(), Some(unit.trim())),
+        _ => (header, None),
+    }
+}
+
+/// Position of the parenthesis opening the group that ends at the end of `text` (excluding the closing parenthesis).
+fn matching_parenthesis(text: &str) -> Option<usize>
+{
+    let mut depth = 0;
+    for (index, c) in text.char_indices().rev()
+    {
+        match c
+        {
+            ')' => depth += 1,
+            '(' if depth == 0 => return Some(index),
+            '(' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse the unit of a header, with the named unit if it is an abbreviation or name of `Units` (preferring absolute
+/// temperature scales, as `parse_unit` does).
+fn parse_header_unit(unit: &str) -> Result<(UnitDefinition, Option<Units>), RuntimeUnitError>
+{
+    let named = match crate::parser::absolute_scale(unit)
+    {
+        Some(named) => Some(named),
+        None => find_header_unit(unit)?,
+    };
+    match named
+    {
+        Some(named) => Ok((named.into(), Some(named))),
+        None => Ok((crate::parser::parse_unit(unit)?, None)),
+    }
+}
+
+/// Find a unit by name like `parse_unit`, but preferring units to physical constants of the same symbol (e.g. `h` is an
+/// hour rather than the Planck constant).
+fn find_header_unit(name: &str) -> Result<Option<Units>, RuntimeUnitError>
+{
+    match crate::parser::find_unit(name)
+    {
+        Err(RuntimeUnitError::AmbiguousUnit { input, candidates }) =>
+        {
+            let constant = crate::constants::find(name).map(|constant| constant.as_unit());
+            let units: Vec<Units> = candidates.iter().copied()
+                .filter(|&candidate| !constant.is_some_and(|constant| constant.approx_eq(candidate.into(), 1e-9)))
+                .collect();
+            match units.first()
+            {
+                Some(&first) if units.iter().all(|&unit| UnitDefinition::from(unit).approx_eq(first.into(), 1e-12)) => Ok(Some(first)),
+                _ => Err(RuntimeUnitError::AmbiguousUnit { input, candidates }),
+            }
+        }
+        result => result,
+    }
+}
+
+/// Named unit matching `unit`, without adding an angle or information to it (e.g. not radians for a plain number, or
+/// radians per second for `1/s`).
+fn named_unit(unit: UnitDefinition) -> Option<Units>
+{
+    let propagated = |unit: UnitDefinition| unit.kind.filter(QuantityKind::is_propagated);
+    crate::UnitTypes::matching(unit).iter()
+        .filter_map(|unit_type| unit_type.find_unit(unit, 1e-9))
+        .find(|&named| propagated(named.into()) == propagated(unit))
+}
+
+/// Split a CSV `line` into fields, handling quoted fields.
+fn split_line(line: &str, delimiter: char) -> Vec<String>
+{
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next()
+    {
+        match c
+        {
+            '"' if quoted && chars.peek() == Some(&'"') => { field.push('"'); chars.next(); },
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Quote a `field` if it contains the `delimiter`, quotes or line breaks.
+fn quote(field: &str, delimiter: char) -> String
+{
+    if field.contains([delimiter, '"', '\n', '\r'])
+    {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    }
+    else
+    {
+        field.to_owned()
+    }
+}
+
+fn io_error(error: std::io::Error) -> RuntimeUnitError
+{
+    RuntimeUnitError::ParseError(format!("Could not read or write CSV: {error}"))
+}
+
+///
+/// Reader of CSV data with units in the headers (e.g. `time [s],pressure (kPa)`).
+///
+#[derive(Copy, Clone, Debug)]
+pub struct CsvReader
+{
+    delimiter: char,
+}
+
+impl Default for CsvReader
+{
+    fn default() -> Self
+    {
+        Self { delimiter: ',' }
+    }
+}
+
+impl CsvReader
+{
+    /// Create a reader of comma-separated values
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// Use another `delimiter` (e.g. `';'` or `'\t'`)
+    pub fn with_delimiter(self, delimiter: char) -> Self
+    {
+        Self { delimiter }
+    }
+
+    /// Read the headers of CSV data and its columns, in the units of the headers.
+    fn read(&self, reader: impl BufRead) -> Result<Vec<(Header, VecQuantity)>, RuntimeUnitError>
+    {
+        let mut lines = reader.lines();
+        let header = lines.next().transpose().map_err(io_error)?.ok_or_else(|| RuntimeUnitError::ParseError("Missing CSV header".into()))?;
+        let headers = split_line(header.trim_end_matches('\r'), self.delimiter);
+        let mut columns = Vec::new();
+        for header in &headers
+        {
+            let header = parse_named_header(header)?;
+            let column = VecQuantity { unit: header.unit, values: Vec::new() };
+            columns.push((header, column));
+        }
+        for (row, line) in lines.enumerate()
+        {
+            let line = line.map_err(io_error)?;
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty()
+            {
+                continue;
+            }
+            let fields = split_line(line, self.delimiter);
+            if fields.len() != columns.len()
+            {
+                return Err(RuntimeUnitError::ParseError(format!("Row {} has {} fields instead of {}", row + 1, fields.len(), columns.len())));
+            }
+            for ((_, column), field) in columns.iter_mut().zip(&fields)
+            {
+                let field = field.trim();
+                column.values.push(if field.is_empty() { f64::NAN } else { field.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: field.to_owned() })? });
+            }
+        }
+        Ok(columns)
+    }
+
+    ///
+    /// Read the columns of CSV data, each as a `QuantitiesVec` in the unit of its header (a header without a unit is
+    /// `Dimensionless`). Units without a name are converted to the base unit of the matching quantity (e.g. `L·min⁻¹` to
+    /// `m³/s`), use [`CsvReader::read_table`] to keep them.
+    ///
+    pub fn read_columns(&self, reader: impl BufRead) -> Result<Vec<(String, QuantitiesVec)>, RuntimeUnitError>
+    {
+        // Keep the unit named in the header where there is one (e.g. `psi` rather than `lbf/in²`).
+        self.read(reader)?.into_iter().map(|(header, column)|
+        {
+            let base = UnitDefinition { multiplier: 1.0, offset: column.unit.offset.map(|_| 0.0), ..column.unit };
+            match header.named.or_else(|| named_unit(column.unit)).or_else(|| named_unit(base))
+            {
+                Some(unit) => Ok((header.name, QuantitiesVec::new(column.try_convert(unit.into())?.values, unit))),
+                None => Ok((header.name, column.try_into_quantities_vec()?)),
+            }
+        }).collect()
+    }
+
+    /// Read the columns of CSV data from a string, see [`CsvReader::read_columns`]
+    pub fn read_columns_str(&self, input: &str) -> Result<Vec<(String, QuantitiesVec)>, RuntimeUnitError>
+    {
+        self.read_columns(input.as_bytes())
+    }
+
+    ///
+    /// Read CSV data into a `QuantityTable`, with each column in the unit of its header, keeping the units written in the
+    /// headers (see [`QuantityTable::named_unit`] and [`QuantityTable::unit_expression`]).
+    ///
+    pub fn read_table(&self, reader: impl BufRead) -> Result<QuantityTable, RuntimeUnitError>
+    {
+        self.read(reader)?.into_iter().try_fold(QuantityTable::new(), |mut table, (header, column)|
+        {
+            match (header.named, header.text)
+            {
+                (Some(unit), _) => table.push_quantities(header.name, QuantitiesVec::new(column.values, unit))?,
+                (None, Some(text)) => table.push_expression_column(header.name, column, text)?,
+                (None, None) => table.push_column(header.name, column)?,
+            }
+            Ok(table)
+        })
+    }
+
+    /// Read CSV data from a string into a `QuantityTable`
+    pub fn read_table_str(&self, input: &str) -> Result<QuantityTable, RuntimeUnitError>
+    {
+        self.read_table(input.as_bytes())
+    }
+}
+
+///
+/// Writer of a `QuantityTable` as CSV data with units in the headers, optionally converting columns on write.
+///
+#[derive(Clone, Debug)]
+pub struct CsvWriter
+{
+    delimiter: char,
+    header_style: HeaderStyle,
+    base_units: bool,
+    column_units: Vec<(String, UnitDefinition)>,
+}
+
+impl Default for CsvWriter
+{
+    fn default() -> Self
+    {
+        Self { delimiter: ',', header_style: HeaderStyle::default(), base_units: false, column_units: Vec::new() }
+    }
+}
+
+impl CsvWriter
+{
+    /// Create a writer of comma-separated values, with units in brackets (e.g. `time [s]`)
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// Use another `delimiter` (e.g. `';'` or `'\t'`)
+    pub fn with_delimiter(self, delimiter: char) -> Self
+    {
+        Self { delimiter, ..self }
+    }
+
+    /// Use another convention to write units in headers
+    pub fn with_header_style(self, header_style: HeaderStyle) -> Self
+    {
+        Self { header_style, ..self }
+    }
+
+    /// Convert all columns to SI base units (e.g. psi to Pa), unless a unit is given for the column
+    pub fn with_base_units(self) -> Self
+    {
+        Self { base_units: true, ..self }
+    }
+
+    /// Convert the column `name` to `unit`
+    pub fn with_column_unit(mut self, name: impl Into<String>, unit: impl Into<UnitDefinition>) -> Self
+    {
+        self.column_units.push((name.into(), unit.into()));
+        self
+    }
+
+    /// Unit of a column in the written file, and its header, keeping the unit the column was read with where it is the target
+    fn target(&self, table: &QuantityTable, name: &str) -> (UnitDefinition, String)
+    {
+        let unit = table.unit(name).expect("Column names are in the table");
+        let target = match self.column_units.iter().find(|(column, _)| column == name)
+        {
+            Some((_, unit)) => *unit,
+            None if self.base_units => UnitDefinition { multiplier: 1.0, offset: unit.offset.map(|_| 0.0), ..unit },
+            None => unit,
+        };
+        if target.approx_eq(unit, 1e-12)
+        {
+            if let Some(named) = table.named_unit(name)
+            {
+                return (named.into(), named.abbreviation().to_owned());
+            }
+            if let Some(expression) = table.unit_expression(name)
+            {
+                return (unit, expression.to_owned());
+            }
+        }
+        if target == UnitDefinition::dimensionless()
+        {
+            return (target, String::new());
+        }
+        match named_unit(target)
+        {
+            Some(named) => (named.into(), named.abbreviation().to_owned()),
+            // Units without a name are written in base units, which can be parsed back.
+            None =>
+            {
+                let base = UnitDefinition { multiplier: 1.0, offset: target.offset.map(|_| 0.0), ..target };
+                let abbreviation = if base.base == UnitDefinition::dimensionless().base { String::new() } else { base.unit_string() };
+                (base, abbreviation)
+            }
+        }
+    }
+
+    fn header(&self, name: &str, unit: &str) -> String
+    {
+        match (self.header_style, unit.is_empty())
+        {
+            (_, true) => name.to_owned(),
+            (HeaderStyle::Brackets, false) => format!("{name} [{unit}]"),
+            (HeaderStyle::Parentheses, false) => format!("{name} ({unit})"),
+            (HeaderStyle::Slash, false) => format!("{name}/{unit}"),
+        }
+    }
+
+    /// Write a `table` as CSV data, failing if a column cannot be converted to its target unit
+    pub fn write_table(&self, table: &QuantityTable, mut writer: impl Write) -> Result<(), RuntimeUnitError>
+    {
+        let mut headers = Vec::new();
+        let mut columns = Vec::new();
+        for name in table.names()
+        {
+            let column = table.column(name).expect("Column names are in the table");
+            let (unit, abbreviation) = self.target(table, name);
+            headers.push(quote(&self.header(name, &abbreviation), self.delimiter));
+            columns.push(column.try_convert(unit)?);
+        }
+        let delimiter = self.delimiter.to_string();
+        writeln!(writer, "{}", headers.join(&delimiter)).map_err(io_error)?;
+        for row in 0..table.row_count()
+        {
+            let fields: Vec<String> = columns.iter().map(|column| if column.values[row].is_nan() { String::new() } else { column.values[row].to_string() }).collect();
+            writeln!(writer, "{}", fields.join(&delimiter)).map_err(io_error)?;
+        }
+        Ok(())
+    }
+
+    /// Write a `table` as a string of CSV data
+    pub fn write_table_string(&self, table: &QuantityTable) -> Result<String, RuntimeUnitError>
+    {
+        let mut output = Vec::new();
+        self.write_table(table, &mut output)?;
+        String::from_utf8(output).map_err(|error| RuntimeUnitError::ParseError(error.to_string()))
+    }
+}
@@ -1,19 +1,114 @@
 use core::fmt::Display;
 
+use crate::level::{LevelKind, LevelUnit};
+use crate::units_base::{QuantityKind, UnitBase, UnitDefinition};
+use crate::{UnitTypes, Units};
+
 #[derive(Clone, Debug)]
 pub enum RuntimeUnitError
 {
     IncompatibleUnitConversion(String),
     ParseError(String),
+    /// Conversion between units of different dimensions (e.g. meters to seconds).
+    IncompatibleDimensions { from: UnitBase, to: UnitBase },
+    /// Conversion between units of the same dimensions but different kinds (e.g. radians to bytes, or torque to energy).
+    IncompatibleKinds { from: Option<QuantityKind>, to: Option<QuantityKind> },
+    /// Conversion between an absolute unit (e.g. degrees Celsius) and a relative unit (e.g. a temperature interval).
+    AbsoluteRelativeMismatch { from: UnitDefinition, to: UnitDefinition },
+    /// A unit name that is not recognized, either for a given quantity (e.g. `UnitTypes::Length`) or for all quantities.
+    UnknownUnit { input: String, quantity: Option<UnitTypes> },
+    /// A unit name shared by several quantities (e.g. `"h"` for hours and the Planck constant).
+    AmbiguousUnit { input: String, candidates: Vec<Units> },
+    /// A converted value that the numeric type of a quantity cannot represent (e.g. 300 cm as `u8` millimeters, or 1500 g
+    /// as integer kilograms), see `Numeric::try_from_f64`.
+    ValueOutOfRange { value: f64 },
+    /// Values of different lengths (e.g. adding a slice of 3 values to a slice of 4 values).
+    LengthMismatch { expected: usize, found: usize },
+    /// Arrays whose shapes cannot be broadcast together (e.g. `[2, 3]` and `[4]`), see `ndarray_quantity::QuantityNd`.
+    ShapeMismatch { lhs: Vec<usize>, rhs: Vec<usize> },
+    /// An index (e.g. of a value or of a column) that is out of bounds for `len` elements.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// A column name already used in a `QuantityTable`.
+    DuplicateColumn { name: String },
+    /// A column name not found in a `QuantityTable`.
+    UnknownColumn { name: String },
+    /// A column with a number of rows different from the other columns of a `QuantityTable`.
+    RowCountMismatch { name: String, expected: usize, found: usize },
+    /// A name already used by a built-in or registered unit, see `registry::UnitRegistry::register`.
+    DuplicateUnit { name: String },
+    /// A name that cannot be parsed back as a unit name (e.g. empty, `m/s`, `m²` or `m2`), see `registry::UnitRegistry::register`.
+    InvalidUnitName { name: String },
+    /// Logarithmic units that cannot be converted or combined (e.g. dBm to pH, or a gain in dB added to a level).
+    IncompatibleLevels { from: LevelUnit, to: LevelUnit },
+    /// A logarithmic unit of another kind than expected (e.g. dBm where a ratio such as dB is expected).
+    UnexpectedLevelKind { unit: LevelUnit, expected: LevelKind },
+    /// A logarithmic unit without reference quantity (e.g. dB), which cannot be converted to or from a quantity.
+    NoReference { unit: LevelUnit },
+    /// An Arrow field without unit metadata, see `arrow::UNIT_METADATA_KEY`.
+    MissingUnit { field: String },
+    /// An Arrow array of a type other than the floating point types of quantities (e.g. `Int32`).
+    UnsupportedType { field: String, data_type: String },
+    /// A stride of zero, for values laid out with a stride (see `slice::convert_strided_in_place`).
+    InvalidStride,
+    /// A value or factor that is not a valid number.
+    InvalidNumber { input: String },
+    /// A power of a base unit that cannot be represented: the numerator must be within -8..=7 (-128..=127 with the
+    /// `wide_dimensions` feature) and the denominator within 1..=7, see `Rational8::try_new`.
+    ExponentOverflow { numerator: i64, denominator: i64 },
+}
+
+impl RuntimeUnitError
+{
+    /// Error for a unit `from` that is not a unit of the quantity of `to`: units of different dimensions, absolute and
+    /// relative units, or otherwise units of different kinds (e.g. a torque for an energy).
+    pub(crate) fn quantity_mismatch(from: UnitDefinition, to: UnitDefinition) -> Self
+    {
+        match from.try_convert(to)
+        {
+            Err(error) => error,
+            Ok(_) => RuntimeUnitError::IncompatibleKinds { from: from.kind, to: to.kind },
+        }
+    }
 }
 
 impl Display for RuntimeUnitError
 {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result 
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
     {
-        write!(f, "{self:?}")
+        match self
+        {
+            RuntimeUnitError::IncompatibleUnitConversion(message) | RuntimeUnitError::ParseError(message) => write!(f, "{message}"),
+            RuntimeUnitError::IncompatibleDimensions { from, to } => write!(f, "Could not convert from base units of {from} to {to}"),
+            RuntimeUnitError::IncompatibleKinds { from, to } => write!(f, "Could not convert from {from:?} to {to:?}"),
+            RuntimeUnitError::AbsoluteRelativeMismatch { from, to } => write!(f, "Could not convert between absolute unit and relative unit: {from} and {to}"),
+            RuntimeUnitError::UnknownUnit { input, quantity: Some(quantity) } => write!(f, "Unknown unit \"{input}\" for {quantity:?}"),
+            RuntimeUnitError::UnknownUnit { input, quantity: None } => write!(f, "Unknown unit \"{input}\""),
+            RuntimeUnitError::AmbiguousUnit { input, candidates } =>
+            {
+                let candidates: Vec<String> = candidates.iter().map(|unit| format!("{unit:?}")).collect();
+                write!(f, "Ambiguous unit \"{input}\", could be any of {}", candidates.join(", "))
+            }
+            RuntimeUnitError::ValueOutOfRange { value } => write!(f, "Value {value} cannot be represented by the numeric type of the quantity"),
+            RuntimeUnitError::LengthMismatch { expected, found } => write!(f, "Slice dimensions do not match: {found} != {expected}"),
+            RuntimeUnitError::ShapeMismatch { lhs, rhs } => write!(f, "Array shapes do not match: {lhs:?} and {rhs:?}"),
+            RuntimeUnitError::IndexOutOfBounds { index, len } => write!(f, "Index {index} out of bounds for length {len}"),
+            RuntimeUnitError::DuplicateColumn { name } => write!(f, "Column `{name}` already exists"),
+            RuntimeUnitError::UnknownColumn { name } => write!(f, "No column named `{name}`"),
+            RuntimeUnitError::RowCountMismatch { name, expected, found } => write!(f, "Column `{name}` has {found} rows instead of {expected}"),
+            RuntimeUnitError::DuplicateUnit { name } => write!(f, "Unit \"{name}\" is already defined"),
+            RuntimeUnitError::InvalidUnitName { name } => write!(f, "Invalid unit name \"{name}\""),
+            RuntimeUnitError::IncompatibleLevels { from, to } => write!(f, "Could not combine or convert levels in {from} and {to}"),
+            RuntimeUnitError::UnexpectedLevelKind { unit, expected } => write!(f, "{unit} is not a {expected:?} level"),
+            RuntimeUnitError::NoReference { unit } => write!(f, "{unit} has no reference quantity"),
+            RuntimeUnitError::MissingUnit { field } => write!(f, "Field `{field}` has no unit metadata"),
+            RuntimeUnitError::UnsupportedType { field, data_type } => write!(f, "Field `{field}` of type {data_type} is not a Float64 or Float32 array"),
+            RuntimeUnitError::InvalidStride => write!(f, "Stride must be positive"),
+            RuntimeUnitError::InvalidNumber { input } => write!(f, "Invalid number \"{input}\""),
+            RuntimeUnitError::ExponentOverflow { numerator, denominator: 1 } => write!(f, "Exponent {numerator} is out of range"),
+            RuntimeUnitError::ExponentOverflow { numerator, denominator } => write!(f, "Exponent {numerator}/{denominator} is out of range"),
+        }
     }
 }
 
 #[cfg(feature="std")]
-impl std::error::Error for RuntimeUnitError{}
\ No newline at end of file
+impl std::error::Error for RuntimeUnitError{}
@@ -0,0 +1,474 @@
+//! Evaluation of arithmetic expressions over quantities, such as `"3 ft + 20 cm * 2"` or `"(5 kN * 2 m) / 10 s"`.
+//!
+//! Expressions combine numbers, units, variables and function calls with `+`, `-`, `*` (or `·`, `⋅`, `×`), `/` and `^`
+//! (or Unicode superscripts), grouped with parentheses. A number directly followed by units is a quantity (`20 cm`,
+//! `9.81 m/s²`), binding tighter than `*` and `/`, so `10 m / 2 s` is a velocity. Units are resolved as in
+//! [`crate::parser::parse_unit`], including registered units and physical constants; a unit on its own is a quantity of
+//! one such unit. The dimensions are checked at each step: adding a length to a time fails with
+//! `RuntimeUnitError::IncompatibleDimensions`.
+//!
+//! A number followed by a temperature scale (`20 °C`, `300 K`) is an absolute temperature, while a scale named without a
+//! number is a temperature interval, as within unit expressions (`W/(m·°C)`). Absolute temperatures can be subtracted from
+//! each other, giving a temperature interval (`300 K - 20 °C`), and offset by a temperature difference, where the right
+//! operand of `+` is taken as a difference (`20 °C + 5 K`). Temperatures on scales whose zero is not absolute zero cannot
+//! be multiplied, divided, raised to a power or passed to `sqrt` or `abs`: `20 °C * 2` and `20 °C m` fail with
+//! `RuntimeUnitError::AbsoluteRelativeMismatch`, while `300 K * 2` is 600 K.
+//! Ambiguous unit names fail with `RuntimeUnitError::AmbiguousUnit` (`2 h` is an hour or the Planck constant).
+//!
+//! The functions `sqrt(x)`, `pow(x, y)`, `abs(x)`, `min(x, ...)` and `max(x, ...)` are available. Variables bound to
+//! quantities with a [`Context`] take precedence over units of the same name.
+use std::collections::HashMap;
+
+use crate::errors::RuntimeUnitError;
+use crate::parser::{parse_unit, superscript, MULTIPLY};
+use crate::quantity::Quantity;
+use crate::traits::CheckedAdditive;
+use crate::units_base::UnitDefinition;
+
+///
+/// Evaluate an arithmetic expression over quantities (e.g. `"3 ft + 20 cm * 2"`), see [`crate::expression`].
+///
+pub fn eval(input: &str) -> Result<Quantity, RuntimeUnitError>
+{
+    Context::new().eval(input)
+}
+
+/// Variables bound to quantities, used to evaluate expressions.
+#[derive(Clone, Debug, Default)]
+pub struct Context
+{
+    variables: HashMap<String, Quantity>,
+}
+
+impl Context
+{
+    /// Create a context without variables.
+    pub fn new() -> Self
+    {
+        Self { variables: HashMap::new() }
+    }
+
+    /// Bind a variable to a `value`, returning the context.
+    pub fn with_variable(mut self, name: &str, value: Quantity) -> Self
+    {
+        self.set(name, value);
+        self
+    }
+
+    /// Bind a variable to a `value`, returning its previous value if any.
+    pub fn set(&mut self, name: &str, value: Quantity) -> Option<Quantity>
+    {
+        self.variables.insert(name.to_owned(), value)
+    }
+
+    /// Value of a variable, if bound.
+    pub fn get(&self, name: &str) -> Option<Quantity>
+    {
+        self.variables.get(name).copied()
+    }
+
+    /// Remove a variable, returning its value if it was bound.
+    pub fn remove(&mut self, name: &str) -> Option<Quantity>
+    {
+        self.variables.remove(name)
+    }
+
+    /// Evaluate an arithmetic expression over quantities and the variables of this context.
+    pub fn eval(&self, input: &str) -> Result<Quantity, RuntimeUnitError>
+    {
+        let mut evaluator = Evaluator { chars: input.chars().collect(), position: 0, context: self };
+        let result = evaluator.sum()?;
+        evaluator.skip_whitespace();
+        if evaluator.peek().is_some()
+        {
+            return Err(evaluator.error("Unexpected character"));
+        }
+        Ok(result)
+    }
+}
+
+/// Check whether a character may be part of a name (of a unit, variable or function).
+fn is_name(c: char) -> bool
+{
+    !(c.is_whitespace() || MULTIPLY.contains(&c) || matches!(c, '+' | '-' | '/' | '^' | '(' | ')' | ',') || superscript(c).is_some())
+}
+
+/// Check whether a character may start a name. Names may contain, but not start with, digits.
+fn starts_name(c: char) -> bool
+{
+    is_name(c) && !c.is_ascii_digit() && c != '.'
+}
+
+/// Check whether a quantity is a plain number.
+fn is_number(quantity: &Quantity) -> bool
+{
+    quantity.unit == UnitDefinition::dimensionless()
+}
+
+/// Value of a dimensionless quantity (e.g. an exponent), failing with `RuntimeUnitError::IncompatibleDimensions` otherwise.
+fn dimensionless(quantity: Quantity) -> Result<f64, RuntimeUnitError>
+{
+    quantity.unit.try_convert(UnitDefinition::dimensionless()).map(|factor| quantity.value * factor)
+}
+
+/// Check that two quantities share their dimensions, e.g. before adding them.
+fn check_dimensions(lhs: &Quantity, rhs: &Quantity) -> Result<(), RuntimeUnitError>
+{
+    if lhs.unit.base != rhs.unit.base
+    {
+        return Err(RuntimeUnitError::IncompatibleDimensions { from: rhs.unit.base, to: lhs.unit.base });
+    }
+    Ok(())
+}
+
+/// Check that no factor of a product or quotient, base of a power, or argument of `sqrt` or `abs` is on a scale whose zero is not absolute zero (e.g.
+/// `20 °C * 2` is meaningless).
+fn check_relative(operands: &[Quantity]) -> Result<(), RuntimeUnitError>
+{
+    match operands.iter().find(|quantity| quantity.unit.offset.is_some_and(|offset| offset != 0.0))
+    {
+        Some(absolute) => Err(RuntimeUnitError::AbsoluteRelativeMismatch { from: absolute.unit, to: absolute.unit.interval() }),
+        None => Ok(())
+    }
+}
+
+/// Multiply two quantities, keeping the unit of a quantity multiplied by a plain number (e.g. `20 cm`).
+fn multiply(lhs: Quantity, rhs: Quantity) -> Result<Quantity, RuntimeUnitError>
+{
+    if is_number(&lhs)
+    {
+        Ok(Quantity::new(lhs.value * rhs.value, rhs.unit))
+    }
+    else if is_number(&rhs)
+    {
+        Ok(Quantity::new(lhs.value * rhs.value, lhs.unit))
+    }
+    else
+    {
+        lhs.checked_mul(rhs)
+    }
+}
+
+/// Divide two quantities, keeping the unit of a quantity divided by a plain number.
+fn divide(lhs: Quantity, rhs: Quantity) -> Result<Quantity, RuntimeUnitError>
+{
+    if is_number(&rhs)
+    {
+        Ok(Quantity::new(lhs.value / rhs.value, lhs.unit))
+    }
+    else
+    {
+        lhs.checked_div(rhs)
+    }
+}
+
+/// Raise a quantity to a dimensionless power.
+fn power(base: Quantity, exponent: Quantity) -> Result<Quantity, RuntimeUnitError>
+{
+    check_relative(&[base])?;
+    let exponent = dimensionless(exponent)?;
+    if exponent.fract() == 0.0 && (i8::MIN as f64..=i8::MAX as f64).contains(&exponent)
+    {
+        base.checked_powi(exponent as i8)
+    }
+    else
+    {
+        base.checked_powf(exponent)
+    }
+}
+
+/// Smallest (or largest) of quantities sharing their dimensions, in its own unit.
+fn extremum(name: &str, arguments: Vec<Quantity>, ordering: core::cmp::Ordering) -> Result<Quantity, RuntimeUnitError>
+{
+    let mut arguments = arguments.into_iter();
+    let first = arguments.next().ok_or_else(|| RuntimeUnitError::ParseError(format!("Function \"{name}\" expects at least 1 argument")))?;
+    arguments.try_fold(first, |result, argument|
+    {
+        argument.unit.try_convert(result.unit)?;
+        let value = argument.unit.convert_value_unchecked(argument.value, result.unit);
+        Ok(if value.partial_cmp(&result.value) == Some(ordering) { argument } else { result })
+    })
+}
+
+struct Evaluator<'a>
+{
+    chars: Vec<char>,
+    position: usize,
+    context: &'a Context,
+}
+
+impl Evaluator<'_>
+{
+    fn error(&self, message: &str) -> RuntimeUnitError
+    {
+        RuntimeUnitError::ParseError(format!("{message} at position {} in expression \"{}\"", self.position, self.chars.iter().collect::<String>()))
+    }
+
+    fn peek(&self) -> Option<char>
+    {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self)
+    {
+        while self.peek().is_some_and(char::is_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        let mut result = self.product()?;
+        loop
+        {
+            self.skip_whitespace();
+            match self.peek()
+            {
+                Some('+') =>
+                {
+                    self.position += 1;
+                    let mut rhs = self.product()?;
+                    check_dimensions(&result, &rhs)?;
+                    // Two temperatures cannot be added, so the right one is a difference (e.g. "20 °C + 5 K").
+                    if result.unit.is_absolute() && rhs.unit.is_absolute()
+                    {
+                        rhs.unit = rhs.unit.interval();
+                    }
+                    result = result.checked_add(rhs)?;
+                }
+                Some('-') =>
+                {
+                    self.position += 1;
+                    let rhs = self.product()?;
+                    check_dimensions(&result, &rhs)?;
+                    result = result.checked_sub(rhs)?;
+                }
+                _ => return Ok(result)
+            }
+        }
+    }
+
+    /// product := juxtaposition (('*' | '/') juxtaposition)*
+    fn product(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        let mut result = self.juxtaposition()?;
+        loop
+        {
+            self.skip_whitespace();
+            match self.peek()
+            {
+                Some('/') =>
+                {
+                    self.position += 1;
+                    let rhs = self.juxtaposition()?;
+                    check_relative(&[result, rhs])?;
+                    result = divide(result, rhs)?;
+                }
+                Some(c) if MULTIPLY.contains(&c) =>
+                {
+                    self.position += 1;
+                    let rhs = self.juxtaposition()?;
+                    check_relative(&[result, rhs])?;
+                    result = multiply(result, rhs)?;
+                }
+                _ => return Ok(result)
+            }
+        }
+    }
+
+    /// juxtaposition := unary power*, where each following power starts with a name (e.g. "5 kN m")
+    fn juxtaposition(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        let mut result = self.unary()?;
+        let mut literal = is_number(&result);
+        loop
+        {
+            self.skip_whitespace();
+            match self.peek()
+            {
+                Some(c) if starts_name(c) =>
+                {
+                    result = match self.absolute_unit(literal)
+                    {
+                        Some(unit) => Quantity::new(result.value, unit),
+                        None =>
+                        {
+                            let rhs = self.power()?;
+                            check_relative(&[result, rhs])?;
+                            multiply(result, rhs)?
+                        }
+                    };
+                    literal = false;
+                }
+                _ => return Ok(result)
+            }
+        }
+    }
+
+    /// Absolute unit named right after a number (e.g. "20 °C" is a temperature, not a temperature interval), consuming its name.
+    fn absolute_unit(&mut self, literal: bool) -> Option<UnitDefinition>
+    {
+        if !literal
+        {
+            return None;
+        }
+        let start = self.position;
+        let end = (start..self.chars.len()).find(|&i| !is_name(self.chars[i])).unwrap_or(self.chars.len());
+        let name: String = self.chars[start..end].iter().collect();
+        let next = self.chars[end..].iter().find(|c| !c.is_whitespace());
+        if self.context.get(&name).is_some() || next.is_some_and(|&c| c == '^' || c == '(' || superscript(c).is_some())
+        {
+            return None;
+        }
+        let unit = crate::parser::absolute_scale(&name)?;
+        self.position = end;
+        Some(unit.into())
+    }
+
+    /// unary := ('-' | '+') unary | power
+    fn unary(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        self.skip_whitespace();
+        match self.peek()
+        {
+            Some('-') =>
+            {
+                self.position += 1;
+                Ok(self.unary()? * -1.0)
+            }
+            Some('+') =>
+            {
+                self.position += 1;
+                self.unary()
+            }
+            _ => self.power()
+        }
+    }
+
+    /// power := primary ('^' unary | superscript+)?
+    fn power(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        let base = self.primary()?;
+        self.skip_whitespace();
+        if self.peek() == Some('^')
+        {
+            self.position += 1;
+            let exponent = self.unary()?;
+            power(base, exponent)
+        }
+        else if self.peek().and_then(superscript).is_some()
+        {
+            let start = self.position;
+            while self.peek().and_then(superscript).is_some()
+            {
+                self.position += 1;
+            }
+            let exponent: String = self.chars[start..self.position].iter().filter_map(|&c| superscript(c)).collect();
+            let exponent: f64 = exponent.parse().map_err(|_|
+            {
+                self.position = start;
+                self.error("Invalid exponent")
+            })?;
+            power(base, Quantity::new(exponent, UnitDefinition::dimensionless()))
+        }
+        else
+        {
+            Ok(base)
+        }
+    }
+
+    /// primary := '(' sum ')' | number | name '(' arguments ')' | name
+    fn primary(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        self.skip_whitespace();
+        match self.peek()
+        {
+            Some('(') =>
+            {
+                self.position += 1;
+                let result = self.sum()?;
+                self.expect(')')?;
+                Ok(result)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) if starts_name(c) =>
+            {
+                let start = self.position;
+                while self.peek().is_some_and(is_name)
+                {
+                    self.position += 1;
+                }
+                let name: String = self.chars[start..self.position].iter().collect();
+                if let Some(value) = self.context.get(&name)
+                {
+                    return Ok(value);
+                }
+                self.skip_whitespace();
+                if self.peek() == Some('(') && matches!(name.as_str(), "sqrt" | "pow" | "abs" | "min" | "max")
+                {
+                    self.position += 1;
+                    let arguments = self.arguments()?;
+                    return self.call(&name, arguments);
+                }
+                let unit = parse_unit(&name)?;
+                Ok(Quantity::new(1.0, if crate::parser::absolute_scale(&name).is_some() { unit.interval() } else { unit }))
+            }
+            _ => Err(self.error("Expected a number, unit or variable"))
+        }
+    }
+
+    /// number := digits ['.' digits] [('e' | 'E') ['-' | '+'] digits]
+    fn number(&mut self) -> Result<Quantity, RuntimeUnitError>
+    {
+        let start = self.position;
+        self.position = crate::parser::number_end(&self.chars, start);
+        let number: String = self.chars[start..self.position].iter().collect();
+        let value: f64 = number.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: number.clone() })?;
+        Ok(Quantity::new(value, UnitDefinition::dimensionless()))
+    }
+
+    /// arguments := sum (',' sum)* ')'
+    fn arguments(&mut self) -> Result<Vec<Quantity>, RuntimeUnitError>
+    {
+        let mut arguments = vec![self.sum()?];
+        self.skip_whitespace();
+        while self.peek() == Some(',')
+        {
+            self.position += 1;
+            arguments.push(self.sum()?);
+            self.skip_whitespace();
+        }
+        self.expect(')')?;
+        Ok(arguments)
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), RuntimeUnitError>
+    {
+        self.skip_whitespace();
+        if self.peek() != Some(c)
+        {
+            return Err(self.error(&format!("Expected '{c}'")));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn call(&self, name: &str, arguments: Vec<Quantity>) -> Result<Quantity, RuntimeUnitError>
+    {
+        let expected = match name { "sqrt" | "abs" => 1, "pow" => 2, _ => arguments.len().max(1) };
+        if arguments.len() != expected
+        {
+            return Err(self.error(&format!("Function \"{name}\" expects {expected} argument(s), got {}", arguments.len())));
+        }
+        match name
+        {
+            "sqrt" => check_relative(&arguments).and_then(|_| arguments[0].checked_powf(0.5)),
+            "pow" => power(arguments[0], arguments[1]),
+            "abs" => check_relative(&arguments).map(|_| Quantity::new(arguments[0].value.abs(), arguments[0].unit)),
+            "min" => extremum(name, arguments, core::cmp::Ordering::Less),
+            _ => extremum(name, arguments, core::cmp::Ordering::Greater),
+        }
+    }
+}
@@ -0,0 +1,530 @@
+//! Formatting of quantities for display.
+//!
+//! Quantities can be rescaled to the prefixed unit of the same family that is most readable for their value (e.g.
+//! `0.000000123 m` to `123 nm`, or `1610612736 B` to `1.5 GiB`), see [`AutoScale`]. A [`QuantityFormatter`] controls
+//! the precision and notation of values, and how units are written (e.g. `m/s^2`, `m·s⁻²` or `\mathrm{m\,s^{-2}}`).
+use crate::quantity::Quantity;
+use crate::units_base::UnitDefinition;
+use crate::{Quantities, Units};
+
+/// SI prefixes (symbol and factor), including both micro signs.
+const SI_PREFIXES: [(&str, f64); 26] = [
+    ("Q", 1.0E30), ("R", 1.0E27), ("Y", 1.0E24), ("Z", 1.0E21), ("E", 1.0E18), ("P", 1.0E15), ("T", 1.0E12),
+    ("G", 1.0E9), ("M", 1.0E6), ("k", 1.0E3), ("h", 1.0E2), ("da", 1.0E1), ("d", 1.0E-1), ("c", 1.0E-2),
+    ("m", 1.0E-3), ("µ", 1.0E-6), ("μ", 1.0E-6), ("n", 1.0E-9), ("p", 1.0E-12), ("f", 1.0E-15), ("a", 1.0E-18),
+    ("z", 1.0E-21), ("y", 1.0E-24), ("r", 1.0E-27), ("q", 1.0E-30), ("u", 1.0E-6),
+];
+
+/// Binary prefixes (symbol and factor).
+const BINARY_PREFIXES: [(&str, f64); 8] = [
+    ("Ki", 1024.0), ("Mi", 1048576.0), ("Gi", 1073741824.0), ("Ti", 1099511627776.0), ("Pi", 1125899906842624.0),
+    ("Ei", 1152921504606846976.0), ("Zi", 1180591620717411303424.0), ("Yi", 1208925819614629174706176.0),
+];
+
+/// Family of prefixed units considered when rescaling a quantity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum PrefixFamily
+{
+    /// SI prefixes that are powers of 1000 (e.g. `nm`, `µm`, `mm`, `m`, `km`).
+    #[default]
+    Si,
+    /// Binary prefixes (e.g. `B`, `KiB`, `MiB`, `GiB`).
+    Binary,
+}
+
+impl PrefixFamily
+{
+    /// Prefixes of the family. SI prefixes that are not powers of 1000 (centi, deci, deca, hecto) are not used.
+    fn prefixes(&self) -> Vec<(&'static str, f64)>
+    {
+        match self
+        {
+            PrefixFamily::Si => SI_PREFIXES.iter().copied().filter(|(prefix, _)| !matches!(*prefix, "c" | "d" | "da" | "h")).collect(),
+            PrefixFamily::Binary => BINARY_PREFIXES.to_vec(),
+        }
+    }
+}
+
+/// Options for rescaling a quantity to the most readable unit of its prefix family.
+///
+/// The unit chosen is the largest unit of the family whose value lies in `[min, max)`. Units on an absolute scale
+/// (e.g. degrees Celsius), zero and non-finite values are never rescaled.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AutoScale
+{
+    /// Family of prefixed units considered.
+    pub family: PrefixFamily,
+    /// Minimum (inclusive) absolute value of the rescaled quantity.
+    pub min: f64,
+    /// Maximum (exclusive) absolute value of the rescaled quantity.
+    pub max: f64,
+}
+
+impl Default for AutoScale
+{
+    fn default() -> Self {
+        Self { family: PrefixFamily::Si, min: 1.0, max: 1000.0 }
+    }
+}
+
+impl AutoScale
+{
+    /// Rescale using SI prefixes, with values in `[1, 1000)`.
+    pub fn si() -> Self
+    {
+        Self::default()
+    }
+    /// Rescale using binary prefixes, with values in `[1, 1024)`.
+    pub fn binary() -> Self
+    {
+        Self { family: PrefixFamily::Binary, min: 1.0, max: 1024.0 }
+    }
+    /// Use a given range `[min, max)` for the rescaled values.
+    pub fn with_range(self, min: f64, max: f64) -> Self
+    {
+        Self { min, max, ..self }
+    }
+}
+
+/// Check whether `multiplier` is `factor` times `base` (within floating point precision).
+fn is_prefixed(multiplier: f64, base: f64, factor: f64) -> bool
+{
+    (1.0 - multiplier / (base * factor)).abs() < 1e-9
+}
+
+/// Select the unit of the prefix family of `unit` that best displays `value` (expressed in `unit`), given all `units` of
+/// a quantity and their abbreviations and multipliers.
+pub(crate) fn auto_scale<U: Copy>(unit: U, value: f64, units: &[U], abbreviation: impl Fn(&U) -> &'static str, multiplier: impl Fn(&U) -> f64, scale: &AutoScale) -> U
+{
+    if value == 0.0 || !value.is_finite()
+    {
+        return unit;
+    }
+    // Find the unprefixed unit of the family, e.g. "m" for "km" (or "g" for "kg").
+    let find = |symbol: &str| units.iter().find(|candidate| abbreviation(candidate) == symbol);
+    let symbol = abbreviation(&unit);
+    let stem = SI_PREFIXES.iter().chain(BINARY_PREFIXES.iter())
+        .filter_map(|&(prefix, factor)| symbol.strip_prefix(prefix).filter(|rest| !rest.is_empty()).and_then(find).map(|stem| (stem, factor)))
+        .find(|(stem, factor)| is_prefixed(multiplier(&unit), multiplier(stem), *factor))
+        .map_or(unit, |(stem, _)| *stem);
+    let stem_symbol = abbreviation(&stem);
+    let stem_multiplier = multiplier(&stem);
+    let candidates: Vec<U> = core::iter::once(stem).chain(scale.family.prefixes().into_iter().filter_map(|(prefix, factor)|
+    {
+        find(&format!("{prefix}{stem_symbol}")).filter(|candidate| is_prefixed(multiplier(candidate), stem_multiplier, factor)).copied()
+    })).collect();
+
+    let base_value = (value * multiplier(&unit)).abs();
+    let mut in_range: Option<U> = None;
+    let mut above_min: Option<U> = None;
+    let mut smallest: Option<U> = None;
+    for candidate in candidates
+    {
+        let scaled = base_value / multiplier(&candidate);
+        let larger = |current: Option<U>| current.is_none_or(|current| multiplier(&candidate) > multiplier(&current));
+        if scaled >= scale.min && scaled < scale.max && larger(in_range)
+        {
+            in_range = Some(candidate);
+        }
+        if scaled >= scale.min && larger(above_min)
+        {
+            above_min = Some(candidate);
+        }
+        if smallest.is_none_or(|current| multiplier(&candidate) < multiplier(&current))
+        {
+            smallest = Some(candidate);
+        }
+    }
+    in_range.or(above_min).or(smallest).unwrap_or(unit)
+}
+
+/// Round a `value` to a given number of significant `digits` (e.g. to hide rounding errors of unit conversions).
+pub(crate) fn round_significant(value: f64, digits: i32) -> f64
+{
+    if value == 0.0 || !value.is_finite()
+    {
+        return value;
+    }
+    let scale = 10f64.powi(digits - 1 - value.abs().log10().floor() as i32);
+    if scale.is_finite() { (value * scale).round() / scale } else { value }
+}
+
+/// Convert an ASCII integer (e.g. `-2`) to Unicode superscripts (e.g. `⁻²`).
+fn superscript(value: &str) -> String
+{
+    value.chars().map(|c| match c
+    {
+        '0' => '⁰', '1' => '¹', '2' => '²', '3' => '³', '4' => '⁴', '5' => '⁵', '6' => '⁶', '7' => '⁷', '8' => '⁸', '9' => '⁹',
+        '-' => '⁻', '+' => '⁺',
+        c => c
+    }).collect()
+}
+
+/// Style used to write units and exponents.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum UnitStyle
+{
+    /// ASCII characters only, e.g. `kg*m/s^2` or `1.5e-7`.
+    #[default]
+    Ascii,
+    /// Unicode middle dots and superscripts, e.g. `kg·m·s⁻²` or `1.5×10⁻⁷`.
+    Unicode,
+    /// LaTeX math, e.g. `\mathrm{kg\,m\,s^{-2}}` or `1.5 \times 10^{-7}`.
+    Latex,
+}
+
+impl UnitStyle
+{
+    /// Write a unit `name` raised to the power `numerator / denominator`.
+    pub(crate) fn power(&self, name: &str, numerator: i16, denominator: i8) -> String
+    {
+        match (self, denominator)
+        {
+            (UnitStyle::Ascii, 1) => format!("{name}^{numerator}"),
+            (UnitStyle::Unicode, 1) => format!("{name}{}", superscript(&numerator.to_string())),
+            (UnitStyle::Ascii | UnitStyle::Unicode, _) => format!("{name}^({numerator}/{denominator})"),
+            (UnitStyle::Latex, 1) => format!("{name}^{{{numerator}}}"),
+            (UnitStyle::Latex, _) => format!("{name}^{{{numerator}/{denominator}}}"),
+        }
+    }
+
+    /// Write a named unit (e.g. an abbreviation such as `m/s²`) in this style. Named quotients are written with negative
+    /// powers in Unicode and LaTeX (e.g. `m·s⁻²`).
+    fn unit_name(&self, name: &str) -> String
+    {
+        match self
+        {
+            UnitStyle::Unicode => match quotient_factors(name)
+            {
+                Some(factors) => factors.iter().map(|&(base, power)| match power
+                {
+                    1 => base.to_owned(),
+                    power => format!("{base}{}", superscript(&power.to_string())),
+                }).collect::<Vec<_>>().join("·"),
+                None => name.to_owned(),
+            },
+            UnitStyle::Ascii =>
+            {
+                let mut result = String::with_capacity(name.len());
+                let mut exponent = false;
+                for c in name.chars()
+                {
+                    let ascii = match c
+                    {
+                        '⁰' => Some('0'), '¹' => Some('1'), '²' => Some('2'), '³' => Some('3'), '⁴' => Some('4'),
+                        '⁵' => Some('5'), '⁶' => Some('6'), '⁷' => Some('7'), '⁸' => Some('8'), '⁹' => Some('9'), '⁻' => Some('-'),
+                        _ => None
+                    };
+                    match ascii
+                    {
+                        Some(ascii) =>
+                        {
+                            if !exponent
+                            {
+                                result.push('^');
+                            }
+                            result.push(ascii);
+                        }
+                        None if c == '·' || c == '⋅' => result.push('*'),
+                        None => result.push(c),
+                    }
+                    exponent = ascii.is_some();
+                }
+                result
+            }
+            UnitStyle::Latex => format!("\\mathrm{{{}}}", latex_quotient(name).unwrap_or_else(|| latex_text(name))),
+        }
+    }
+}
+
+/// Write the factors of a named quotient of units (e.g. `m/s²` or `J/(kg·K)`) with negative powers in LaTeX, e.g.
+/// `m\,s^{-2}`. Returns `None` for names that are not a single quotient.
+fn latex_quotient(name: &str) -> Option<String>
+{
+    let factors: Vec<String> = quotient_factors(name)?.into_iter().map(|(base, power)| match power
+    {
+        1 => latex_text(base),
+        power => format!("{}^{{{power}}}", latex_text(base)),
+    }).collect();
+    Some(factors.join("\\,"))
+}
+
+/// Split a named quotient of units (e.g. `m/s²` or `J/(kg·K)`) into its factors and their powers, with negative powers
+/// for the factors of the denominator (e.g. `m` and `s⁻²`). Returns `None` for names that are not a single quotient.
+fn quotient_factors(name: &str) -> Option<Vec<(&str, i32)>>
+{
+    let (numerator, denominator) = name.split_once('/')?;
+    let denominator = denominator.strip_prefix('(').and_then(|denominator| denominator.strip_suffix(')')).unwrap_or(denominator);
+    if numerator.contains(['(', ')']) || denominator.contains(['/', '(', ')'])
+    {
+        return None;
+    }
+    let mut factors = Vec::new();
+    for (part, sign) in [(numerator, 1), (denominator, -1)]
+    {
+        for factor in part.split(|c: char| c == ' ' || crate::parser::MULTIPLY.contains(&c)).filter(|factor| !factor.is_empty())
+        {
+            let (base, exponent) = factor.split_at(factor.find(|c| crate::parser::superscript(c).is_some()).unwrap_or(factor.len()));
+            let exponent: i32 = match exponent
+            {
+                "" => 1,
+                exponent => exponent.chars().filter_map(crate::parser::superscript).collect::<String>().parse().ok()?
+            };
+            match (base, sign * exponent)
+            {
+                ("", _) => return None,
+                // The numerator of e.g. "1/(m²·J)"
+                ("1", 1) => continue,
+                (base, power) => factors.push((base, power)),
+            }
+        }
+    }
+    Some(factors)
+}
+
+/// Write a named unit in LaTeX, without the enclosing `\mathrm`.
+fn latex_text(name: &str) -> String
+{
+    let mut result = String::new();
+    let mut exponent = String::new();
+    for c in name.chars().chain(core::iter::once(' '))
+    {
+        let ascii = UnitStyle::Ascii.unit_name(&c.to_string());
+        if let Some(digit) = ascii.strip_prefix('^')
+        {
+            exponent.push_str(digit);
+            continue;
+        }
+        if !exponent.is_empty()
+        {
+            result.push_str(&format!("^{{{exponent}}}"));
+            exponent.clear();
+        }
+        match c
+        {
+            ' ' => result.push_str("\\,"),
+            '·' | '⋅' => result.push_str("\\cdot "),
+            '%' => result.push_str("\\%"),
+            'µ' | 'μ' => result.push_str("\\mu "),
+            '°' => result.push_str("^{\\circ}"),
+            c => result.push(c),
+        }
+    }
+    // Remove the separator added after the last character.
+    result.truncate(result.len() - 2);
+    result
+}
+
+/// Notation used to write values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Notation
+{
+    /// Positional notation, e.g. `0.000123`.
+    #[default]
+    Decimal,
+    /// Scientific notation with a single digit before the decimal point, e.g. `1.23e-4`.
+    Scientific,
+    /// Engineering notation with exponents that are multiples of 3, e.g. `123e-6`.
+    Engineering,
+}
+
+/// Precision used to write values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Precision
+{
+    /// Shortest representation of the value.
+    #[default]
+    Auto,
+    /// Fixed number of decimal places.
+    Decimals(usize),
+    /// Fixed number of significant figures.
+    SignificantFigures(usize),
+}
+
+/// Name used to write named units.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum UnitName
+{
+    /// Abbreviation, e.g. `km`.
+    #[default]
+    Abbreviation,
+    /// Singular name, e.g. `kilometer`.
+    Singular,
+    /// Plural name, e.g. `kilometers`.
+    Plural,
+    /// Singular name for values of one, plural name otherwise (e.g. `1 kilometer`, `2 kilometers`).
+    Agreement,
+}
+
+/// Configurable formatting of quantities, e.g. for reports and user interfaces.
+///
+/// ```ignore
+/// let formatter = QuantityFormatter::default().with_significant_figures(3).with_unit_style(UnitStyle::Unicode);
+/// assert_eq!(formatter.format(&Quantities::Acceleration(Acceleration::meter_per_second_squared(9.80665))), "9.81 m/s²");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct QuantityFormatter
+{
+    notation: Notation,
+    precision: Precision,
+    unit_name: UnitName,
+    unit_style: UnitStyle,
+    auto_scale: Option<AutoScale>,
+}
+
+impl QuantityFormatter
+{
+    /// Use a given `notation` for values.
+    pub fn with_notation(self, notation: Notation) -> Self
+    {
+        Self { notation, ..self }
+    }
+    /// Write values with a fixed number of decimal places.
+    pub fn with_decimals(self, decimals: usize) -> Self
+    {
+        Self { precision: Precision::Decimals(decimals), ..self }
+    }
+    /// Write values with a fixed number of significant figures.
+    pub fn with_significant_figures(self, figures: usize) -> Self
+    {
+        Self { precision: Precision::SignificantFigures(figures.max(1)), ..self }
+    }
+    /// Use a given `unit_name` for named units.
+    pub fn with_unit_name(self, unit_name: UnitName) -> Self
+    {
+        Self { unit_name, ..self }
+    }
+    /// Use a given `unit_style` for units and exponents.
+    pub fn with_unit_style(self, unit_style: UnitStyle) -> Self
+    {
+        Self { unit_style, ..self }
+    }
+    /// Rescale quantities to their most readable unit before formatting them.
+    pub fn with_auto_scale(self, auto_scale: AutoScale) -> Self
+    {
+        Self { auto_scale: Some(auto_scale), ..self }
+    }
+
+    /// Write a number with the given number of `decimals`, or the shortest representation.
+    fn fixed(value: f64, decimals: Option<usize>) -> String
+    {
+        match decimals
+        {
+            Some(decimals) => format!("{value:.decimals$}"),
+            None => format!("{}", round_significant(value, 15)),
+        }
+    }
+
+    /// Format a `value` given the notation and precision of this formatter.
+    pub fn format_value(&self, value: f64) -> String
+    {
+        if !value.is_finite()
+        {
+            return format!("{value}");
+        }
+        let magnitude = if value == 0.0 { 0 } else { value.abs().log10().floor() as i32 };
+        let exponent = match self.notation
+        {
+            Notation::Decimal =>
+            {
+                return match self.precision
+                {
+                    Precision::Auto => Self::fixed(value, None),
+                    Precision::Decimals(decimals) => Self::fixed(value, Some(decimals)),
+                    Precision::SignificantFigures(figures) =>
+                    {
+                        // Use the magnitude after rounding, which may carry into a further digit (e.g. 9.996 to 10.0)
+                        let value = round_significant(value, figures as i32);
+                        let magnitude = if value == 0.0 { 0 } else { value.abs().log10().floor() as i32 };
+                        Self::fixed(value, Some((figures as i32 - 1 - magnitude).max(0) as usize))
+                    }
+                };
+            }
+            Notation::Scientific => magnitude,
+            Notation::Engineering => magnitude.div_euclid(3) * 3,
+        };
+        let decimals = |magnitude: i32, exponent: i32| match self.precision
+        {
+            Precision::Auto => None,
+            Precision::Decimals(decimals) => Some(decimals),
+            Precision::SignificantFigures(figures) => Some((figures as i32 - 1 - (magnitude - exponent)).max(0) as usize),
+        };
+        let mut mantissa = Self::fixed(value / 10f64.powi(exponent), decimals(magnitude, exponent));
+        // Rounding may carry into a further digit (e.g. 9.996 to 10.00)
+        let limit = if self.notation == Notation::Scientific { 10.0 } else { 1000.0 };
+        let mut exponent = exponent;
+        if mantissa.parse::<f64>().is_ok_and(|rounded| rounded.abs() >= limit)
+        {
+            exponent += if self.notation == Notation::Scientific { 1 } else { 3 };
+            mantissa = Self::fixed(value / 10f64.powi(exponent), decimals(magnitude + 1, exponent));
+        }
+        match self.unit_style
+        {
+            UnitStyle::Ascii => format!("{mantissa}e{exponent}"),
+            UnitStyle::Unicode => format!("{mantissa}×10{}", superscript(&exponent.to_string())),
+            UnitStyle::Latex => format!("{mantissa} \\times 10^{{{exponent}}}"),
+        }
+    }
+
+    /// Join a formatted value and unit.
+    fn join(&self, value: String, unit: String) -> String
+    {
+        match (unit.is_empty(), self.unit_style)
+        {
+            (true, _) => value,
+            (false, UnitStyle::Latex) => format!("{value}\\,{unit}"),
+            (false, _) => format!("{value} {unit}"),
+        }
+    }
+
+    /// Format a named `unit`, for a given `value` (used for plural agreement).
+    pub fn format_unit(&self, unit: Units, value: f64) -> String
+    {
+        let name = match self.unit_name
+        {
+            UnitName::Abbreviation => return self.unit_style.unit_name(unit.abbreviation()),
+            UnitName::Singular => unit.singular(),
+            UnitName::Plural => unit.plural(),
+            UnitName::Agreement if value.abs() == 1.0 => unit.singular(),
+            UnitName::Agreement => unit.plural(),
+        };
+        match self.unit_style
+        {
+            UnitStyle::Latex => format!("\\text{{{name}}}"),
+            _ => name.to_owned()
+        }
+    }
+
+    /// Format a `UnitDefinition` using its base units (e.g. `kg*m/s^2`).
+    pub fn format_unit_definition(&self, unit: UnitDefinition) -> String
+    {
+        unit.base.to_string_styled(self.unit_style)
+    }
+
+    /// Format a quantity of a named unit.
+    pub fn format(&self, quantity: &Quantities) -> String
+    {
+        let quantity = match &self.auto_scale
+        {
+            Some(scale) => quantity.auto_scale(scale),
+            None => *quantity,
+        };
+        let value = quantity.value();
+        self.join(self.format_value(value), self.format_unit(quantity.unit(), value))
+    }
+
+    /// Format an arbitrary quantity. Quantities matching a named unit are formatted with that unit, others are
+    /// converted to their base units (e.g. `kg*m/s^2`).
+    pub fn format_quantity(&self, quantity: &Quantity) -> String
+    {
+        if Units::find(quantity.unit, 1e-9).is_some()
+        {
+            if let Ok(quantity) = quantity.try_into_quantities()
+            {
+                return self.format(&quantity);
+            }
+        }
+        let value = quantity.unit.base_value(quantity.value);
+        self.join(self.format_value(value), self.format_unit_definition(quantity.unit))
+    }
+}
@@ -0,0 +1,399 @@
+//! Logarithmic units: levels in decibels, bels or nepers, levels relative to a reference quantity (e.g. dBm, dBV or dB SPL),
+//! and cologarithmic scales such as pH.
+//!
+//! Levels cannot be expressed by a `UnitDefinition`, which only scales values, so they are stored in a [`Level`] with its
+//! [`LevelUnit`]. Levels of power quantities follow the 10·log₁₀ rule (`L = 10·log₁₀(P/P₀)` dB) and levels of field (root-power)
+//! quantities the 20·log₁₀ rule (`L = 20·log₁₀(F/F₀)` dB), with 1 Np = 20/ln(10) dB. Adding levels combines incoherent sources,
+//! by summing their powers: ratios without a reference (e.g. gains in dB) are not sources, and are combined with
+//! [`Level::apply_gain`] instead.
+use core::fmt::Display;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+use crate::errors::RuntimeUnitError;
+use crate::quantity::Quantity;
+use crate::traits::{ArbitraryQuantity, CheckedAdditive};
+use crate::units_base::UnitDefinition;
+
+/// Rule relating a level to the quantity it measures.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LevelKind
+{
+    /// Ratio without reference (e.g. a gain in dB), either of powers or of fields.
+    Ratio,
+    /// Level of a power quantity: `10·log₁₀(P/P₀)` dB.
+    Power,
+    /// Level of a field (root-power) quantity: `20·log₁₀(F/F₀)` dB.
+    Field,
+    /// Negative decimal logarithm: `-log₁₀(x/x₀)` (e.g. pH).
+    Cologarithm,
+}
+
+/// Logarithmic unit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(non_camel_case_types)]
+pub enum LevelUnit
+{
+    #[cfg_attr(feature="serde", serde(alias="dB"))]
+    decibel,
+    #[cfg_attr(feature="serde", serde(alias="B"))]
+    bel,
+    #[cfg_attr(feature="serde", serde(alias="Np"))]
+    neper,
+    /// Power level relative to 1 mW.
+    #[cfg_attr(feature="serde", serde(alias="dBm"))]
+    decibel_milliwatt,
+    /// Power level relative to 1 W.
+    #[cfg_attr(feature="serde", serde(alias="dBW"))]
+    decibel_watt,
+    /// Voltage level relative to 1 V.
+    #[cfg_attr(feature="serde", serde(alias="dBV"))]
+    decibel_volt,
+    /// Voltage level relative to 1 μV.
+    #[cfg_attr(feature="serde", serde(alias="dBμV"))]
+    decibel_microvolt,
+    /// Sound pressure level relative to 20 μPa.
+    #[cfg_attr(feature="serde", serde(alias="dB SPL"))]
+    decibel_sound_pressure_level,
+    /// Negative decimal logarithm of a concentration of hydrogen ions in mol/L (approximating their activity).
+    #[cfg_attr(feature="serde", serde(alias="pH"))]
+    ph,
+}
+
+impl LevelUnit
+{
+    /// All logarithmic units.
+    pub const ALL: [LevelUnit; 9] = [LevelUnit::decibel, LevelUnit::bel, LevelUnit::neper, LevelUnit::decibel_milliwatt, LevelUnit::decibel_watt,
+        LevelUnit::decibel_volt, LevelUnit::decibel_microvolt, LevelUnit::decibel_sound_pressure_level, LevelUnit::ph];
+
+    /// Abbreviation of unit.
+    pub fn abbreviation(&self) -> &'static str
+    {
+        match self
+        {
+            LevelUnit::decibel => "dB",
+            LevelUnit::bel => "B",
+            LevelUnit::neper => "Np",
+            LevelUnit::decibel_milliwatt => "dBm",
+            LevelUnit::decibel_watt => "dBW",
+            LevelUnit::decibel_volt => "dBV",
+            LevelUnit::decibel_microvolt => "dBμV",
+            LevelUnit::decibel_sound_pressure_level => "dB SPL",
+            LevelUnit::ph => "pH",
+        }
+    }
+
+    /// Rule relating a level in this unit to the quantity it measures.
+    pub fn kind(&self) -> LevelKind
+    {
+        match self
+        {
+            LevelUnit::decibel | LevelUnit::bel | LevelUnit::neper => LevelKind::Ratio,
+            LevelUnit::decibel_milliwatt | LevelUnit::decibel_watt => LevelKind::Power,
+            LevelUnit::decibel_volt | LevelUnit::decibel_microvolt | LevelUnit::decibel_sound_pressure_level => LevelKind::Field,
+            LevelUnit::ph => LevelKind::Cologarithm,
+        }
+    }
+
+    /// Reference quantity of levels in this unit (e.g. 1 mW for dBm), `None` for ratios.
+    pub fn reference(&self) -> Option<Quantity>
+    {
+        let unit = match self
+        {
+            LevelUnit::decibel | LevelUnit::bel | LevelUnit::neper => return None,
+            LevelUnit::decibel_milliwatt => UnitDefinition::new(1E-3, 2, 1, -3, 0, 0, 0, 0),
+            LevelUnit::decibel_watt => UnitDefinition::new(1.0, 2, 1, -3, 0, 0, 0, 0),
+            LevelUnit::decibel_volt => UnitDefinition::new(1.0, 2, 1, -3, -1, 0, 0, 0),
+            LevelUnit::decibel_microvolt => UnitDefinition::new(1E-6, 2, 1, -3, -1, 0, 0, 0),
+            LevelUnit::decibel_sound_pressure_level => UnitDefinition::new(20E-6, -1, 1, -2, 0, 0, 0, 0),
+            LevelUnit::ph => UnitDefinition::new(1E3, -3, 0, 0, 0, 0, 1, 0),
+        };
+        Some(Quantity::new(1.0, unit))
+    }
+
+    /// Number of decibels in one unit (e.g. 10 for bels), `None` for cologarithmic scales.
+    pub fn decibels(&self) -> Option<f64>
+    {
+        match self
+        {
+            LevelUnit::bel => Some(10.0),
+            LevelUnit::neper => Some(20.0 / core::f64::consts::LN_10),
+            LevelUnit::ph => None,
+            _ => Some(1.0)
+        }
+    }
+}
+
+impl Display for LevelUnit
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl FromStr for LevelUnit
+{
+    type Err = RuntimeUnitError;
+
+    /// Parses the abbreviation (e.g. `"dBm"`) or name (e.g. `"decibel_milliwatt"`) of a logarithmic unit.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        LevelUnit::ALL.into_iter().find(|unit| unit.abbreviation() == s || format!("{unit:?}") == s || (s == "dBuV" && *unit == LevelUnit::decibel_microvolt))
+            .ok_or_else(|| RuntimeUnitError::UnknownUnit { input: s.to_owned(), quantity: None })
+    }
+}
+
+#[doc = "A level in a logarithmic unit (e.g. 30 dBm)."]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Level
+{
+    pub(crate) value: f64,
+    pub(crate) unit: LevelUnit
+}
+
+impl Level
+{
+    ///
+    /// Create a new instance of `Level` with a given `value` and `unit`
+    ///
+    pub fn new(value: f64, unit: LevelUnit) -> Self
+    {
+        Self { value, unit }
+    }
+
+    /// Level of a `quantity` relative to the reference of `unit` (e.g. 1 W is 30 dBm), following the rule of its [`LevelKind`].
+    /// Fails for units without reference, if the dimensions of `quantity` do not match the reference, or if `quantity` is not
+    /// positive (`ValueOutOfRange`, its logarithm is not defined).
+    pub fn from_quantity(quantity: impl Into<Quantity>, unit: LevelUnit) -> Result<Level, RuntimeUnitError>
+    {
+        let reference = unit.reference().ok_or(RuntimeUnitError::NoReference { unit })?;
+        let ratio = positive(quantity.into().try_convert(reference.unit)?.value / reference.value)?;
+        let value = match unit.kind()
+        {
+            LevelKind::Power => 10.0 * ratio.log10(),
+            LevelKind::Field => 20.0 * ratio.log10(),
+            _ => -ratio.log10()
+        };
+        Ok(Self { value: Self::from_decibels(value, unit), unit })
+    }
+
+    /// Level of a ratio of powers (e.g. a power gain of 100 is 20 dB). Fails if `ratio` is not positive.
+    pub fn from_power_ratio(ratio: f64, unit: LevelUnit) -> Result<Level, RuntimeUnitError>
+    {
+        Self::from_ratio(10.0 * positive(ratio)?.log10(), unit)
+    }
+
+    /// Level of a ratio of fields (e.g. a voltage gain of 100 is 40 dB). Fails if `ratio` is not positive.
+    pub fn from_field_ratio(ratio: f64, unit: LevelUnit) -> Result<Level, RuntimeUnitError>
+    {
+        Self::from_ratio(20.0 * positive(ratio)?.log10(), unit)
+    }
+
+    fn from_ratio(decibels: f64, unit: LevelUnit) -> Result<Level, RuntimeUnitError>
+    {
+        match unit.kind()
+        {
+            LevelKind::Ratio => Ok(Self { value: Self::from_decibels(decibels, unit), unit }),
+            _ => Err(RuntimeUnitError::UnexpectedLevelKind { unit, expected: LevelKind::Ratio })
+        }
+    }
+
+    fn from_decibels(decibels: f64, unit: LevelUnit) -> f64
+    {
+        decibels / unit.decibels().unwrap_or(1.0)
+    }
+
+    /// Value of this level
+    #[inline]
+    pub fn value(&self) -> f64
+    {
+        self.value
+    }
+
+    /// Unit of this level
+    #[inline]
+    pub fn unit(&self) -> LevelUnit
+    {
+        self.unit
+    }
+
+    /// Value of this level in decibels, `None` for cologarithmic scales.
+    pub fn decibels(&self) -> Option<f64>
+    {
+        self.unit.decibels().map(|decibels| self.value * decibels)
+    }
+
+    /// Ratio of powers represented by this level (e.g. 20 dB is 100), `None` for cologarithmic scales.
+    pub fn power_ratio(&self) -> Option<f64>
+    {
+        self.decibels().map(|decibels| 10f64.powf(decibels / 10.0))
+    }
+
+    /// Ratio of fields represented by this level (e.g. 20 dB is 10), `None` for cologarithmic scales.
+    pub fn field_ratio(&self) -> Option<f64>
+    {
+        self.decibels().map(|decibels| 10f64.powf(decibels / 20.0))
+    }
+
+    /// Linear quantity represented by this level (e.g. 1 W for 30 dBm). Fails for ratios, which have no reference.
+    pub fn to_quantity(&self) -> Result<Quantity, RuntimeUnitError>
+    {
+        let reference = self.unit.reference().ok_or(RuntimeUnitError::NoReference { unit: self.unit })?;
+        let ratio = match self.unit.kind()
+        {
+            LevelKind::Power => self.power_ratio(),
+            LevelKind::Field => self.field_ratio(),
+            _ => None
+        }.unwrap_or_else(|| 10f64.powf(-self.value));
+        Ok(reference * ratio)
+    }
+
+    /// Convert to another logarithmic unit (e.g. dBm to dBW, or dB to Np). Fails between ratios and levels with a reference,
+    /// and between references of different dimensions.
+    pub fn try_convert(&self, unit: LevelUnit) -> Result<Level, RuntimeUnitError>
+    {
+        if self.unit == unit
+        {
+            return Ok(*self);
+        }
+        match (self.unit.reference(), unit.reference())
+        {
+            (None, None) => Ok(Self { value: Self::from_decibels(self.value * self.unit.decibels().unwrap_or(1.0), unit), unit }),
+            (Some(_), Some(_)) if (self.unit.kind() == LevelKind::Cologarithm) == (unit.kind() == LevelKind::Cologarithm) => Self::from_quantity(self.to_quantity()?, unit),
+            _ => Err(RuntimeUnitError::IncompatibleLevels { from: self.unit, to: unit })
+        }
+    }
+
+    /// Apply a `gain` (a ratio such as 10 dB, or an attenuation if negative) to this level.
+    pub fn apply_gain(&self, gain: Level) -> Result<Level, RuntimeUnitError>
+    {
+        match (gain.unit.kind(), gain.decibels(), self.unit.decibels())
+        {
+            (LevelKind::Ratio, Some(decibels), Some(unit_decibels)) => Ok(Self { value: self.value + decibels / unit_decibels, unit: self.unit }),
+            (LevelKind::Ratio, ..) => Err(RuntimeUnitError::IncompatibleLevels { from: gain.unit, to: self.unit }),
+            _ => Err(RuntimeUnitError::UnexpectedLevelKind { unit: gain.unit, expected: LevelKind::Ratio })
+        }
+    }
+
+    /// Combine the powers of this level and `rhs` (in decibels, in the unit of this level), with a `sign` of 1 to add or -1 to remove a source.
+    fn incoherent(&self, rhs: Level, sign: f64) -> Result<Level, RuntimeUnitError>
+    {
+        if self.unit.kind() == LevelKind::Ratio || rhs.unit.kind() == LevelKind::Ratio
+        {
+            // Ratios are not sources, see `Level::apply_gain` to apply a gain
+            return Err(RuntimeUnitError::IncompatibleLevels { from: rhs.unit, to: self.unit });
+        }
+        let (Some(lhs), Some(rhs)) = (self.decibels(), rhs.try_convert(self.unit)?.decibels()) else
+        {
+            return Err(RuntimeUnitError::IncompatibleLevels { from: rhs.unit, to: self.unit });
+        };
+        // Removing a source of more power than the total
+        let power = 10f64.powf(lhs / 10.0) + sign * 10f64.powf(rhs / 10.0);
+        if power < 0.0
+        {
+            return Err(RuntimeUnitError::ValueOutOfRange { value: power });
+        }
+        Ok(Self { value: Self::from_decibels(10.0 * power.log10(), self.unit), unit: self.unit })
+    }
+}
+
+/// Check that a `ratio` is positive, as its logarithm is otherwise not defined.
+fn positive(ratio: f64) -> Result<f64, RuntimeUnitError>
+{
+    if ratio > 0.0 { Ok(ratio) } else { Err(RuntimeUnitError::ValueOutOfRange { value: ratio }) }
+}
+
+impl CheckedAdditive for Level
+{
+    /// Level of the incoherent sum of two sources (e.g. 90 dB SPL + 90 dB SPL is about 93 dB SPL), in the unit of this level.
+    /// Ratios (e.g. gains in dB) are not sources and fail, see [`Level::apply_gain`].
+    fn checked_add(self, rhs: Level) -> Result<Self, RuntimeUnitError> {
+        self.incoherent(rhs, 1.0)
+    }
+
+    /// Level after removing the incoherent source `rhs` from the total given by this level (e.g. to subtract background noise).
+    fn checked_sub(self, rhs: Level) -> Result<Self, RuntimeUnitError> {
+        self.incoherent(rhs, -1.0)
+    }
+
+    fn try_add_assign(&mut self, rhs: Level) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_add(rhs)?;
+        Ok(())
+    }
+
+    fn try_sub_assign(&mut self, rhs: Level) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_sub(rhs)?;
+        Ok(())
+    }
+}
+
+impl Add<Level> for Level
+{
+    type Output = Level;
+
+    /// Add two incoherent sources, see [`CheckedAdditive::checked_add`]. Panics on incompatible units.
+    fn add(self, rhs: Level) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Sub<Level> for Level
+{
+    type Output = Level;
+
+    /// Remove an incoherent source, see [`CheckedAdditive::checked_sub`]. Panics on incompatible units or if `rhs` exceeds this level.
+    fn sub(self, rhs: Level) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl AddAssign for Level
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Level
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Display for Level
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.unit
+        {
+            LevelUnit::ph => write!(f, "pH {}", self.value),
+            unit => write!(f, "{} {}", self.value, unit)
+        }
+    }
+}
+
+impl FromStr for Level
+{
+    type Err = RuntimeUnitError;
+
+    /// Parses a string into a `Level`.
+    ///
+    /// # Format
+    /// The expected input format is: `<value> <unit>`, e.g. `"30 dBm"` or `"94 dB SPL"`, or `"pH <value>"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(value) = s.strip_prefix("pH")
+        {
+            let value = value.trim();
+            return Ok(Self::new(value.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: value.to_owned() })?, LevelUnit::ph));
+        }
+        let mut parts = s.splitn(2, char::is_whitespace);
+        let value_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing value".into()))?;
+        let unit_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing unit".into()))?;
+        let value: f64 = value_str.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: value_str.to_owned() })?;
+        Ok(Self::new(value, unit_str.parse()?))
+    }
+}
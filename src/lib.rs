@@ -1,12 +1,40 @@
 use quantity::Quantity;
+pub mod analysis;
 pub mod array_quantity;
+#[cfg(feature="arrow")]
+pub mod arrow;
+pub(crate) mod complex_macros;
+pub mod complex_quantity;
+pub use complex_quantity::{Complex, ComplexQuantity};
+pub mod constants;
+pub mod csv;
 pub mod errors;
+pub mod expression;
+pub use expression::eval;
+pub mod format;
+pub mod level;
+pub use level::{Level, LevelUnit};
 pub(crate) mod macros;
+pub mod measured_quantity;
+#[cfg(feature="ndarray")]
+pub mod ndarray_quantity;
+#[cfg(feature="ndarray")]
+pub use ndarray_quantity::QuantityNd;
+pub use measured_quantity::MeasuredQuantity;
 pub(crate) mod mutivalue_macros;
+pub mod parser;
 pub(crate) mod quantity;
+pub mod registry;
+pub mod slice;
+pub mod slice_quantity;
+pub mod table;
+pub use table::QuantityTable;
 mod tests;
 pub mod traits;
 pub mod units_base;
 mod unit_definitions;
 pub use crate::unit_definitions::*;
-pub mod vector_quantity;
\ No newline at end of file
+pub mod vector_quantity;
+pub(crate) mod vector3_macros;
+pub mod vector3_quantity;
+pub use vector3_quantity::Vector3Quantity;
\ No newline at end of file
@@ -35,184 +35,299 @@ macro_rules! prefix {
     (kibi) => { 1024.0 };
 }
 
+#[macro_export]
+#[doc(hidden)]
+macro_rules! unit_offset {
+    () => { None };
+    ($offset:expr) => { Some($offset) };
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! quantity_kind {
+    () => { None };
+    ($kind:ident) => { Some($crate::units_base::QuantityKind::$kind) };
+}
+
+/// Expand `$item` only for the quantities with a typed variant of the given shape: `complex` for electrical quantities
+/// (e.g. impedances, phasors, complex powers and permittivities) and `Angle`, `vector3` for positions and their
+/// derivatives, forces, momenta and torques, and vector fields (e.g. electric and magnetic fields, heat flux densities).
+#[macro_export]
+#[doc(hidden)]
+macro_rules! if_typed_variant {
+    (complex, Angle, $($item:tt)*) => { $($item)* };
+    (complex, Capacitance, $($item:tt)*) => { $($item)* };
+    (complex, ElectricCharge, $($item:tt)*) => { $($item)* };
+    (complex, ElectricCurrent, $($item:tt)*) => { $($item)* };
+    (complex, ElectricCurrentDensity, $($item:tt)*) => { $($item)* };
+    (complex, ElectricField, $($item:tt)*) => { $($item)* };
+    (complex, ElectricPermittivity, $($item:tt)*) => { $($item)* };
+    (complex, ElectricPotential, $($item:tt)*) => { $($item)* };
+    (complex, ElectricalConductance, $($item:tt)*) => { $($item)* };
+    (complex, ElectricalConductivity, $($item:tt)*) => { $($item)* };
+    (complex, ElectricalResistance, $($item:tt)*) => { $($item)* };
+    (complex, ElectricalResistivity, $($item:tt)*) => { $($item)* };
+    (complex, Inductance, $($item:tt)*) => { $($item)* };
+    (complex, MagneticFieldStrength, $($item:tt)*) => { $($item)* };
+    (complex, MagneticFlux, $($item:tt)*) => { $($item)* };
+    (complex, MagneticFluxDensity, $($item:tt)*) => { $($item)* };
+    (complex, MagneticPermeability, $($item:tt)*) => { $($item)* };
+    (complex, Power, $($item:tt)*) => { $($item)* };
+    (vector3, Acceleration, $($item:tt)*) => { $($item)* };
+    (vector3, AngularAcceleration, $($item:tt)*) => { $($item)* };
+    (vector3, AngularVelocity, $($item:tt)*) => { $($item)* };
+    (vector3, ElectricCurrentDensity, $($item:tt)*) => { $($item)* };
+    (vector3, ElectricDipoleMoment, $($item:tt)*) => { $($item)* };
+    (vector3, ElectricDisplacementField, $($item:tt)*) => { $($item)* };
+    (vector3, ElectricField, $($item:tt)*) => { $($item)* };
+    (vector3, Force, $($item:tt)*) => { $($item)* };
+    (vector3, HeatFluxDensity, $($item:tt)*) => { $($item)* };
+    (vector3, Jerk, $($item:tt)*) => { $($item)* };
+    (vector3, Length, $($item:tt)*) => { $($item)* };
+    (vector3, MagneticFieldStrength, $($item:tt)*) => { $($item)* };
+    (vector3, MagneticFluxDensity, $($item:tt)*) => { $($item)* };
+    (vector3, MagneticMoment, $($item:tt)*) => { $($item)* };
+    (vector3, Momentum, $($item:tt)*) => { $($item)* };
+    (vector3, TemperatureGradient, $($item:tt)*) => { $($item)* };
+    (vector3, Torque, $($item:tt)*) => { $($item)* };
+    (vector3, Velocity, $($item:tt)*) => { $($item)* };
+    ($variant:ident, $quantity:ident, $($item:tt)*) => {};
+}
+
 #[macro_export]
 macro_rules! impl_quantity_ops {   
     ($quantity:ident) =>
     {
         use $crate::traits::IsScalarQuantity;
-        impl Mul<f64> for $quantity
+        impl<T: Numeric> Mul<f64> for $quantity<T>
         {
-            type Output = $quantity;
+            type Output = $quantity<T>;
 
             fn mul(self, rhs: f64) -> Self::Output {
-                Self { value: self.value*rhs, unit: self.unit }
+                Self { value: self.value.mul_f64(rhs), unit: self.unit }
             }
         }
-        impl Div<f64> for $quantity
+        impl<T: Numeric> Div<f64> for $quantity<T>
         {
-            type Output = $quantity;
+            type Output = $quantity<T>;
 
             fn div(self, rhs: f64) -> Self::Output {
-                Self { value: self.value/rhs, unit: self.unit }
+                Self { value: self.value.div_f64(rhs), unit: self.unit }
             }
         }
 
         
-        impl MulAssign<f64> for $quantity
+        impl<T: Numeric> MulAssign<f64> for $quantity<T>
         {
             fn mul_assign(&mut self, rhs: f64) {
-                self.value *= rhs;
+                self.value = self.value.mul_f64(rhs);
             }
         }
 
 
-        impl DivAssign<f64> for $quantity
+        impl<T: Numeric> DivAssign<f64> for $quantity<T>
         {
             fn div_assign(&mut self, rhs: f64) {
-                self.value /= rhs;
+                self.value = self.value.div_f64(rhs);
             }
         }
 
-        impl PartialEq<Quantity> for $quantity
+        impl<T: Numeric> PartialEq<Quantity<T>> for $quantity<T>
         {
-            fn eq(&self, other: &Quantity) -> bool {
+            fn eq(&self, other: &Quantity<T>) -> bool {
                 Quantity::from(*self).eq(other)
             }
         }
         
-        impl PartialEq<$quantity> for Quantity
+        impl<T: Numeric> PartialEq<$quantity<T>> for Quantity<T>
         {
-            fn eq(&self, other: &$quantity) -> bool {
+            fn eq(&self, other: &$quantity<T>) -> bool {
                 *other == *self
             }
         }
 
-        impl PartialEq<$quantity> for $quantity
+        impl<T: Numeric> PartialEq<$quantity<T>> for $quantity<T>
         {
-            fn eq(&self, other: &$quantity) -> bool {
-                self.value * self.definition().multiplier == other.value * other.definition().multiplier
+            fn eq(&self, other: &$quantity<T>) -> bool {
+                self.definition().base_value(self.value.to_f64()) == other.definition().base_value(other.value.to_f64())
             }
         }
         
-        impl PartialOrd for $quantity
+        impl<T: Numeric> PartialOrd for $quantity<T>
         {
             fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-                (self.value * self.definition().multiplier).partial_cmp(&(other.value * other.definition().multiplier))
+                self.definition().base_value(self.value.to_f64()).partial_cmp(&other.definition().base_value(other.value.to_f64()))
             }        
         }
 
-        impl<T:IsScalarQuantity> Mul<T> for $quantity
+        impl<T: Numeric, Q: IsScalarQuantity<Value = T>> Mul<Q> for $quantity<T>
         {
-            type Output = Quantity;
-            fn mul(self, rhs: T) -> Quantity {
-                Quantity{ value: self.value*rhs.value(), unit: self.definition()*rhs.unit() }
+            type Output = Quantity<T>;
+            fn mul(self, rhs: Q) -> Quantity<T> {
+                Quantity{ value: self.definition().relative_value(self.value)*rhs.unit().relative_value(rhs.value()), unit: self.definition()*rhs.unit() }
             }
         }
-        impl<T:IsScalarQuantity> Div<T> for $quantity
+        impl<T: Numeric, Q: IsScalarQuantity<Value = T>> Div<Q> for $quantity<T>
         {
-            type Output = Quantity;
+            type Output = Quantity<T>;
 
-            fn div(self, rhs: T) -> Quantity {
-                Quantity{ value: self.value/rhs.value(), unit: self.definition()/rhs.unit() }
+            fn div(self, rhs: Q) -> Quantity<T> {
+                Quantity{ value: self.definition().relative_value(self.value)/rhs.unit().relative_value(rhs.value()), unit: self.definition()/rhs.unit() }
             }
         }
+    }
+}
 
-        impl Add<$quantity> for $quantity
+#[macro_export]
+macro_rules! impl_quantity_additive_ops {   
+    ($quantity:ident) =>
+    {
+        impl<T: Numeric> Add<$quantity<T>> for $quantity<T>
         {
             type Output=Self;
 
-            fn add(self, rhs: $quantity) -> Self {
+            fn add(self, rhs: $quantity<T>) -> Self {
                 let rhs_value = rhs.convert_unchecked(self.definition());
                 Self{ value: self.value + rhs_value, unit: self.unit }
             }
         }
-        impl Sub<$quantity> for $quantity
+        impl<T: Numeric> Sub<$quantity<T>> for $quantity<T>
         {
             type Output=Self;
-            fn sub(self, rhs: $quantity) -> Self {
+            fn sub(self, rhs: $quantity<T>) -> Self {
                 let rhs_value = rhs.convert_unchecked(self.definition());
                 Self{ value: self.value - rhs_value, unit: self.unit }
             }
         }       
 
-        impl AddAssign for $quantity
+        impl<T: Numeric> AddAssign for $quantity<T>
         {
             fn add_assign(&mut self, rhs: Self) {
                 let rhs_value = rhs.convert_unchecked(self.definition());
-                self.value += rhs_value;
+                self.value = self.value + rhs_value;
             }
         }
 
-        impl SubAssign for $quantity
+        impl<T: Numeric> SubAssign for $quantity<T>
         {
             fn sub_assign(&mut self, rhs: Self) {
                 let rhs_value = rhs.convert_unchecked(self.definition());
-                self.value -= rhs_value;
+                self.value = self.value - rhs_value;
             }
         }
 
-        impl Add<Quantity> for $quantity
+        impl<T: Numeric> $crate::traits::CheckedAdditive for $quantity<T>
+        {
+            fn checked_add(self, rhs: Self) -> Result<Self, RuntimeUnitError> {
+                $crate::traits::CheckedAdditive::checked_add(self, Quantity::from(rhs))
+            }
+
+            fn checked_sub(self, rhs: Self) -> Result<Self, RuntimeUnitError> {
+                $crate::traits::CheckedAdditive::checked_sub(self, Quantity::from(rhs))
+            }
+
+            fn try_add_assign(&mut self, rhs: Self) -> Result<(), RuntimeUnitError> {
+                *self = $crate::traits::CheckedAdditive::checked_add(*self, rhs)?;
+                Ok(())
+            }
+
+            fn try_sub_assign(&mut self, rhs: Self) -> Result<(), RuntimeUnitError> {
+                *self = $crate::traits::CheckedAdditive::checked_sub(*self, rhs)?;
+                Ok(())
+            }
+        }
+
+        impl<T: Numeric> $quantity<T>
+        {
+            /// Value of `rhs` in the unit of this quantity, failing with `RuntimeUnitError::ValueOutOfRange` if `T` cannot
+            /// represent it (e.g. 1 km as `u8` meters).
+            fn try_operand(&self, rhs: Quantity<T>) -> Result<T, RuntimeUnitError>
+            {
+                let factor = rhs.unit.try_convert(self.definition())?;
+                rhs.value.try_scale(factor, 0.0).ok_or(RuntimeUnitError::ValueOutOfRange { value: rhs.value.to_f64() * factor })
+            }
+        }
+
+        impl<T: Numeric> $crate::traits::CheckedAdditive<Quantity<T>> for $quantity<T>
+        {
+            fn checked_add(self, rhs: Quantity<T>) -> Result<Self, RuntimeUnitError> {
+                let rhs = self.try_operand(rhs)?;
+                let value = self.value.try_add(rhs).ok_or(RuntimeUnitError::ValueOutOfRange { value: self.value.to_f64() + rhs.to_f64() })?;
+                Ok(Self{ value, unit: self.unit })
+            }
+
+            fn checked_sub(self, rhs: Quantity<T>) -> Result<Self, RuntimeUnitError> {
+                let rhs = self.try_operand(rhs)?;
+                let value = self.value.try_sub(rhs).ok_or(RuntimeUnitError::ValueOutOfRange { value: self.value.to_f64() - rhs.to_f64() })?;
+                Ok(Self{ value, unit: self.unit })
+            }
+
+            fn try_add_assign(&mut self, rhs: Quantity<T>) -> Result<(), RuntimeUnitError> {
+                *self = $crate::traits::CheckedAdditive::checked_add(*self, rhs)?;
+                Ok(())
+            }
+
+            fn try_sub_assign(&mut self, rhs: Quantity<T>) -> Result<(), RuntimeUnitError> {
+                *self = $crate::traits::CheckedAdditive::checked_sub(*self, rhs)?;
+                Ok(())
+            }
+        }
+
+        impl<T: Numeric> Add<Quantity<T>> for $quantity<T>
         {
             type Output=Self;
 
-            fn add(self, rhs: Quantity) -> Self {
-                if self.definition().base != rhs.unit.base
-                {
-                    panic!("Incompatible units added");
-                }
-                let rhs_value = rhs.convert_unchecked(self.definition());
-                Self{ value: self.value + rhs_value, unit: self.unit }
+            fn add(self, rhs: Quantity<T>) -> Self {
+                $crate::traits::CheckedAdditive::checked_add(self, rhs).unwrap_or_else(|error| panic!("{error}"))
             }
         }
-        impl Sub<Quantity> for $quantity
+        impl<T: Numeric> Sub<Quantity<T>> for $quantity<T>
         {
             type Output=Self;
-            fn sub(self, rhs: Quantity) -> Self {
-                if self.definition().base != rhs.unit.base
-                {
-                    panic!("Incompatible units subtracted");
-                }
-                let rhs_value = rhs.convert_unchecked(self.definition());
-                Self{ value: self.value - rhs_value, unit: self.unit }
+            fn sub(self, rhs: Quantity<T>) -> Self {
+                $crate::traits::CheckedAdditive::checked_sub(self, rhs).unwrap_or_else(|error| panic!("{error}"))
             }
         }       
 
-        impl AddAssign<Quantity> for $quantity
+        impl<T: Numeric> AddAssign<Quantity<T>> for $quantity<T>
         {
-            fn add_assign(&mut self, rhs: Quantity) {
-                if self.definition().base != rhs.unit.base
-                {
-                    panic!("Incompatible units subtracted");
-                }
-                let rhs_value = rhs.convert_unchecked(self.definition());
-                self.value += rhs_value;
+            fn add_assign(&mut self, rhs: Quantity<T>) {
+                $crate::traits::CheckedAdditive::try_add_assign(self, rhs).unwrap_or_else(|error| panic!("{error}"))
             }
         }
 
-        impl SubAssign<Quantity> for $quantity
+        impl<T: Numeric> SubAssign<Quantity<T>> for $quantity<T>
         {
-            fn sub_assign(&mut self, rhs: Quantity) {
-                if self.definition().base != rhs.unit.base
-                {
-                    panic!("Incompatible units subtracted");
-                }
-                let rhs_value = rhs.convert_unchecked(self.definition());
-                self.value -= rhs_value;
+            fn sub_assign(&mut self, rhs: Quantity<T>) {
+                $crate::traits::CheckedAdditive::try_sub_assign(self, rhs).unwrap_or_else(|error| panic!("{error}"))
             }
         }
-
-
     }
 }
 
+/// Implement addition and subtraction for a quantity and its array and vector storage. Quantities whose units carry
+/// an offset are on an absolute scale (e.g. thermodynamic temperature), and define their additive operations against 
+/// their relative quantity by hand instead.
+#[macro_export]
+macro_rules! impl_additive_ops {
+    ($quantity:ident $(, $offset:expr)+) => {};
+    ($quantity:ident) =>
+    {
+        use $crate::{impl_quantity_additive_ops, impl_quantity_vec_additive_ops, impl_quantity_array_additive_ops};
+        impl_quantity_additive_ops!($quantity);
+        impl_quantity_vec_additive_ops!($quantity);
+        impl_quantity_array_additive_ops!($quantity);
+    };
+}
+
 #[macro_export]
 macro_rules! quantity {
     (        
         $(#[$quantity_attr:meta])* quantity: $quantity:ident; $description:expr;
         $(#[$dim_attr:meta])* dimension: $system:ident[$($dimension:expr),+];
-        $(kind: $kind:ty;)?
+        $(kind: $kind:ident;)?
         units {            
-            $($(#[$unit_attr:meta])* @$unit:ident: $conversion:expr; $abbreviation:literal,
+            $($(#[$unit_attr:meta])* @$unit:ident: $conversion:expr $(, $offset:expr)?; $abbreviation:literal,
                 $singular:literal, $plural:literal;)+
         }
     ) => {
@@ -221,6 +336,7 @@ macro_rules! quantity {
         use static_init::dynamic;
         use $crate::errors::RuntimeUnitError;
         use $crate::Quantity;
+        use $crate::traits::Numeric;
         use $crate::units_base::{UnitDefinition, UnitBase};
         paste::paste!{
         $(#[$quantity_attr])*
@@ -245,13 +361,15 @@ macro_rules! quantity {
 
         paste::paste! { 
             #[dynamic]
-            pub(crate) static [<$quantity:upper _UNIT_BASE>]: UnitBase = $crate::units_base::to_unit_base(($($dimension,)+));
+            pub(crate) static [<$quantity:upper _UNIT_BASE>]: UnitBase = $crate::units_base::with_kind_dimensions($crate::units_base::to_unit_base(($($dimension,)+)), [<$quantity:upper _KIND>]);
             $(pub(crate) const [<$quantity:upper _ $unit:upper _conversion:upper>]: f64 = $conversion;)+
+            $(pub(crate) const [<$quantity:upper _ $unit:upper _offset:upper>]: Option<f64> = $crate::unit_offset!($($offset)?);)+
+            pub(crate) const [<$quantity:upper _KIND>]: Option<$crate::units_base::QuantityKind> = $crate::quantity_kind!($($kind)?);
         const [<$quantity:upper _BASE_UNIT>]: [<$quantity Unit>] =  [<get_base_unit_ $quantity:lower>]();
         const fn [<get_base_unit_ $quantity:lower>]() -> [<$quantity Unit>] 
         {
             
-            $(if [<$quantity Unit>]::$unit.multiplier() == 1.0
+            $(if [<$quantity Unit>]::$unit.multiplier() == 1.0 && matches!([<$quantity Unit>]::$unit.offset(), None | Some(0.0))
             {
                 return [<$quantity Unit>]::$unit;
             })+
@@ -264,7 +382,7 @@ macro_rules! quantity {
                 #[allow(clippy::eq_op)]
                 pub fn [<get_$unit:snake>]() -> UnitDefinition
                 {
-                    UnitDefinition{ base: *[<$quantity:upper _UNIT_BASE>], multiplier: [<$quantity:upper _ $unit:upper _conversion:upper>] }
+                    UnitDefinition{ base: *[<$quantity:upper _UNIT_BASE>], multiplier: [<$quantity:upper _ $unit:upper _conversion:upper>], offset: [<$quantity:upper _ $unit:upper _offset:upper>], kind: [<$quantity:upper _KIND>] }
                 })+
             }        
             #[doc = "Multiplier of unit to its base quantity."]
@@ -276,6 +394,19 @@ macro_rules! quantity {
                     $([<$quantity Unit>]::$unit => [<$quantity:upper _ $unit:upper _conversion:upper>],)+
                 }
             }
+            #[doc = "Offset of an absolute unit to the zero of its base unit (`None` for relative units)."]
+            pub const fn offset(&self) -> Option<f64>
+            {
+                match self
+                {
+                    $([<$quantity Unit>]::$unit => [<$quantity:upper _ $unit:upper _offset:upper>],)+
+                }
+            }
+            #[doc = "Kind of the quantity of this unit, distinguishing it from quantities of the same dimensions (`None` if not needed)."]
+            pub const fn kind(&self) -> Option<$crate::units_base::QuantityKind>
+            {
+                [<$quantity:upper _KIND>]
+            }
             #[doc = "Abbreviation of unit."]
             pub fn abbreviation(&self) -> &'static str
             {
@@ -300,6 +431,51 @@ macro_rules! quantity {
                     $([<$quantity Unit>]::$unit => $plural,)+
                 }
             }
+            #[doc = "Identifier of unit (e.g. `kilometer`), which is unique within its quantity."]
+            pub fn identifier(&self) -> &'static str
+            {
+                match self
+                {
+                    $([<$quantity Unit>]::$unit => stringify!($unit),)+
+                }
+            }
+            #[doc = "Find the unit with a given identifier, see `identifier`."]
+            pub fn from_identifier(identifier: &str) -> Option<Self>
+            {
+                Self::variants().iter().copied().find(|unit| unit.identifier() == identifier)
+            }
+            #[doc = "All units of this quantity."]
+            pub fn variants() -> &'static [Self]
+            {
+                const VARIANTS: &[[<$quantity Unit>]] = &[ $([<$quantity Unit>]::$unit,)+ ];
+                VARIANTS
+            }
+            #[doc = "Find the unit of the prefix family of this unit best suited to display a `value` given in this unit, see `format::AutoScale`."]
+            pub fn auto_scale(&self, value: f64, scale: &$crate::format::AutoScale) -> Self
+            {
+                if self.offset().is_some()
+                {
+                    return *self;
+                }
+                $crate::format::auto_scale(*self, value, Self::variants(), Self::abbreviation, Self::multiplier, scale)
+            }
+            #[doc = "Find the unit best matching a given `UnitDefinition`, within a relative `tolerance` on its multiplier."]
+            pub fn find(unit: UnitDefinition, tolerance: f64) -> Option<Self>
+            {
+                let mut best: Option<(Self, f64)> = None;
+                $(
+                    let candidate = [<$quantity Unit>]::[<get_$unit:snake>]();
+                    if candidate.approx_eq(unit, tolerance)
+                    {
+                        let error = (1.0 - unit.multiplier / candidate.multiplier).abs();
+                        if best.is_none_or(|(_, best_error)| error < best_error)
+                        {
+                            best = Some(([<$quantity Unit>]::$unit, error));
+                        }
+                    }
+                )+
+                best.map(|(unit, _)| unit)
+            }
             #[doc = "Available units for this `[" [<$quantity Unit>] "`]."]
             pub fn units() -> &'static [&'static str]
             {
@@ -353,17 +529,20 @@ macro_rules! quantity {
                 {
                     if value.base != *[<$quantity:upper _UNIT_BASE>] 
                     {
-                        return Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Base mismatch: {:?} vs {}", value, stringify!($quantity))));
+                        return Err(RuntimeUnitError::IncompatibleDimensions { from: value.base, to: *[<$quantity:upper _UNIT_BASE>] });
                     }
-                    match value.multiplier 
+                    let default: UnitDefinition = [<$quantity Unit>]::default().into();
+                    if !value.is_same_kind(default)
                     {
-                        $(
-                            [<$quantity:upper _ $unit:upper _conversion:upper>] => Ok([<$quantity Unit>]::$unit),
-                        )+
-                        _ => Err(RuntimeUnitError::IncompatibleUnitConversion(
-                            format!("Unknown multiplier: {:?} for {}", value, stringify!($quantity))
-                        ))
+                        return Err(RuntimeUnitError::IncompatibleKinds { from: value.kind, to: default.kind });
                     }
+                    $(
+                        if value.multiplier == [<$quantity:upper _ $unit:upper _conversion:upper>] && value.offset == [<$quantity:upper _ $unit:upper _offset:upper>]
+                        {
+                            return Ok([<$quantity Unit>]::$unit);
+                        }
+                    )+
+                    Err(RuntimeUnitError::UnknownUnit { input: value.to_string(), quantity: Some($crate::UnitTypes::$quantity) })
                 }
             }
             impl TryFrom<$crate::Units> for [<$quantity Unit>]
@@ -374,38 +553,38 @@ macro_rules! quantity {
                     match value
                     {
                         $($crate::Units::$quantity([<$quantity Unit>]::$unit) => Ok([<$quantity Unit>]::$unit),)+
-                        _ => Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from {} to {}", value, stringify!($quantity))))
+                        _ => Err(RuntimeUnitError::quantity_mismatch(value.into(), [<$quantity Unit>]::default().into()))
                     }
                 }
             }
             // Permit creation of `Units` from a `FixedQuantity`
-            impl From<[<$quantity>]> for $crate::Units
+            impl<T: Numeric> From<[<$quantity>]<T>> for $crate::Units
             {
-                fn from(value: [<$quantity>]) -> Self {
+                fn from(value: [<$quantity>]<T>) -> Self {
                     $crate::Units::$quantity(value.unit)
                 }
             }        
 
             // Permit creation of `Units` from a &`FixedQuantity`
-            impl From<&[<$quantity>]> for $crate::Units
+            impl<T: Numeric> From<&[<$quantity>]<T>> for $crate::Units
             {
-                fn from(value: &[<$quantity>]) -> Self {
+                fn from(value: &[<$quantity>]<T>) -> Self {
                     $crate::Units::$quantity(value.unit)
                 }
             }      
 
             // Permit creation of `Units` from a `FixedSliceQuantity`
-            impl From<[<$quantity Vec>]> for $crate::Units
+            impl<T: Numeric> From<[<$quantity Vec>]<T>> for $crate::Units
             {
-                fn from(value:[<$quantity Vec>]) -> Self {
+                fn from(value:[<$quantity Vec>]<T>) -> Self {
                     $crate::Units::$quantity(value.unit)
                 }
             }     
 
              // Permit creation of `Units` from a &`FixedSliceQuantity`
-             impl From<&[<$quantity Vec>]> for $crate::Units
+             impl<T: Numeric> From<&[<$quantity Vec>]<T>> for $crate::Units
              {
-                 fn from(value: &[<$quantity Vec>]) -> Self {
+                 fn from(value: &[<$quantity Vec>]<T>) -> Self {
                      $crate::Units::$quantity(value.unit)
                  }
              }     
@@ -419,20 +598,15 @@ macro_rules! quantity {
             #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
             #[cfg_attr(feature="utoipa", derive(ToSchema))]
             #[cfg_attr(feature="utoipa", schema(title = "" [<$quantity>]))]
-            #[doc = "Scalar storage of a quantity (f64 and [`" [<$quantity Unit>]"`])."]   
-            pub struct $quantity
+            #[doc = "Scalar storage of a quantity (a `Numeric` value, f64 by default, and [`" [<$quantity Unit>]"`])."]   
+            pub struct $quantity<T = f64>
             {
-                pub(crate) value: f64,
+                pub(crate) value: T,
                 pub(crate) unit: [<$quantity Unit>]   
             }
           
             impl $quantity
             {
-                #[doc = "Create a new [`" [<$quantity Unit>]"`]."]   
-                pub fn new(value: f64, unit: [<$quantity Unit>]) -> Self
-                {
-                    Self {value, unit }
-                }
                 $(
                     #[doc = "Create a new [`" [<$quantity>] "`] with units of [`" [<$quantity Unit>] "::" [<$unit>] "`]."] 
                     pub fn [<$unit:snake>](value: f64) -> Self
@@ -440,17 +614,26 @@ macro_rules! quantity {
                         Self { value, unit: [<$quantity Unit>]::$unit.into() }
                     }
                 )+
+            }
+
+            impl<T: Numeric> $quantity<T>
+            {
+                #[doc = "Create a new [`" [<$quantity Unit>]"`] storing a value of any [`Numeric`] type (e.g. `f32`, `i64`)."]   
+                pub fn new(value: T, unit: [<$quantity Unit>]) -> Self
+                {
+                    Self {value, unit }
+                }
 
                 #[doc = "Retrieve the value associated with this [`" [<$quantity>]"`]."]   
                 #[inline]
-                pub fn value(&self) -> f64
+                pub fn value(&self) -> T
                 {
                     self.value
                 }
 
                 #[doc = "Retrieve the mutable value associated with this [`" [<$quantity>]"`]."]   
                 #[inline]
-                pub fn value_mut(&mut self) -> &mut f64
+                pub fn value_mut(&mut self) -> &mut T
                 {
                     &mut self.value
                 }          
@@ -463,30 +646,34 @@ macro_rules! quantity {
                 }
                 #[inline] 
                 /// Convert from one unit to another (no check is made to ensure destination unit is valid).
-                fn convert_unchecked(&self, unit: UnitDefinition) -> f64
+                fn convert_unchecked(&self, unit: UnitDefinition) -> T
                 {
                     let definition = self.definition();
                     if definition == unit
                     {
-                        self.value
-                    }
-                    else
-                    {
-                        self.value * definition.multiplier / unit.multiplier()
+                        return self.value;
                     }
+                    let (scale, shift) = definition.affine_conversion_unchecked(unit);
+                    self.value.scale(scale, shift)
+                }
+                #[doc = "Convert to the unit of the same prefix family best suited to display this [`" [<$quantity>]"`] (e.g. 0.000000123 m to 123 nm)."]
+                pub fn auto_scale(&self, scale: &$crate::format::AutoScale) -> Self
+                {
+                    let unit = self.unit.auto_scale(self.value.to_f64(), scale);
+                    Self { value: self.convert_unchecked(unit.into()), unit }
                 }
                 $(
                     #[doc = "Convert to [`" [<$quantity Unit>] "::" [<$unit>] "`]."]                        
                     #[inline]
                     pub fn [<to_ $unit:snake>](&self) -> Self
                     {                     
-                        Self { value: self.value * (self.definition().multiplier / ([<$quantity:upper _ $unit:upper _conversion:upper>])) as f64, unit: [<$quantity Unit>]::$unit }    
+                        Self { value: self.convert_unchecked([<$quantity Unit>]::[<get_$unit:snake>]()), unit: [<$quantity Unit>]::$unit }    
                     }
                 )+    
             }
 
                         
-            impl FixedQuantity<[<$quantity Unit>]> for $quantity
+            impl<T: Numeric> FixedQuantity<[<$quantity Unit>]> for $quantity<T>
             {                
                 #[inline]
                 fn unit(&self) -> [<$quantity Unit>]
@@ -515,8 +702,9 @@ macro_rules! quantity {
                 #[inline]
                 fn try_convert(&self, unit: $crate::Units) -> Result<Self, RuntimeUnitError> where Self: Sized
                 {
-                    let destination_unit: [<$quantity Unit>] = unit.try_into()?;                   
-                    Ok(self.convert(destination_unit))     
+                    let destination_unit: [<$quantity Unit>] = unit.try_into()?;
+                    $crate::slice::check_representable(core::slice::from_ref(&self.value), self.unit.into(), destination_unit.into())?;
+                    Ok(self.convert(destination_unit))
                 }
             }
 
@@ -528,17 +716,17 @@ macro_rules! quantity {
                     match value        
                     {
                         crate::Quantities::$quantity(value) => Ok(value),         
-                        _ => Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Base mismatch: {:?} vs {}", value, stringify!($quantity))))
+                        _ => Err(RuntimeUnitError::quantity_mismatch($crate::quantity::Quantity::from(value).unit, [<$quantity Unit>]::default().into()))
                     }
                 }
             }
 
             
             
-            impl From<$quantity> for UnitDefinition
+            impl<T: Numeric> From<$quantity<T>> for UnitDefinition
             {
                 #[inline]
-                fn from(value: $quantity) -> Self 
+                fn from(value: $quantity<T>) -> Self 
                 {
                     paste::paste!{
                         match value.unit
@@ -548,21 +736,23 @@ macro_rules! quantity {
                     }
                 }
             }
-            impl From<$quantity> for Quantity
+            impl<T: Numeric> From<$quantity<T>> for Quantity<T>
             {    
-                fn from(quantity: $quantity) -> Self {
+                fn from(quantity: $quantity<T>) -> Self {
                     Self { value: quantity.value, unit: quantity.unit.into() }
                 }                
             }
-            impl From<Quantity> for $quantity
+            impl<T: Numeric> From<Quantity<T>> for $quantity<T>
             {
-                fn from(quantity: Quantity) -> $quantity {
+                fn from(quantity: Quantity<T>) -> $quantity<T> {
                     $quantity { value: quantity.value, unit: [<$quantity Unit>]::try_from(quantity.unit).unwrap() }
                 } 
             }
-            impl $crate::traits::IsScalarQuantity for $quantity
+            impl<T: Numeric> $crate::traits::IsScalarQuantity for $quantity<T>
             {
-                fn value(&self) -> f64
+                type Value = T;
+
+                fn value(&self) -> T
                 {
                     self.value
                 }
@@ -578,29 +768,29 @@ macro_rules! quantity {
             use crate::{impl_quantity_vec_ops, impl_quantity_array_ops};
             
             
-            impl [<$quantity Vec>]
+            impl<T: Numeric> [<$quantity Vec>]<T>
             {
                 #[doc = "Create a new vector of [`" [<$quantity Unit>]"`]."]   
-                pub fn new(values: Vec<f64>, unit: [<$quantity Unit>]) -> Self
+                pub fn new(values: Vec<T>, unit: [<$quantity Unit>]) -> Self
                 {
                     Self{values, unit}
                 }
                 #[inline]
                 #[doc = "Retrieve values associated with this [`" [<$quantity Slice>]"`]."]   
-                pub fn values(&self) -> &Vec<f64>
+                pub fn values(&self) -> &Vec<T>
                 {
                     &self.values
                 }
                 #[inline]
                 #[doc = "Retrieve the mutable values associated with this [`" [<$quantity Slice>]"`]."]   
-                pub fn values_mut(&mut self) -> &mut Vec<f64>
+                pub fn values_mut(&mut self) -> &mut Vec<T>
                 {
                     &mut self.values
                 }
 
                 $(
                     #[doc = "Create a new [`" [<$quantity>] "`] with units of [`" [<$quantity Unit>] "::" [<$unit>] "`]."] 
-                    pub fn [<$unit:snake>](values: Vec<f64>) -> Self
+                    pub fn [<$unit:snake>](values: Vec<T>) -> Self
                     {
                         Self{ values, unit: [<$quantity Unit>]::$unit.into() }
                     }
@@ -619,29 +809,29 @@ macro_rules! quantity {
                 )+   
             }
 
-            impl<const D: usize> [<$quantity Array>]<D>
+            impl<const D: usize, T: Numeric> [<$quantity Array>]<D, T>
             {
                 #[doc = "Create a new vector of [`" [<$quantity Unit>]"`]."]   
-                pub fn new(values: [f64; D], unit: [<$quantity Unit>]) -> Self
+                pub fn new(values: [T; D], unit: [<$quantity Unit>]) -> Self
                 {
                     Self{values, unit}
                 }
                 #[inline]
                 #[doc = "Retrieve values associated with this [`" [<$quantity Slice>]"`]."]   
-                pub fn values(&self) -> &[f64; D]
+                pub fn values(&self) -> &[T; D]
                 {
                     &self.values
                 }
                 #[inline]
                 #[doc = "Retrieve the mutable values associated with this [`" [<$quantity Slice>]"`]."]   
-                pub fn values_mut(&mut self) -> &mut [f64; D]
+                pub fn values_mut(&mut self) -> &mut [T; D]
                 {
                     &mut self.values
                 }
 
                 $(
                     #[doc = "Create a new [`" [<$quantity>] "`] with units of [`" [<$quantity Unit>] "::" [<$unit>] "`]."] 
-                    pub fn [<$unit:snake>](values: [f64; D]) -> Self
+                    pub fn [<$unit:snake>](values: [T; D]) -> Self
                     {
                         Self{ values, unit: [<$quantity Unit>]::$unit.into() }
                     }
@@ -659,7 +849,7 @@ macro_rules! quantity {
                     }
                 )+   
                 
-                pub fn at(&self, index: usize) -> $quantity
+                pub fn at(&self, index: usize) -> $quantity<T>
                 {
                     $quantity{ unit: self.unit, value: self.values[index] }
                 }
@@ -667,13 +857,18 @@ macro_rules! quantity {
             
             impl_quantity_ops!($quantity);
             create_multivalue_quantities!($quantity);
+            $crate::create_slice_quantities!($quantity);
             impl_quantity_vec_ops!($quantity);
             impl_quantity_array_ops!($quantity);
+            $crate::create_complex_quantity!(@scalar $quantity);
+            $crate::if_typed_variant!(complex, $quantity, $crate::create_complex_quantity!($quantity););
+            $crate::if_typed_variant!(vector3, $quantity, $crate::create_vector3_quantity!($quantity););
+            $crate::impl_additive_ops!($quantity $($(, $offset)?)+);
             
             use crate::vector_quantity::VecQuantity;
-            impl From<[<$quantity Vec>]> for VecQuantity
+            impl<T: Numeric> From<[<$quantity Vec>]<T>> for VecQuantity<T>
             {
-                fn from(input: [<$quantity Vec>]) -> Self 
+                fn from(input: [<$quantity Vec>]<T>) -> Self 
                 {
                     VecQuantity{ unit: input.unit.definition(), values: input.values }
                 }
@@ -699,7 +894,7 @@ macro_rules! quantity {
         {
             impl TryFrom<&str> for [<$quantity Unit>]
             {
-                type Error = &'static str;
+                type Error = RuntimeUnitError;
                 fn try_from(value: &str) -> Result<Self, Self::Error> 
                 {                
                     let abbreviation_check: Option<[<$quantity Unit>]> =
@@ -734,7 +929,7 @@ macro_rules! quantity {
                     }
                     else
                     {
-                        Err(concat!("Unit \"{value}\" not supported for quantity \"", stringify!{$quantity}, "\""))
+                        Err(RuntimeUnitError::UnknownUnit { input: value.to_owned(), quantity: Some($crate::UnitTypes::$quantity) })
 
                     }
                 }
@@ -780,6 +975,12 @@ macro_rules! system {
            pub use $crate::unit_definitions::[<$quantity:snake>]::$quantity;}
            paste::paste!{#[cfg(any(feature = "" $quantity, feature="All"))]                                              
            pub use $crate::unit_definitions::[<$quantity:snake>]::[<$quantity Vec>];}
+           $crate::if_typed_variant!(complex, $quantity, paste::paste!{#[cfg(any(feature = "" $quantity, feature="All"))]
+           pub use $crate::unit_definitions::[<$quantity:snake>]::[<Complex $quantity>];});
+           paste::paste!{#[cfg(any(feature = "" $quantity, feature="All"))]
+           pub use $crate::unit_definitions::[<$quantity:snake>]::{[<$quantity Slice>], [<$quantity SliceMut>]};}
+           $crate::if_typed_variant!(vector3, $quantity, paste::paste!{#[cfg(any(feature = "" $quantity, feature="All"))]
+           pub use $crate::unit_definitions::[<$quantity:snake>]::[<$quantity Vector3>];});
            
         )+
         pub mod quantities
@@ -842,6 +1043,60 @@ macro_rules! system {
         impl UnitTypes
         {
             paste::paste!{
+                ///
+                /// Retrieve the base dimensions of this `UnitType`.
+                /// 
+                pub fn base(&self) -> $crate::units_base::UnitBase
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]
+                            UnitTypes::$quantity=>*[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>],
+                        )+
+                    }
+                }
+                ///
+                /// Retrieve the base unit of this `UnitType`.
+                /// 
+                pub fn base_unit(&self) -> $crate::Units
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]
+                            UnitTypes::$quantity=>$crate::units::[<$quantity Unit>]::default().into(),
+                        )+
+                    }
+                }
+                ///
+                /// Retrieve all `UnitTypes` sharing the dimensions of a given `unit` (e.g. both `Energy` and `Torque` for `N·m`).
+                /// 
+                pub fn matching(unit: UnitDefinition) -> Vec<UnitTypes>
+                {
+                    let mut types = Vec::new();
+                    $(
+                        #[cfg(any(feature = "" $quantity, feature="All"))]
+                        if unit.base == *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>]
+                        {
+                            types.push(UnitTypes::$quantity);
+                        }
+                    )+
+                    types
+                }
+                ///
+                /// Find the unit of this `UnitType` best matching a given `unit`, within a relative `tolerance` on its multiplier.
+                /// 
+                pub fn find_unit(&self, unit: UnitDefinition, tolerance: f64) -> Option<$crate::Units>
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]
+                            UnitTypes::$quantity=>$crate::units::[<$quantity Unit>]::find(unit, tolerance).map(Into::into),
+                        )+
+                    }
+                }
                 ///
                 /// Retrieve list of units available for this `UnitType`.
                 /// 
@@ -860,19 +1115,13 @@ macro_rules! system {
                 ///
                 /// Convert a given unit string to the Corresponding `Units`
                 /// 
-                pub fn to_unit(&self, unit_str: &str) -> Result<$crate::Units, &'static str>
+                pub fn to_unit(&self, unit_str: &str) -> Result<$crate::Units, RuntimeUnitError>
                 {
                     match self
                     {
                         $(
                             #[cfg(any(feature = "" $quantity, feature="All"))]   
-                            UnitTypes::$quantity => {
-                                match $crate::units::[<$quantity Unit>]::try_from(unit_str)
-                                {
-                                    Ok(r) => Ok(r.into()),
-                                    Err(err) => Err(err)
-                                }                                
-                            }
+                            UnitTypes::$quantity => $crate::units::[<$quantity Unit>]::try_from(unit_str).map(Into::into),
                         )+
                     }
                 }
@@ -940,6 +1189,51 @@ macro_rules! system {
                 {
                     Quantity::from(*self).value()
                 }
+                /// Add `other` to this quantity (result in the units of this quantity), returning an error instead of panicking on 
+                /// incompatible quantities. See [`CheckedAdditive`]($crate::traits::CheckedAdditive).
+                pub fn try_add(&self, other: &Quantities) -> Result<Quantities, RuntimeUnitError>
+                {
+                    use $crate::traits::CheckedAdditive;
+                    self.result_of(Quantity::from(*self).checked_add(Quantity::from(*other))?, other)
+                }
+                /// Subtract `other` from this quantity (result in the units of this quantity), returning an error instead of panicking 
+                /// on incompatible quantities. See [`CheckedAdditive`]($crate::traits::CheckedAdditive).
+                pub fn try_sub(&self, other: &Quantities) -> Result<Quantities, RuntimeUnitError>
+                {
+                    use $crate::traits::CheckedAdditive;
+                    self.result_of(Quantity::from(*self).checked_sub(Quantity::from(*other))?, other)
+                }
+                /// Convert the result of an operation on `self` and `other` back to a `Quantities`, preferring their units.
+                fn result_of(&self, result: Quantity, other: &Quantities) -> Result<Quantities, RuntimeUnitError>
+                {
+                    match [self.unit(), other.unit()].into_iter().find(|&unit| UnitDefinition::from(unit).approx_eq(result.unit, 1e-12))
+                    {
+                        Some(unit) => Ok(Quantities::new(result.value, unit)),
+                        None => result.try_into_quantities()
+                    }
+                }
+                /// Convert to the unit of the same prefix family best suited to display this quantity, see `format::AutoScale`.
+                pub fn auto_scale(&self, scale: &$crate::format::AutoScale) -> Quantities
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]   
+                            Quantities::$quantity(x)=> Quantities::$quantity(x.auto_scale(scale)),
+                        )+
+                    }
+                }
+                /// Format this quantity in its most readable SI-prefixed unit, with values in `[1, 1000)` (e.g. `123 nm`).
+                pub fn to_human_string(&self) -> String
+                {
+                    self.to_human_string_with(&$crate::format::AutoScale::default())
+                }
+                /// Format this quantity in its most readable prefixed unit, given the `scale` options (e.g. `1.5 GiB`).
+                pub fn to_human_string_with(&self, scale: &$crate::format::AutoScale) -> String
+                {
+                    let quantity = self.auto_scale(scale);
+                    format!("{} {}", $crate::format::round_significant(quantity.value(), 12), quantity.unit().abbreviation())
+                }
             }
             /// A means to create a default quantity with a given set of units.
             impl From<Units> for Quantities
@@ -963,11 +1257,20 @@ macro_rules! system {
                     {
                         $(
                             #[cfg(any(feature = "" $quantity, feature="All"))]   
-                            Quantities::$quantity(x)=>Quantity { value: x.value, unit: UnitDefinition{multiplier: x.unit.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>]} },
+                            Quantities::$quantity(x)=>Quantity { value: x.value, unit: UnitDefinition{multiplier: x.unit.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>], offset: x.unit.offset(), kind: x.unit.kind()} },
                         )+
                     }
                 }
             }
+            impl TryFrom<UnitDefinition> for Units
+            {
+                type Error = RuntimeUnitError;
+                /// Find the named unit matching a given `UnitDefinition`, within a relative tolerance of `1e-9`. See [`Units::find`].
+                fn try_from(value: UnitDefinition) -> Result<Self, Self::Error>
+                {
+                    Units::find(value, 1e-9).ok_or_else(|| RuntimeUnitError::UnknownUnit { input: value.to_string(), quantity: None })
+                }
+            }
             impl ToString for Quantities
             {
                 fn to_string(&self) -> String 
@@ -1000,18 +1303,29 @@ macro_rules! system {
                     let value_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing value".into()))?;
                     let unit_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing unit".into()))?;
 
-                    let value: f64 = value_str.parse().map_err(|_| RuntimeUnitError::ParseError("Invalid value".into()))?;
+                    let value: f64 = value_str.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: value_str.to_owned() })?;
+                    let unit: Units = unit_str.trim().parse()?;
+                    Ok(Quantities::new(value, unit))
+                }
+            }          
 
-                    $(
-                        #[cfg(any(feature = "" $quantity, feature="All"))]
-                        if let Ok(unit) = $crate::units::[<$quantity Unit>]::try_from(unit_str) {
-                            return Ok(Quantities::$quantity($quantity::new(value, unit)));
-                        }
-                    )+
+            impl std::str::FromStr for Units
+            {
+                type Err = RuntimeUnitError;
 
-                    Err(RuntimeUnitError::ParseError(format!("Unknown unit: {}", unit_str)))
+                /// Parses the abbreviation, singular or plural name of a unit (e.g. `"km"`, `"kilometer"` or `"kilometers"`).
+                /// 
+                /// A temperature scale is absolute (`"°C"` or `"K"`), as in [`crate::parser::parse_unit`]. Names shared by units
+                /// of different dimensions or magnitudes (e.g. `"h"`) fail with `RuntimeUnitError::AmbiguousUnit`, while of units
+                /// that only differ in kind the first quantity in the system supporting it is used.
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    if let Some(unit) = $crate::parser::absolute_scale(s)
+                    {
+                        return Ok(unit);
+                    }
+                    $crate::parser::find_unit(s)?.ok_or_else(|| RuntimeUnitError::UnknownUnit { input: s.to_owned(), quantity: None })
                 }
-            }          
+            }
 
             
                         
@@ -1040,7 +1354,7 @@ macro_rules! system {
                         {
                             $(
                                 #[cfg(any(feature = "" $quantity, feature="All"))]     
-                                Units::$quantity(x)=> UnitDefinition{multiplier: x.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>]},
+                                Units::$quantity(x)=> UnitDefinition{multiplier: x.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>], offset: x.offset(), kind: x.kind()},
                             )+
                         }
                     }
@@ -1062,6 +1376,81 @@ macro_rules! system {
             }
             impl Units
             {
+                /// Find all units with a given abbreviation, singular or plural name (e.g. `"h"` is both an hour and the Planck constant).
+                pub fn candidates(name: &str) -> Vec<Units>
+                {
+                    let mut candidates = Vec::new();
+                    $(
+                        #[cfg(any(feature = "" $quantity, feature="All"))]
+                        if let Ok(unit) = $crate::units::[<$quantity Unit>]::try_from(name) {
+                            candidates.push(unit.into());
+                        }
+                    )+
+                    candidates
+                }
+
+                ///
+                /// Canonical name of this unit, qualified by its quantity (e.g. `TemperatureInterval::degree_celsius`). Unlike
+                /// abbreviations, canonical names are unambiguous, see [`Units::from_canonical_name`].
+                ///
+                pub fn canonical_name(&self) -> String
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]
+                            Units::$quantity(x) => format!("{}::{}", stringify!($quantity), x.identifier()),
+                        )+
+                    }
+                }
+
+                /// Parse a canonical name of a unit (e.g. `TemperatureInterval::degree_celsius`), see [`Units::canonical_name`].
+                pub fn from_canonical_name(name: &str) -> Result<Units, RuntimeUnitError>
+                {
+                    let unknown = |quantity| RuntimeUnitError::UnknownUnit { input: name.to_owned(), quantity };
+                    let (quantity, unit) = name.split_once("::").ok_or_else(|| unknown(None))?;
+                    $(
+                        #[cfg(any(feature = "" $quantity, feature="All"))]
+                        if quantity == stringify!($quantity)
+                        {
+                            return $crate::units::[<$quantity Unit>]::from_identifier(unit).map(Into::into).ok_or_else(|| unknown(Some(UnitTypes::$quantity)));
+                        }
+                    )+
+                    Err(unknown(None))
+                }
+
+                /// Parse the name of a unit like `from_str`, but fail with `RuntimeUnitError::AmbiguousUnit` if the name is shared
+                /// by several quantities.
+                pub fn parse_unique(name: &str) -> Result<Units, RuntimeUnitError>
+                {
+                    let candidates = Units::candidates(name);
+                    match candidates.as_slice()
+                    {
+                        [] => Err(RuntimeUnitError::UnknownUnit { input: name.to_owned(), quantity: None }),
+                        [unit] => Ok(*unit),
+                        _ => Err(RuntimeUnitError::AmbiguousUnit { input: name.to_owned(), candidates })
+                    }
+                }
+                ///
+                /// Find the named unit best matching a given `unit`, within a relative `tolerance` on its multiplier. Where 
+                /// several quantities share the dimensions of `unit`, the closest match in the first quantity of the system is used.
+                /// 
+                pub fn find(unit: UnitDefinition, tolerance: f64) -> Option<Units>
+                {
+                    let mut best: Option<(Units, f64)> = None;
+                    for unit_type in UnitTypes::matching(unit)
+                    {
+                        if let Some(candidate) = unit_type.find_unit(unit, tolerance)
+                        {
+                            let error = (1.0 - unit.multiplier / UnitDefinition::from(candidate).multiplier).abs();
+                            if best.is_none_or(|(_, best_error)| error < best_error)
+                            {
+                                best = Some((candidate, error));
+                            }
+                        }
+                    }
+                    best.map(|(unit, _)| unit)
+                }
                 ///
                 /// Convert a given 'value' expressed in the given `Units` into a convertible `Quantity`
                 /// 
@@ -1071,6 +1460,45 @@ macro_rules! system {
                     $crate::quantity::Quantity { unit, value }
                 }
                 paste!{
+                ///
+                /// Get the singular name of this unit (e.g. `kilometer`).
+                /// 
+                pub fn singular(&self) -> &'static str
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]   
+                            Units::$quantity(x)=>x.singular(),
+                        )+
+                    }
+                }
+                ///
+                /// Get the plural name of this unit (e.g. `kilometers`).
+                /// 
+                pub fn plural(&self) -> &'static str
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]   
+                            Units::$quantity(x)=>x.plural(),
+                        )+
+                    }
+                }
+                ///
+                /// Get the abbreviation of this unit (e.g. `km`).
+                /// 
+                pub fn abbreviation(&self) -> &'static str
+                {
+                    match self
+                    {
+                        $(
+                            #[cfg(any(feature = "" $quantity, feature="All"))]   
+                            Units::$quantity(x)=>x.abbreviation(),
+                        )+
+                    }
+                }
      
                 ///
                 /// Get list of units available for this `Units` type
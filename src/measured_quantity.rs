@@ -0,0 +1,326 @@
+//! Quantities carrying a standard uncertainty, propagated with the first-order rules of the GUM (Guide to the Expression
+//! of Uncertainty in Measurement).
+//!
+//! Operators assume the operands are uncorrelated. The `*_correlated` methods take the correlation coefficient of both
+//! operands instead (e.g. `x.sub_correlated(x, 1.0)` has no uncertainty).
+use core::fmt::Display;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::errors::RuntimeUnitError;
+use crate::quantity::Quantity;
+use crate::traits::{ArbitraryQuantity, CheckedAdditive};
+use crate::units_base::UnitDefinition;
+
+#[doc = "A measured value with its standard uncertainty (`value ± uncertainty`), in a given unit."]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MeasuredQuantity
+{
+    pub(crate) value: f64,
+    pub(crate) uncertainty: f64,
+    pub(crate) unit: UnitDefinition
+}
+
+impl MeasuredQuantity
+{
+    ///
+    /// Create a new instance of `MeasuredQuantity` with a given `value`, standard `uncertainty` and `unit`
+    ///
+    pub fn new(value: f64, uncertainty: f64, unit: UnitDefinition) -> Self
+    {
+        Self { value, uncertainty: uncertainty.abs(), unit }
+    }
+
+    ///
+    /// Create a new instance of `MeasuredQuantity` from a `quantity` and its standard `uncertainty` (in the units of `quantity`)
+    ///
+    pub fn from_quantity(quantity: impl Into<Quantity>, uncertainty: f64) -> Self
+    {
+        let quantity = quantity.into();
+        Self::new(quantity.value, uncertainty, quantity.unit)
+    }
+
+    /// Measured value
+    #[inline]
+    pub fn value(&self) -> f64
+    {
+        self.value
+    }
+
+    /// Standard uncertainty of the value
+    #[inline]
+    pub fn uncertainty(&self) -> f64
+    {
+        self.uncertainty
+    }
+
+    /// Standard uncertainty relative to the magnitude of the value
+    #[inline]
+    pub fn relative_uncertainty(&self) -> f64
+    {
+        self.uncertainty / self.value.abs()
+    }
+
+    /// Measured value as a `Quantity` (without uncertainty)
+    #[inline]
+    pub fn quantity(&self) -> Quantity
+    {
+        Quantity::new(self.value, self.unit)
+    }
+
+    /// Standard uncertainty as a `Quantity`, in the units of the value (relative to the zero of absolute units)
+    #[inline]
+    pub fn uncertainty_quantity(&self) -> Quantity
+    {
+        Quantity::new(self.uncertainty, self.unit.interval())
+    }
+
+    /// Raise to a floating point power: `u(xⁿ) = |n xⁿ⁻¹| u(x)`
+    pub fn powf(&self, value: f64) -> MeasuredQuantity
+    {
+        let x = self.unit.relative_value(self.value);
+        Self::new(x.powf(value), value * x.powf(value - 1.0) * self.uncertainty, self.unit.powf(value))
+    }
+
+    /// Raise to an integer power: `u(xⁿ) = |n xⁿ⁻¹| u(x)`
+    pub fn powi(&self, value: i8) -> MeasuredQuantity
+    {
+        let x = self.unit.relative_value(self.value);
+        Self::new(x.powi(value as i32), value as f64 * x.powi(value as i32 - 1) * self.uncertainty, self.unit.powi(value))
+    }
+
+    /// Multiply two measured quantities with a given `correlation` coefficient (within -1..=1)
+    pub fn mul_correlated(&self, rhs: MeasuredQuantity, correlation: f64) -> MeasuredQuantity
+    {
+        let (x, y) = (self.unit.relative_value(self.value), rhs.unit.relative_value(rhs.value));
+        let uncertainty = combine(y * self.uncertainty, x * rhs.uncertainty, correlation);
+        Self::new(x * y, uncertainty, self.unit * rhs.unit)
+    }
+
+    /// Divide two measured quantities with a given `correlation` coefficient (within -1..=1)
+    pub fn div_correlated(&self, rhs: MeasuredQuantity, correlation: f64) -> MeasuredQuantity
+    {
+        let (x, y) = (self.unit.relative_value(self.value), rhs.unit.relative_value(rhs.value));
+        let uncertainty = combine(self.uncertainty / y, -x / (y * y) * rhs.uncertainty, correlation);
+        Self::new(x / y, uncertainty, self.unit / rhs.unit)
+    }
+
+    /// Add two measured quantities with a given `correlation` coefficient (within -1..=1), see [`CheckedAdditive::checked_add`]
+    pub fn add_correlated(&self, rhs: MeasuredQuantity, correlation: f64) -> Result<MeasuredQuantity, RuntimeUnitError>
+    {
+        let sum = self.quantity().checked_add(rhs.quantity())?;
+        Ok(self.additive(rhs, sum, 1.0, correlation))
+    }
+
+    /// Subtract two measured quantities with a given `correlation` coefficient (within -1..=1), see [`CheckedAdditive::checked_sub`]
+    pub fn sub_correlated(&self, rhs: MeasuredQuantity, correlation: f64) -> Result<MeasuredQuantity, RuntimeUnitError>
+    {
+        let difference = self.quantity().checked_sub(rhs.quantity())?;
+        Ok(self.additive(rhs, difference, -1.0, correlation))
+    }
+
+    /// Combine the uncertainties of a sum (`sign` = 1) or difference (`sign` = -1), expressed in the unit of `result`.
+    fn additive(&self, rhs: MeasuredQuantity, result: Quantity, sign: f64, correlation: f64) -> MeasuredQuantity
+    {
+        let unit = result.unit.interval();
+        let uncertainty = combine(self.uncertainty * self.unit.interval().convert_unchecked(unit),
+            sign * rhs.uncertainty * rhs.unit.interval().convert_unchecked(unit), correlation);
+        Self::new(result.value, uncertainty, result.unit)
+    }
+}
+
+/// Combine two uncertainty contributions (sensitivity times uncertainty) with a correlation coefficient.
+fn combine(lhs: f64, rhs: f64, correlation: f64) -> f64
+{
+    (lhs * lhs + rhs * rhs + 2.0 * correlation * lhs * rhs).max(0.0).sqrt()
+}
+
+impl From<Quantity> for MeasuredQuantity
+{
+    /// An exact quantity, without uncertainty.
+    fn from(quantity: Quantity) -> Self
+    {
+        Self::new(quantity.value, 0.0, quantity.unit)
+    }
+}
+
+impl ArbitraryQuantity for MeasuredQuantity
+{
+    fn unit(&self) -> UnitDefinition {
+        self.unit
+    }
+
+    fn unit_mut(&mut self) -> &mut UnitDefinition {
+        &mut self.unit
+    }
+
+    fn try_convert(&self, unit: UnitDefinition) -> Result<Self, RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        Ok(self.convert(unit))
+    }
+
+    fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        self.convert_mut(unit);
+        Ok(())
+    }
+
+    fn convert(&self, unit: UnitDefinition) -> Self {
+        let mut result = *self;
+        result.convert_mut(unit);
+        result
+    }
+
+    fn convert_mut(&mut self, unit: UnitDefinition) {
+        // Offsets of absolute units shift the value, but not its uncertainty.
+        self.value = self.unit.convert_value_unchecked(self.value, unit);
+        self.uncertainty *= self.unit.convert_unchecked(unit).abs();
+        self.unit = unit;
+    }
+}
+
+impl CheckedAdditive for MeasuredQuantity
+{
+    fn checked_add(self, rhs: MeasuredQuantity) -> Result<Self, RuntimeUnitError> {
+        self.add_correlated(rhs, 0.0)
+    }
+
+    fn checked_sub(self, rhs: MeasuredQuantity) -> Result<Self, RuntimeUnitError> {
+        self.sub_correlated(rhs, 0.0)
+    }
+
+    fn try_add_assign(&mut self, rhs: MeasuredQuantity) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_add(rhs)?;
+        Ok(())
+    }
+
+    fn try_sub_assign(&mut self, rhs: MeasuredQuantity) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_sub(rhs)?;
+        Ok(())
+    }
+}
+
+impl Add<MeasuredQuantity> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    /// Add two uncorrelated quantities, see [`CheckedAdditive::checked_add`]. Panics on incompatible units.
+    fn add(self, rhs: MeasuredQuantity) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Sub<MeasuredQuantity> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    /// Subtract two uncorrelated quantities, see [`CheckedAdditive::checked_sub`]. Panics on incompatible units.
+    fn sub(self, rhs: MeasuredQuantity) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl AddAssign for MeasuredQuantity
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for MeasuredQuantity
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<MeasuredQuantity> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    /// Multiply two uncorrelated quantities.
+    fn mul(self, rhs: MeasuredQuantity) -> Self::Output {
+        self.mul_correlated(rhs, 0.0)
+    }
+}
+
+impl Div<MeasuredQuantity> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    /// Divide two uncorrelated quantities.
+    fn div(self, rhs: MeasuredQuantity) -> Self::Output {
+        self.div_correlated(rhs, 0.0)
+    }
+}
+
+impl Mul<Quantity> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    fn mul(self, rhs: Quantity) -> Self::Output {
+        self * MeasuredQuantity::from(rhs)
+    }
+}
+
+impl Div<Quantity> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    fn div(self, rhs: Quantity) -> Self::Output {
+        self / MeasuredQuantity::from(rhs)
+    }
+}
+
+impl MulAssign for MeasuredQuantity
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for MeasuredQuantity
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<f64> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.value * rhs, self.uncertainty * rhs, self.unit)
+    }
+}
+
+impl Div<f64> for MeasuredQuantity
+{
+    type Output = MeasuredQuantity;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self::new(self.value / rhs, self.uncertainty / rhs, self.unit)
+    }
+}
+
+impl MulAssign<f64> for MeasuredQuantity
+{
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for MeasuredQuantity
+{
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Display for MeasuredQuantity
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ± {} {}", self.value, self.uncertainty, self.unit)
+    }
+}
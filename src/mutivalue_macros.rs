@@ -5,99 +5,125 @@ macro_rules! impl_quantity_vec_ops {
         paste::paste!
         {
         use core::ops::{Mul, Div, Add, Sub, AddAssign, SubAssign, MulAssign, DivAssign };
-        impl Div<f64> for [<$quantity Vec>]
+        use $crate::traits::Unit;
+        impl<T: Numeric> Div<f64> for [<$quantity Vec>]<T>
         {
-            type Output = [<$quantity Vec>];
+            type Output = [<$quantity Vec>]<T>;
 
             fn div(self, rhs: f64) -> Self::Output {
                 let mut result = self.clone();
                 for val in result.values.as_mut_slice()
                 {
-                *val /= rhs;
+                *val = val.div_f64(rhs);
                 }
                 result
             }
         }
 
 
-        impl Mul<f64> for [<$quantity Vec>]
+        impl<T: Numeric> Mul<f64> for [<$quantity Vec>]<T>
         {
-            type Output = [<$quantity Vec>];
+            type Output = [<$quantity Vec>]<T>;
 
             fn mul(self, rhs: f64) -> Self::Output {
                 let mut result = self.clone();
                 for val in result.values.as_mut_slice()
                 {
-                *val *= rhs;
+                *val = val.mul_f64(rhs);
                 }
                 result
             }
         }
-        impl DivAssign<f64> for [<$quantity Vec>]
+        impl<T: Numeric> DivAssign<f64> for [<$quantity Vec>]<T>
         {
 
             fn div_assign(&mut self, rhs: f64) {
                 for val in self.values.as_mut_slice()
                 {
-                *val /= rhs;
+                *val = val.div_f64(rhs);
                 }
             }
         }
-        impl MulAssign<f64> for [<$quantity Vec>]
+        impl<T: Numeric> MulAssign<f64> for [<$quantity Vec>]<T>
         {
 
             fn mul_assign(&mut self, rhs: f64) {
                 for val in self.values.as_mut_slice()
                 {
-                *val *= rhs;
+                *val = val.mul_f64(rhs);
                 }
             }
         }
-        use $crate::traits::Unit;
-        impl AddAssign<[<$quantity Vec>]> for [<$quantity Vec>]
+    }
+    }
+}
+
+#[macro_export]
+macro_rules! impl_quantity_vec_additive_ops {
+    ($quantity:ident) =>
+    {
+        paste::paste!
+        {
+        impl<T: Numeric> $crate::traits::CheckedAdditive for [<$quantity Vec>]<T>
         {
+            fn checked_add(mut self, rhs: [<$quantity Vec>]<T>) -> Result<Self, $crate::errors::RuntimeUnitError> {
+                $crate::traits::CheckedAdditive::try_add_assign(&mut self, rhs)?;
+                Ok(self)
+            }
 
-            fn add_assign(&mut self, rhs: [<$quantity Vec>]) {
-                if rhs.values.len() != self.values.len()
-                {
-                    panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-                }
+            fn checked_sub(mut self, rhs: [<$quantity Vec>]<T>) -> Result<Self, $crate::errors::RuntimeUnitError> {
+                $crate::traits::CheckedAdditive::try_sub_assign(&mut self, rhs)?;
+                Ok(self)
+            }
+
+            fn try_add_assign(&mut self, rhs: [<$quantity Vec>]<T>) -> Result<(), $crate::errors::RuntimeUnitError> {
+                $crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
                 let factor = rhs.unit.definition().convert_unchecked(self.unit.definition());
                 for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
                 {
-                *val += rhs*factor;
+                *val = *val + rhs.mul_f64(factor);
                 }
+                Ok(())
             }
-        }
-        impl SubAssign<[<$quantity Vec>]> for [<$quantity Vec>]
-        {
 
-            fn sub_assign(&mut self, rhs: [<$quantity Vec>]) {
-                if rhs.values.len() != self.values.len()
-                {
-                    panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-                }
+            fn try_sub_assign(&mut self, rhs: [<$quantity Vec>]<T>) -> Result<(), $crate::errors::RuntimeUnitError> {
+                $crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
                 let factor = rhs.unit.convert_unchecked(self.unit);
                 for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
                 {
-                *val -= rhs*factor;
+                *val = *val - rhs.mul_f64(factor);
                 }
+                Ok(())
             }
         }
-        impl Add<[<$quantity Vec>]> for [<$quantity Vec>]
+        impl<T: Numeric> AddAssign<[<$quantity Vec>]<T>> for [<$quantity Vec>]<T>
+        {
+
+            fn add_assign(&mut self, rhs: [<$quantity Vec>]<T>) {
+                $crate::traits::CheckedAdditive::try_add_assign(self, rhs).unwrap_or_else(|error| panic!("{error}"))
+            }
+        }
+        impl<T: Numeric> SubAssign<[<$quantity Vec>]<T>> for [<$quantity Vec>]<T>
+        {
+
+            fn sub_assign(&mut self, rhs: [<$quantity Vec>]<T>) {
+                $crate::traits::CheckedAdditive::try_sub_assign(self, rhs).unwrap_or_else(|error| panic!("{error}"))
+            }
+        }
+        impl<T: Numeric> Add<[<$quantity Vec>]<T>> for [<$quantity Vec>]<T>
         {
             type Output=Self;
 
-            fn add(self, rhs: [<$quantity Vec>]) -> Self {
+            fn add(self, rhs: [<$quantity Vec>]<T>) -> Self {
                 let mut result = self.clone();
                 result += rhs;
                 result
             }
         }
-        impl Sub<[<$quantity Vec>]> for [<$quantity Vec>]
+        impl<T: Numeric> Sub<[<$quantity Vec>]<T>> for [<$quantity Vec>]<T>
         {
             type Output=Self;
-            fn sub(self, rhs: [<$quantity Vec>]) -> Self {
+            fn sub(self, rhs: [<$quantity Vec>]<T>) -> Self {
                 let mut result = self;
                 result -= rhs;
                 result
@@ -113,101 +139,127 @@ macro_rules! impl_quantity_array_ops {
     {
         paste::paste!
         {
-        impl<const N: usize> Div<f64> for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> Div<f64> for [<$quantity Array>]<N, T>
         {
-            type Output = [<$quantity Array>]<N>;
+            type Output = [<$quantity Array>]<N, T>;
 
             fn div(self, rhs: f64) -> Self::Output {
                 let mut result = self.clone();
                 for val in result.values.as_mut_slice()
                 {
-                *val /= rhs;
+                *val = val.div_f64(rhs);
                 }
                 result
             }
         }
 
 
-        impl<const N: usize> Mul<f64> for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> Mul<f64> for [<$quantity Array>]<N, T>
         {
-            type Output = [<$quantity Array>]<N>;
+            type Output = [<$quantity Array>]<N, T>;
 
             fn mul(self, rhs: f64) -> Self::Output {
                 let mut result = self.clone();
                 for val in result.values.as_mut_slice()
                 {
-                *val *= rhs;
+                *val = val.mul_f64(rhs);
                 }
                 result
             }
         }
-        impl<const N: usize> DivAssign<f64> for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> DivAssign<f64> for [<$quantity Array>]<N, T>
         {
 
             fn div_assign(&mut self, rhs: f64) {
                 for val in self.values.as_mut_slice()
                 {
-                *val /= rhs;
+                *val = val.div_f64(rhs);
+                }
+            }
+        }
+        impl<const N: usize, T: Numeric> MulAssign<f64> for [<$quantity Array>]<N, T>
+        {
+
+            fn mul_assign(&mut self, rhs: f64) {
+                for val in self.values.as_mut_slice()
+                {
+                *val = val.mul_f64(rhs);
                 }
             }
         }
-        impl<const N: usize> Add<[<$quantity Array>]<N>> for [<$quantity Array>]<N>
+    }
+    }
+}
+
+#[macro_export]
+macro_rules! impl_quantity_array_additive_ops {
+    ($quantity:ident) =>
+    {
+        paste::paste!
+        {
+        impl<const N: usize, T: Numeric> Add<[<$quantity Array>]<N, T>> for [<$quantity Array>]<N, T>
         {
             type Output=Self;
 
-            fn add(self, rhs: [<$quantity Array>]<N>) -> Self {
+            fn add(self, rhs: [<$quantity Array>]<N, T>) -> Self {
                 let mut result = self;
                 result += rhs;
                 result
             }
         }
-        impl<const N: usize> Sub<[<$quantity Array>]<N>> for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> Sub<[<$quantity Array>]<N, T>> for [<$quantity Array>]<N, T>
         {
             type Output=Self;
-            fn sub(self, rhs: [<$quantity Array>]<N>) -> Self {
+            fn sub(self, rhs: [<$quantity Array>]<N, T>) -> Self {
                 let mut result = self;
                 result -= rhs;
                 result
             }
         }     
-        impl<const N: usize> MulAssign<f64> for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> $crate::traits::CheckedAdditive for [<$quantity Array>]<N, T>
         {
+            fn checked_add(mut self, rhs: [<$quantity Array>]<N, T>) -> Result<Self, $crate::errors::RuntimeUnitError> {
+                $crate::traits::CheckedAdditive::try_add_assign(&mut self, rhs)?;
+                Ok(self)
+            }
 
-            fn mul_assign(&mut self, rhs: f64) {
-                for val in self.values.as_mut_slice()
-                {
-                *val *= rhs;
-                }
+            fn checked_sub(mut self, rhs: [<$quantity Array>]<N, T>) -> Result<Self, $crate::errors::RuntimeUnitError> {
+                $crate::traits::CheckedAdditive::try_sub_assign(&mut self, rhs)?;
+                Ok(self)
             }
-        }
-        impl<const N: usize> AddAssign<[<$quantity Array>]<N>> for [<$quantity Array>]<N>
-        {
 
-            fn add_assign(&mut self, rhs: [<$quantity Array>]<N>) {
-                if rhs.values.len() != self.values.len()
-                {
-                    panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-                }
+            fn try_add_assign(&mut self, rhs: [<$quantity Array>]<N, T>) -> Result<(), $crate::errors::RuntimeUnitError> {
+                $crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
                 let factor = rhs.unit.definition().convert_unchecked(self.unit.definition());
                 for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
                 {
-                *val += rhs*factor;
+                *val = *val + rhs.mul_f64(factor);
                 }
+                Ok(())
             }
-        }
-        impl<const N: usize> SubAssign<[<$quantity Array>]<N>> for [<$quantity Array>]<N>
-        {
 
-            fn sub_assign(&mut self, rhs: [<$quantity Array>]<N>) {
-                if rhs.values.len() != self.values.len()
-                {
-                    panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-                }
+            fn try_sub_assign(&mut self, rhs: [<$quantity Array>]<N, T>) -> Result<(), $crate::errors::RuntimeUnitError> {
+                $crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
                 let factor = rhs.unit.convert_unchecked(self.unit);
                 for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
                 {
-                *val -= rhs*factor;
+                *val = *val - rhs.mul_f64(factor);
                 }
+                Ok(())
+            }
+        }
+        impl<const N: usize, T: Numeric> AddAssign<[<$quantity Array>]<N, T>> for [<$quantity Array>]<N, T>
+        {
+
+            fn add_assign(&mut self, rhs: [<$quantity Array>]<N, T>) {
+                $crate::traits::CheckedAdditive::try_add_assign(self, rhs).unwrap_or_else(|error| panic!("{error}"))
+            }
+        }
+        impl<const N: usize, T: Numeric> SubAssign<[<$quantity Array>]<N, T>> for [<$quantity Array>]<N, T>
+        {
+
+            fn sub_assign(&mut self, rhs: [<$quantity Array>]<N, T>) {
+                $crate::traits::CheckedAdditive::try_sub_assign(self, rhs).unwrap_or_else(|error| panic!("{error}"))
             }
         }
     }
@@ -228,31 +280,31 @@ macro_rules! create_multivalue_quantities {
         #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
         #[cfg_attr(feature="utoipa", derive(ToSchema))]
         #[doc = "Array storage for a series of values and [`" [<$quantity Unit>]"`]."]
-        pub struct [<$quantity Array>]<const N: usize>
+        pub struct [<$quantity Array>]<const N: usize, T = f64>
         {
             pub(crate) unit: [<$quantity Unit>],
-            #[cfg_attr(feature="serde", serde_as(as = "[_; N]"))]
-            pub(crate) values: [f64; N],
+            #[cfg_attr(feature="serde", serde_as(as = "[_; N]"), serde(bound(serialize = "T: serde::Serialize", deserialize = "T: serde::Deserialize<'de>")))]
+            pub(crate) values: [T; N],
         }
 
-        impl<const N: usize> Default for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> Default for [<$quantity Array>]<N, T>
         {
             fn default() -> Self {
-                [<$quantity Array>]{unit: [<$quantity Unit>]::default(), values: [0.0; N]}
+                [<$quantity Array>]{unit: [<$quantity Unit>]::default(), values: [T::from_f64(0.0); N]}
             }
         }
 
-        impl<const N: usize> Deref for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> Deref for [<$quantity Array>]<N, T>
         {
-            type Target = [f64; N];
-            fn deref(&self) -> &[f64; N] {
+            type Target = [T; N];
+            fn deref(&self) -> &[T; N] {
                 &self.values
             }        
         }
 
-        impl<const N: usize> DerefMut for  [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> DerefMut for  [<$quantity Array>]<N, T>
         {
-            fn deref_mut(&mut self) -> &mut [f64; N] {
+            fn deref_mut(&mut self) -> &mut [T; N] {
                 &mut self.values
             }
         } 
@@ -261,37 +313,37 @@ macro_rules! create_multivalue_quantities {
         #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
         #[cfg_attr(feature="utoipa", derive(ToSchema))]
         #[doc = "Vector storage for a series of values and [`" [<$quantity Unit>]"`]."]
-        pub struct [<$quantity Vec>]
+        pub struct [<$quantity Vec>]<T = f64>
         {
             pub(crate) unit: [<$quantity Unit>],
-            pub(crate) values: Vec<f64>
+            pub(crate) values: Vec<T>
         }
 
-        impl Deref for [<$quantity Vec>]
+        impl<T: Numeric> Deref for [<$quantity Vec>]<T>
         {
-            type Target = Vec<f64>;
-            fn deref(&self) -> &Vec<f64> {
+            type Target = Vec<T>;
+            fn deref(&self) -> &Vec<T> {
                 &self.values
             }        
         }
-        impl DerefMut for [<$quantity Vec>]
+        impl<T: Numeric> DerefMut for [<$quantity Vec>]<T>
         {
-            fn deref_mut(&mut self) -> &mut Vec<f64> {
+            fn deref_mut(&mut self) -> &mut Vec<T> {
                 &mut self.values
             }
         }
 
-        impl $crate::traits::FixedSliceQuantity<[<$quantity Unit>], f64> for [<$quantity Vec>]
+        impl<T: Numeric> $crate::traits::FixedSliceQuantity<[<$quantity Unit>], T> for [<$quantity Vec>]<T>
         {
             fn unit(&self) -> [<$quantity Unit>] {
                 self.unit
             }
 
-            fn values(&self) -> &[f64] {
+            fn values(&self) -> &[T] {
                 &self.values.as_slice()
             }
 
-            fn values_mut(&mut self) -> &mut [f64] {
+            fn values_mut(&mut self) -> &mut [T] {
                 self.values.as_mut_slice()
             }
 
@@ -308,11 +360,7 @@ macro_rules! create_multivalue_quantities {
 
             #[inline]
             fn convert_mut(&mut self, unit: [<$quantity Unit>]) {
-                let factor = self.unit.definition().convert_unchecked(unit.definition());
-                for val in self.values.as_mut_slice().iter_mut()
-                {
-                    *val *= factor;
-                }
+                $crate::slice::convert_in_place_unchecked(self.values.as_mut_slice(), self.unit.into(), unit.into());
                 self.unit = unit;
             }
 
@@ -320,20 +368,21 @@ macro_rules! create_multivalue_quantities {
             fn try_convert(&self, unit: $crate::Units) -> Result<Self, RuntimeUnitError> where Self: Sized
             {
                 let destination_unit: [<$quantity Unit>] = unit.try_into()?;
+                $crate::slice::check_representable(&self.values, self.unit.into(), destination_unit.into())?;
                 Ok(self.convert(destination_unit))
             }
         }
-        impl<const N: usize>  $crate::traits::FixedSliceQuantity<[<$quantity Unit>], f64> for [<$quantity Array>]<N>
+        impl<const N: usize, T: Numeric> $crate::traits::FixedSliceQuantity<[<$quantity Unit>], T> for [<$quantity Array>]<N, T>
         {
             fn unit(&self) -> [<$quantity Unit>] {
                 self.unit
             }
 
-            fn values(&self) -> &[f64] {
+            fn values(&self) -> &[T] {
                 &self.values.as_slice()
             }
 
-            fn values_mut(&mut self) -> &mut [f64] {
+            fn values_mut(&mut self) -> &mut [T] {
                 self.values.as_mut_slice()
             }
 
@@ -350,22 +399,224 @@ macro_rules! create_multivalue_quantities {
 
             #[inline]
             fn convert_mut(&mut self, unit: [<$quantity Unit>]) {
-                let factor = self.unit.definition().convert_unchecked(unit.definition());
-                for val in self.values.as_mut_slice().iter_mut()
-                {
-                    *val *= factor;
-                }            
+                $crate::slice::convert_in_place_unchecked(self.values.as_mut_slice(), self.unit.into(), unit.into());
                 self.unit = unit;
             }
 
             #[inline]
             fn try_convert(&self, unit: $crate::Units) -> Result<Self, RuntimeUnitError> where Self: Sized
             {
-                let destination_unit: [<$quantity Unit>] = unit.try_into()?;                   
-                Ok(self.convert(destination_unit))      
+                let destination_unit: [<$quantity Unit>] = unit.try_into()?;
+                $crate::slice::check_representable(&self.values, self.unit.into(), destination_unit.into())?;
+                Ok(self.convert(destination_unit))
+            }
+        }
+
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! create_slice_quantities {
+    ($quantity:ident) =>
+    {
+        paste::paste!
+        {
+        #[derive(Copy, Clone, Debug)]
+        #[doc = "Borrowed slice of values and [`" [<$quantity Unit>]"`]."]
+        pub struct [<$quantity Slice>]<'a, T = f64>
+        {
+            pub(crate) unit: [<$quantity Unit>],
+            pub(crate) values: &'a [T],
+        }
+
+        #[derive(Debug)]
+        #[doc = "Mutably borrowed slice of values and [`" [<$quantity Unit>]"`], which can be converted in place."]
+        pub struct [<$quantity SliceMut>]<'a, T = f64>
+        {
+            pub(crate) unit: [<$quantity Unit>],
+            pub(crate) values: &'a mut [T],
+        }
+
+        impl<'a, T: Numeric> [<$quantity Slice>]<'a, T>
+        {
+            #[doc = "Attach a [`" [<$quantity Unit>]"`] to borrowed values."]
+            pub fn new(values: &'a [T], unit: [<$quantity Unit>]) -> Self
+            {
+                Self { unit, values }
+            }
+
+            #[doc = "Return the [`" [<$quantity Unit>]"`] of the values."]
+            pub fn unit(&self) -> [<$quantity Unit>]
+            {
+                self.unit
+            }
+
+            /// Return the borrowed values.
+            pub fn values(&self) -> &'a [T]
+            {
+                self.values
+            }
+
+            #[doc = "Return the value at `index` as a [`" $quantity "`]."]
+            pub fn get(&self, index: usize) -> Option<$quantity<T>>
+            {
+                self.values.get(index).map(|&value| $quantity::new(value, self.unit))
+            }
+
+            #[doc = "Iterate over the values as [`" $quantity "`]."]
+            pub fn iter(&self) -> impl Iterator<Item = $quantity<T>> + 'a
+            {
+                let unit = self.unit;
+                self.values.iter().map(move |&value| $quantity::new(value, unit))
+            }
+
+            /// Convert the values to `unit`, writing them to `output` (of the same length). Fails with
+            /// `RuntimeUnitError::ValueOutOfRange` if the numeric type cannot represent a converted value.
+            pub fn convert_into(&self, output: &mut [T], unit: [<$quantity Unit>]) -> Result<(), RuntimeUnitError>
+            {
+                $crate::slice::convert_slice_into(self.values, output, self.unit, unit)
+            }
+
+            #[doc = "Convert the values to `unit` into a new [`" [<$quantity Vec>]"`]."]
+            pub fn convert_to_vec(&self, unit: [<$quantity Unit>]) -> [<$quantity Vec>]<T>
+            {
+                let mut values = self.values.to_vec();
+                $crate::slice::convert_in_place_unchecked(&mut values, self.unit.into(), unit.into());
+                [<$quantity Vec>] { unit, values }
+            }
+
+            #[doc = "Copy the values into a new [`" [<$quantity Vec>]"`]."]
+            pub fn to_vec(&self) -> [<$quantity Vec>]<T>
+            {
+                [<$quantity Vec>] { unit: self.unit, values: self.values.to_vec() }
             }
         }
 
+        impl<'a, T: Numeric> [<$quantity SliceMut>]<'a, T>
+        {
+            #[doc = "Attach a [`" [<$quantity Unit>]"`] to mutably borrowed values."]
+            pub fn new(values: &'a mut [T], unit: [<$quantity Unit>]) -> Self
+            {
+                Self { unit, values }
+            }
+
+            #[doc = "Return the [`" [<$quantity Unit>]"`] of the values."]
+            pub fn unit(&self) -> [<$quantity Unit>]
+            {
+                self.unit
+            }
+
+            #[doc = "Borrow as an immutable [`" [<$quantity Slice>]"`]."]
+            pub fn as_quantity_slice(&self) -> [<$quantity Slice>]<'_, T>
+            {
+                [<$quantity Slice>] { unit: self.unit, values: self.values }
+            }
+
+            #[doc = "Return the value at `index` as a [`" $quantity "`]."]
+            pub fn get(&self, index: usize) -> Option<$quantity<T>>
+            {
+                self.as_quantity_slice().get(index)
+            }
+
+            #[doc = "Set the value at `index` from a [`" $quantity "`] (converted to the unit of this slice). Fails with `RuntimeUnitError::IndexOutOfBounds` if `index` is out of bounds, or `RuntimeUnitError::ValueOutOfRange` if the numeric type cannot represent the converted value."]
+            pub fn set(&mut self, index: usize, quantity: $quantity<T>) -> Result<(), RuntimeUnitError>
+            {
+                let len = self.values.len();
+                let value = self.values.get_mut(index).ok_or(RuntimeUnitError::IndexOutOfBounds { index, len })?;
+                *value = $crate::traits::FixedQuantity::try_convert(&quantity, self.unit.into())?.value;
+                Ok(())
+            }
+
+            #[doc = "Iterate over the values as [`" $quantity "`]."]
+            pub fn iter(&self) -> impl Iterator<Item = $quantity<T>> + '_
+            {
+                let unit = self.unit;
+                self.values.iter().map(move |&value| $quantity::new(value, unit))
+            }
+
+            /// Convert the values to `unit`, writing them to `output` (of the same length).
+            pub fn convert_into(&self, output: &mut [T], unit: [<$quantity Unit>]) -> Result<(), RuntimeUnitError>
+            {
+                self.as_quantity_slice().convert_into(output, unit)
+            }
+
+            /// Convert the values to `unit` in place, updating the unit of this slice. Values that the numeric type cannot
+            /// represent are rounded or saturated, see [`Self::try_convert_mut`].
+            pub fn convert_mut(&mut self, unit: [<$quantity Unit>])
+            {
+                $crate::slice::convert_in_place_unchecked(self.values, self.unit.into(), unit.into());
+                self.unit = unit;
+            }
+
+            /// Convert the values to `unit` in place, updating the unit of this slice. Fails with
+            /// `RuntimeUnitError::ValueOutOfRange`, leaving the values unchanged, if the numeric type cannot represent a
+            /// converted value.
+            pub fn try_convert_mut(&mut self, unit: [<$quantity Unit>]) -> Result<(), RuntimeUnitError>
+            {
+                $crate::slice::convert_slice_in_place(self.values, self.unit, unit)?;
+                self.unit = unit;
+                Ok(())
+            }
+        }
+
+        impl<T: Numeric> Deref for [<$quantity Slice>]<'_, T>
+        {
+            type Target = [T];
+            fn deref(&self) -> &[T] {
+                self.values
+            }
+        }
+
+        impl<T: Numeric> Deref for [<$quantity SliceMut>]<'_, T>
+        {
+            type Target = [T];
+            fn deref(&self) -> &[T] {
+                self.values
+            }
+        }
+
+        impl<T: Numeric> DerefMut for [<$quantity SliceMut>]<'_, T>
+        {
+            fn deref_mut(&mut self) -> &mut [T] {
+                self.values
+            }
+        }
+
+        impl<'a, T: Numeric> From<[<$quantity Slice>]<'a, T>> for $crate::slice_quantity::QuantitySlice<'a, T>
+        {
+            fn from(slice: [<$quantity Slice>]<'a, T>) -> Self
+            {
+                $crate::slice_quantity::QuantitySlice { unit: slice.unit.into(), values: slice.values }
+            }
+        }
+
+        impl<'a, T: Numeric> TryFrom<$crate::slice_quantity::QuantitySlice<'a, T>> for [<$quantity Slice>]<'a, T>
+        {
+            type Error = RuntimeUnitError;
+            fn try_from(slice: $crate::slice_quantity::QuantitySlice<'a, T>) -> Result<Self, Self::Error>
+            {
+                Ok(Self { unit: [<$quantity Unit>]::try_from(slice.unit)?, values: slice.values })
+            }
+        }
+
+        impl<T: Numeric> [<$quantity Vec>]<T>
+        {
+            #[doc = "Borrow the values as a [`" [<$quantity Slice>]"`]."]
+            pub fn as_quantity_slice(&self) -> [<$quantity Slice>]<'_, T>
+            {
+                [<$quantity Slice>] { unit: self.unit, values: &self.values }
+            }
+        }
+
+        impl<const N: usize, T: Numeric> [<$quantity Array>]<N, T>
+        {
+            #[doc = "Borrow the values as a [`" [<$quantity Slice>]"`]."]
+            pub fn as_quantity_slice(&self) -> [<$quantity Slice>]<'_, T>
+            {
+                [<$quantity Slice>] { unit: self.unit, values: &self.values }
+            }
+        }
         }
     }
 }
@@ -467,7 +718,7 @@ macro_rules! create_multivalue_quantities_vec_enum {
                     {
                         $(
                             #[cfg(any(feature = "" $quantity, feature="All"))]   
-                            QuantitiesVec::$quantity(x)=>crate::vector_quantity::VecQuantity { values: x.values, unit: UnitDefinition{multiplier: x.unit.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>]} },
+                            QuantitiesVec::$quantity(x)=>crate::vector_quantity::VecQuantity { values: x.values, unit: UnitDefinition{multiplier: x.unit.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>], offset: x.unit.offset(), kind: x.unit.kind()} },
                         )+
                     }
                 }
@@ -578,7 +829,7 @@ macro_rules! create_multivalue_quantities_array_enum {
                     {
                         $(
                             #[cfg(any(feature = "" $quantity, feature="All"))]   
-                            QuantitiesArray::$quantity(x)=>ArrayQuantity { values: x.values, unit: UnitDefinition{multiplier: x.unit.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>]} },
+                            QuantitiesArray::$quantity(x)=>ArrayQuantity { values: x.values, unit: UnitDefinition{multiplier: x.unit.multiplier(), base: *[<$quantity:snake>]::[<$quantity:upper _UNIT_BASE>], offset: x.unit.offset(), kind: x.unit.kind()} },
                         )+
                     }
                 }
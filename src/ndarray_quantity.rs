@@ -0,0 +1,348 @@
+//! N-dimensional arrays of values with a unit (with the `ndarray` feature), e.g. 3-D grids of a simulation field.
+//!
+//! Products and quotients broadcast the shapes of their operands like `ndarray` does, and combine units like `Quantity`.
+//! Sums and differences check units like `Quantity` (e.g. an interval plus a temperature is a temperature) and broadcast
+//! the right-hand side to the shape of the left-hand side.
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Deref, DerefMut};
+
+use ndarray::{Array, ArrayView, DimMax, Dimension, IntoDimension, NdIndex, Zip};
+
+use crate::errors::RuntimeUnitError;
+use crate::quantity::Quantity;
+use crate::traits::{ArbitraryQuantity, CheckedAdditive, FixedQuantity, IsScalarQuantity, Unit};
+use crate::units_base::UnitDefinition;
+
+#[derive(Clone, Debug, PartialEq)]
+#[doc ="Data structure to hold a unit and an n-dimensional array of data"]
+pub struct QuantityNd<D: Dimension>
+{
+    pub unit: UnitDefinition,
+    pub values: Array<f64, D>
+}
+
+/// Shape of the result of broadcasting two shapes together, failing if they are incompatible.
+fn broadcast_shape<D: Dimension + DimMax<E>, E: Dimension>(lhs: &D, rhs: &E) -> Result<<D as DimMax<E>>::Output, RuntimeUnitError>
+{
+    let (lhs, rhs) = (lhs.slice(), rhs.slice());
+    let mut shape = <D as DimMax<E>>::Output::zeros(lhs.len().max(rhs.len()));
+    let ndim = shape.ndim();
+    for (index, size) in shape.slice_mut().iter_mut().enumerate()
+    {
+        // Shapes are aligned on their last axis, missing axes have a length of 1.
+        let axis = |shape: &[usize]| (index + shape.len()).checked_sub(ndim).map_or(1, |axis| shape[axis]);
+        *size = match (axis(lhs), axis(rhs))
+        {
+            (lhs, rhs) if lhs == rhs || rhs == 1 => lhs,
+            (1, rhs) => rhs,
+            _ => return Err(RuntimeUnitError::ShapeMismatch { lhs: lhs.to_vec(), rhs: rhs.to_vec() }),
+        };
+    }
+    Ok(shape)
+}
+
+impl<D: Dimension> QuantityNd<D>
+{
+    /// Create a new `QuantityNd` from an array of `values` in a given `unit`
+    pub fn new(values: Array<f64, D>, unit: UnitDefinition) -> Self
+    {
+        Self { unit, values }
+    }
+
+    /// Return the value at `index` as a `Quantity`
+    pub fn get<I: NdIndex<D>>(&self, index: I) -> Option<Quantity>
+    {
+        self.values.get(index).map(|&value| Quantity::new(value, self.unit))
+    }
+
+    ///
+    /// Return the value at `index` as a typed quantity in the given `unit` (e.g. `let p: Pressure = field.try_get_as([0, 1, 2], PressureUnit::kilopascal)?`),
+    /// failing if `index` is out of bounds (for its first axis out of bounds) or the units are incompatible.
+    ///
+    pub fn try_get_as<I: NdIndex<D> + IntoDimension<Dim = D> + Clone, U: Unit + Into<UnitDefinition>, Q: FixedQuantity<U> + From<Quantity>>(&self, index: I, unit: U) -> Result<Q, RuntimeUnitError>
+    {
+        let quantity = self.get(index.clone()).ok_or_else(|| self.out_of_bounds(index.into_dimension()))?;
+        Ok(Q::from(quantity.try_convert(unit.into())?))
+    }
+
+    /// Error for an `index` out of bounds: its first axis out of bounds, or a number of axes different from the shape.
+    fn out_of_bounds(&self, index: D) -> RuntimeUnitError
+    {
+        let (shape, ndim) = (self.values.shape(), index.ndim());
+        match index.slice().iter().zip(shape).find(|(index, len)| index >= len)
+        {
+            Some((&index, &len)) if ndim == shape.len() => RuntimeUnitError::IndexOutOfBounds { index, len },
+            _ => RuntimeUnitError::LengthMismatch { expected: shape.len(), found: index.ndim() },
+        }
+    }
+
+    /// Iterate over the values as `Quantity`, in logical order
+    pub fn iter(&self) -> impl Iterator<Item = Quantity> + '_
+    {
+        self.values.iter().map(|&value| Quantity::new(value, self.unit))
+    }
+
+    /// Multiply two quantities element-wise, broadcasting their shapes and failing if they are incompatible or if a
+    /// resulting power of the units cannot be represented.
+    pub fn checked_mul<E: Dimension>(&self, rhs: &QuantityNd<E>) -> Result<QuantityNd<<D as DimMax<E>>::Output>, RuntimeUnitError> where D: DimMax<E>
+    {
+        self.zip_with(rhs, self.unit.checked_mul(rhs.unit)?, |lhs, rhs| lhs * rhs)
+    }
+
+    /// Divide two quantities element-wise, broadcasting their shapes and failing if they are incompatible or if a
+    /// resulting power of the units cannot be represented.
+    pub fn checked_div<E: Dimension>(&self, rhs: &QuantityNd<E>) -> Result<QuantityNd<<D as DimMax<E>>::Output>, RuntimeUnitError> where D: DimMax<E>
+    {
+        self.zip_with(rhs, self.unit.checked_div(rhs.unit)?, |lhs, rhs| lhs / rhs)
+    }
+
+    fn zip_with<E: Dimension>(&self, rhs: &QuantityNd<E>, unit: UnitDefinition, op: impl Fn(f64, f64) -> f64) -> Result<QuantityNd<<D as DimMax<E>>::Output>, RuntimeUnitError> where D: DimMax<E>
+    {
+        let shape = broadcast_shape(&self.values.raw_dim(), &rhs.values.raw_dim())?;
+        let (lhs_values, rhs_values) = (self.relative_values(), rhs.relative_values());
+        let lhs = lhs_values.broadcast(shape.clone()).expect("Broadcast shape is valid");
+        let rhs = rhs_values.broadcast(shape).expect("Broadcast shape is valid");
+        Ok(QuantityNd { unit, values: Zip::from(&lhs).and(&rhs).map_collect(|&lhs, &rhs| op(lhs, rhs)) })
+    }
+
+    /// Values measured from the zero of the scale of an absolute unit (e.g. 293.15 for 20 °C), for products and quotients.
+    fn relative_values(&self) -> Array<f64, D>
+    {
+        self.values.mapv(|value| self.unit.relative_value(value))
+    }
+
+    /// Broadcast the values of `rhs` to the shape of this quantity.
+    fn broadcast_rhs<'a, E: Dimension>(&self, rhs: &'a QuantityNd<E>) -> Result<ArrayView<'a, f64, D>, RuntimeUnitError>
+    {
+        rhs.values.broadcast(self.values.raw_dim())
+            .ok_or_else(|| RuntimeUnitError::ShapeMismatch { lhs: self.values.shape().to_vec(), rhs: rhs.values.shape().to_vec() })
+    }
+}
+
+impl<D: Dimension> Deref for QuantityNd<D>
+{
+    type Target = Array<f64, D>;
+    fn deref(&self) -> &Array<f64, D> {
+        &self.values
+    }
+}
+impl<D: Dimension> DerefMut for QuantityNd<D>
+{
+    fn deref_mut(&mut self) -> &mut Array<f64, D> {
+        &mut self.values
+    }
+}
+
+impl<D: Dimension> ArbitraryQuantity for QuantityNd<D>
+{
+    fn unit(&self) -> UnitDefinition {
+        self.unit
+    }
+
+    fn convert(&self, unit: UnitDefinition) -> Self
+    {
+        let mut result = self.clone();
+        result.convert_mut(unit);
+        result
+    }
+
+    fn convert_mut(&mut self, unit: UnitDefinition) {
+        let (scale, shift) = self.unit.affine_conversion_unchecked(unit);
+        match self.values.as_slice_memory_order_mut()
+        {
+            Some(values) => crate::slice::scale_in_place(values, scale, shift),
+            None => self.values.mapv_inplace(|value| value * scale + shift),
+        }
+        self.unit = unit;
+    }
+
+    fn try_convert(&self, unit: UnitDefinition) -> Result<Self, RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        Ok(self.convert(unit))
+    }
+
+    fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        self.convert_mut(unit);
+        Ok(())
+    }
+
+    fn unit_mut(&mut self) -> &mut UnitDefinition {
+        &mut self.unit
+    }
+}
+
+impl<D: Dimension> Mul<f64> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+impl<D: Dimension> Div<f64> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    fn div(mut self, rhs: f64) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+impl<D: Dimension> MulAssign<f64> for QuantityNd<D>
+{
+    fn mul_assign(&mut self, rhs: f64) {
+        self.values *= rhs;
+    }
+}
+impl<D: Dimension> DivAssign<f64> for QuantityNd<D>
+{
+    fn div_assign(&mut self, rhs: f64) {
+        self.values /= rhs;
+    }
+}
+
+impl<D: Dimension> Mul<Quantity> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    fn mul(self, rhs: Quantity) -> Self::Output {
+        QuantityNd { unit: self.unit * rhs.unit, values: self.relative_values() * rhs.relative_value() }
+    }
+}
+impl<D: Dimension> Div<Quantity> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    fn div(self, rhs: Quantity) -> Self::Output {
+        QuantityNd { unit: self.unit / rhs.unit, values: self.relative_values() / rhs.relative_value() }
+    }
+}
+impl<D: Dimension, Q: IsScalarQuantity<Value = f64>> Mul<Q> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    fn mul(self, rhs: Q) -> Self::Output {
+        self * Quantity::new(rhs.value(), rhs.unit())
+    }
+}
+impl<D: Dimension, Q: IsScalarQuantity<Value = f64>> Div<Q> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    fn div(self, rhs: Q) -> Self::Output {
+        self / Quantity::new(rhs.value(), rhs.unit())
+    }
+}
+
+impl<D: Dimension + DimMax<E>, E: Dimension> Mul<QuantityNd<E>> for QuantityNd<D>
+{
+    type Output = QuantityNd<<D as DimMax<E>>::Output>;
+
+    /// Panics on incompatible shapes, see [`QuantityNd::checked_mul`].
+    fn mul(self, rhs: QuantityNd<E>) -> Self::Output {
+        self.checked_mul(&rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl<D: Dimension + DimMax<E>, E: Dimension> Div<QuantityNd<E>> for QuantityNd<D>
+{
+    type Output = QuantityNd<<D as DimMax<E>>::Output>;
+
+    /// Panics on incompatible shapes, see [`QuantityNd::checked_div`].
+    fn div(self, rhs: QuantityNd<E>) -> Self::Output {
+        self.checked_div(&rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl<D: Dimension, E: Dimension> MulAssign<QuantityNd<E>> for QuantityNd<D>
+{
+    /// Panics if `rhs` cannot be broadcast to the shape of this quantity.
+    fn mul_assign(&mut self, rhs: QuantityNd<E>) {
+        let values = self.broadcast_rhs(&rhs).unwrap_or_else(|error| panic!("{error}"));
+        let unit = self.unit;
+        Zip::from(&mut self.values).and(&values).for_each(|lhs, &value| *lhs = unit.relative_value(*lhs) * rhs.unit.relative_value(value));
+        self.unit = self.unit * rhs.unit;
+    }
+}
+impl<D: Dimension, E: Dimension> DivAssign<QuantityNd<E>> for QuantityNd<D>
+{
+    /// Panics if `rhs` cannot be broadcast to the shape of this quantity.
+    fn div_assign(&mut self, rhs: QuantityNd<E>) {
+        let values = self.broadcast_rhs(&rhs).unwrap_or_else(|error| panic!("{error}"));
+        let unit = self.unit;
+        Zip::from(&mut self.values).and(&values).for_each(|lhs, &value| *lhs = unit.relative_value(*lhs) / rhs.unit.relative_value(value));
+        self.unit = self.unit / rhs.unit;
+    }
+}
+
+impl<D: Dimension, E: Dimension> CheckedAdditive<QuantityNd<E>> for QuantityNd<D>
+{
+    fn checked_add(mut self, rhs: QuantityNd<E>) -> Result<Self, RuntimeUnitError> {
+        self.try_add_assign(rhs)?;
+        Ok(self)
+    }
+
+    fn checked_sub(mut self, rhs: QuantityNd<E>) -> Result<Self, RuntimeUnitError> {
+        self.try_sub_assign(rhs)?;
+        Ok(self)
+    }
+
+    fn try_add_assign(&mut self, rhs: QuantityNd<E>) -> Result<(), RuntimeUnitError> {
+        let values = self.broadcast_rhs(&rhs)?;
+        // A relative quantity plus an absolute one (e.g. 10 K + 20 °C) is absolute, in the units of `rhs`.
+        if rhs.unit.is_absolute() && !self.unit.is_absolute()
+        {
+            let factor = self.unit.try_convert(rhs.unit.interval())?;
+            Zip::from(&mut self.values).and(&values).for_each(|lhs, &rhs| *lhs = *lhs * factor + rhs);
+            self.unit = rhs.unit;
+            return Ok(());
+        }
+        let factor = rhs.unit.try_convert(self.unit.interval())?;
+        Zip::from(&mut self.values).and(&values).for_each(|lhs, &rhs| *lhs += rhs * factor);
+        Ok(())
+    }
+
+    fn try_sub_assign(&mut self, rhs: QuantityNd<E>) -> Result<(), RuntimeUnitError> {
+        let values = self.broadcast_rhs(&rhs)?;
+        // The difference of two absolute quantities (e.g. temperatures) is relative.
+        let (target, unit) = if rhs.unit.is_absolute() { (self.unit, self.unit.interval()) } else { (self.unit.interval(), self.unit) };
+        rhs.unit.try_convert(target)?;
+        let (scale, shift) = rhs.unit.affine_conversion_unchecked(target);
+        Zip::from(&mut self.values).and(&values).for_each(|lhs, &rhs| *lhs -= rhs * scale + shift);
+        self.unit = unit;
+        Ok(())
+    }
+}
+
+impl<D: Dimension, E: Dimension> Add<QuantityNd<E>> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    /// Panics on incompatible units or shapes, see [`CheckedAdditive::checked_add`].
+    fn add(self, rhs: QuantityNd<E>) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl<D: Dimension, E: Dimension> Sub<QuantityNd<E>> for QuantityNd<D>
+{
+    type Output = QuantityNd<D>;
+
+    /// Panics on incompatible units or shapes, see [`CheckedAdditive::checked_sub`].
+    fn sub(self, rhs: QuantityNd<E>) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+impl<D: Dimension, E: Dimension> AddAssign<QuantityNd<E>> for QuantityNd<D>
+{
+    /// Panics on incompatible units or shapes, see [`CheckedAdditive::try_add_assign`].
+    fn add_assign(&mut self, rhs: QuantityNd<E>) {
+        self.try_add_assign(rhs).unwrap_or_else(|error| panic!("{error}"));
+    }
+}
+impl<D: Dimension, E: Dimension> SubAssign<QuantityNd<E>> for QuantityNd<D>
+{
+    /// Panics on incompatible units or shapes, see [`CheckedAdditive::try_sub_assign`].
+    fn sub_assign(&mut self, rhs: QuantityNd<E>) {
+        self.try_sub_assign(rhs).unwrap_or_else(|error| panic!("{error}"));
+    }
+}
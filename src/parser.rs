@@ -0,0 +1,454 @@
+//! Parsing of unit expressions such as `"kg*m^2/s^2"` or `"W/(m·K)"` into a `UnitDefinition`.
+//!
+//! An expression is a composition of the unit names known to `Units` (abbreviation, singular or plural), combined with
+//! `*`, `·`, `⋅`, `×` or whitespace for multiplication and `/` for division. Parentheses group sub-expressions, and any
+//! factor may be raised to an integer or rational power with `^` (`^2`, `^-1`, `^(1/3)`), Unicode superscripts (`m²`, `s⁻¹`)
+//! or digits following a unit name (`m2`, `s-2`). Rational powers need parentheses: `m^1/2` is `m^1 / 2`, half a meter.
+//! Plain numbers are dimensionless factors (e.g. `1/s`). Units of the global [`crate::registry`] are recognized as well,
+//! followed by the names and symbols of the physical [`crate::constants`] (e.g. `"kg*speed_of_light^2"`).
+//!
+//! Names shared by units of different dimensions or magnitudes (e.g. `"h"` for hours and the Planck constant, or `"rad"`
+//! for radians and rads) fail with `RuntimeUnitError::AmbiguousUnit`. Names shared by units that only differ in kind
+//! resolve to the first quantity of the system, like `Units::from_str`. A temperature scale on its own (`"°C"` or `"K"`,
+//! as in `"20 °C".parse::<Quantity>()`) is absolute, like in [`crate::expression::eval`], while within an expression
+//! (`"J/(kg·°C)"`) it is a temperature interval.
+use core::str::FromStr;
+
+use crate::errors::RuntimeUnitError;
+use crate::measured_quantity::MeasuredQuantity;
+use crate::quantity::Quantity;
+use crate::registry::UnitRegistry;
+use crate::units_base::UnitDefinition;
+use crate::Units;
+
+///
+/// Parse a unit expression (e.g. `"kg*m^2/s^2"`, `"W/(m·K)"`, `"m·s⁻²"`) into a `UnitDefinition`.
+///
+pub fn parse_unit(input: &str) -> Result<UnitDefinition, RuntimeUnitError>
+{
+    crate::registry::global().parse_unit(input)
+}
+
+/// Parse a unit expression using the built-in units and the units of a given `registry`.
+pub(crate) fn parse_unit_with(input: &str, registry: &UnitRegistry) -> Result<UnitDefinition, RuntimeUnitError>
+{
+    let input = input.trim();
+    // The dimensionless ratio has an empty name, which is not a unit expression.
+    if input.is_empty()
+    {
+        return Err(RuntimeUnitError::UnknownUnit { input: input.to_owned(), quantity: None });
+    }
+    if let Some(unit) = absolute_scale(input)
+    {
+        return Ok(unit.into());
+    }
+    // Names such as "m/s" or "J/(m³ · K)" are defined directly in the unit tables.
+    if let Some(unit) = lookup(input, registry)?
+    {
+        return Ok(unit);
+    }
+    let mut parser = Parser { chars: input.chars().collect(), position: 0, registry };
+    let unit = parser.expression()?;
+    parser.skip_whitespace();
+    if parser.position < parser.chars.len()
+    {
+        return Err(parser.error("Unexpected character"));
+    }
+    Ok(unit)
+}
+
+impl FromStr for UnitDefinition
+{
+    type Err = RuntimeUnitError;
+
+    /// Parses a unit expression, see [`parse_unit`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_unit(s)
+    }
+}
+
+impl FromStr for Quantity
+{
+    type Err = RuntimeUnitError;
+
+    /// Parses a string into a `Quantity`.
+    ///
+    /// # Format
+    /// The expected input format is: `<value> <unit expression>`, e.g. `"9.81 m/s^2"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut parts = s.splitn(2, char::is_whitespace);
+        let value_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing value".into()))?;
+        let unit_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing unit".into()))?;
+        let value: f64 = value_str.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: value_str.to_owned() })?;
+        Ok(Quantity::new(value, parse_unit(unit_str)?))
+    }
+}
+
+impl FromStr for MeasuredQuantity
+{
+    type Err = RuntimeUnitError;
+
+    /// Parses a string into a `MeasuredQuantity`.
+    ///
+    /// # Format
+    /// The expected input format is either `<value> ± <uncertainty> <unit expression>` (or `+/-`), e.g. `"12.3 ± 0.2 mm"`, 
+    /// or the concise notation `<value>(<uncertainty digits>) <unit expression>`, e.g. `"12.3(2) mm"` or `"6.674(15)e-11 m^3/(kg*s^2)"`,
+    /// where the digits in parentheses are the uncertainty of the last digits of the value.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some((value_str, rest)) = s.split_once('±').or_else(|| s.split_once("+/-"))
+        {
+            let mut parts = rest.trim_start().splitn(2, char::is_whitespace);
+            let uncertainty_str = parts.next().filter(|part| !part.is_empty()).ok_or_else(|| RuntimeUnitError::ParseError("Missing uncertainty".into()))?;
+            let unit_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing unit".into()))?;
+            return Ok(MeasuredQuantity::new(number(value_str.trim())?, number(uncertainty_str)?, parse_unit(unit_str)?));
+        }
+        let mut parts = s.splitn(2, char::is_whitespace);
+        let value_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing value".into()))?;
+        let unit_str = parts.next().ok_or_else(|| RuntimeUnitError::ParseError("Missing unit".into()))?;
+        let (value, uncertainty) = concise_uncertainty(value_str)?;
+        Ok(MeasuredQuantity::new(value, uncertainty, parse_unit(unit_str)?))
+    }
+}
+
+fn number(input: &str) -> Result<f64, RuntimeUnitError>
+{
+    input.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: input.to_owned() })
+}
+
+/// Parse a value in concise notation, e.g. `"12.3(2)"` (12.3 ± 0.2) or `"1.2345(12)e-3"` (0.0012345 ± 0.0000012).
+fn concise_uncertainty(input: &str) -> Result<(f64, f64), RuntimeUnitError>
+{
+    let invalid = || RuntimeUnitError::InvalidNumber { input: input.to_owned() };
+    let (mantissa, rest) = input.split_once('(').ok_or_else(|| RuntimeUnitError::ParseError(format!("Missing uncertainty in \"{input}\"")))?;
+    let (digits, exponent) = rest.split_once(')').ok_or_else(invalid)?;
+    let value = number(&format!("{mantissa}{exponent}")).map_err(|_| invalid())?;
+    let scale = match exponent
+    {
+        "" => 1.0,
+        exponent => 10f64.powi(exponent[1..].parse::<i32>().map_err(|_| invalid())?)
+    };
+    let uncertainty = number(digits).map_err(|_| invalid())?;
+    // Digits without a decimal point apply to the last decimals of the value, e.g. 12.3(2) or 12.30(25).
+    let decimals = if digits.contains('.') { 0 } else { mantissa.split_once('.').map_or(0, |(_, decimals)| decimals.len() as i32) };
+    Ok((value, uncertainty * 10f64.powi(-decimals) * scale))
+}
+
+/// Absolute temperature scale named `name`, if any (e.g. degrees Celsius for `"°C"` or kelvins for `"K"`, which also name
+/// temperature intervals).
+pub(crate) fn absolute_scale(name: &str) -> Option<Units>
+{
+    Units::candidates(name).into_iter().find(|&unit| UnitDefinition::from(unit).is_absolute())
+}
+
+/// Find a built-in unit by name. Fails if units of different dimensions or magnitudes share the name, while of units that
+/// only differ in kind or offset the first quantity of the system is used.
+pub(crate) fn find_unit(name: &str) -> Result<Option<Units>, RuntimeUnitError>
+{
+    let candidates = Units::candidates(name);
+    if let Some(&first) = candidates.first()
+    {
+        let unit = UnitDefinition::from(first);
+        let differs = |candidate: &Units|
+        {
+            let candidate = UnitDefinition::from(*candidate);
+            candidate.base != unit.base || (1.0 - candidate.multiplier / unit.multiplier).abs() > 1e-12
+        };
+        if candidates.iter().any(differs)
+        {
+            return Err(RuntimeUnitError::AmbiguousUnit { input: name.to_owned(), candidates });
+        }
+    }
+    Ok(candidates.first().copied())
+}
+
+/// Find a built-in or registered unit, or a physical constant, by name, see [`find_unit`].
+fn lookup(name: &str, registry: &UnitRegistry) -> Result<Option<UnitDefinition>, RuntimeUnitError>
+{
+    Ok(find_unit(name)?.map(UnitDefinition::from)
+        .or_else(|| registry.get(name))
+        .or_else(|| crate::constants::find(name).map(|constant| constant.as_unit())))
+}
+
+/// Check that an exponent fits in the powers of a `UnitBase`.
+fn narrow(numerator: i64, denominator: i64) -> Result<(i8, i8), RuntimeUnitError>
+{
+    match (i8::try_from(numerator), i8::try_from(denominator))
+    {
+        (Ok(numerator), Ok(denominator)) => Ok((numerator, denominator)),
+        _ => Err(RuntimeUnitError::ExponentOverflow { numerator, denominator })
+    }
+}
+
+/// Characters used for multiplication of two units.
+pub(crate) const MULTIPLY: [char; 4] = ['*', '·', '⋅', '×'];
+
+/// Convert a Unicode superscript character to its ASCII equivalent.
+pub(crate) fn superscript(c: char) -> Option<char>
+{
+    match c
+    {
+        '⁰' => Some('0'),
+        '¹' => Some('1'),
+        '²' => Some('2'),
+        '³' => Some('3'),
+        '⁴' => Some('4'),
+        '⁵' => Some('5'),
+        '⁶' => Some('6'),
+        '⁷' => Some('7'),
+        '⁸' => Some('8'),
+        '⁹' => Some('9'),
+        '⁻' => Some('-'),
+        '⁺' => Some('+'),
+        _ => None
+    }
+}
+
+/// End of a number literal starting at `start`, including a decimal exponent (e.g. `2.5e-3`). An `e` is only an exponent
+/// when followed by digits, e.g. `1e-3` vs `2 e` (elementary charges).
+pub(crate) fn number_end(chars: &[char], start: usize) -> usize
+{
+    let mut end = start;
+    while chars.get(end).is_some_and(|&c| c.is_ascii_digit() || c == '.')
+    {
+        end += 1;
+    }
+    if matches!(chars.get(end), Some('e' | 'E'))
+    {
+        let digits = if matches!(chars.get(end + 1), Some('-' | '+')) { end + 2 } else { end + 1 };
+        if chars.get(digits).is_some_and(char::is_ascii_digit)
+        {
+            end = digits;
+            while chars.get(end).is_some_and(char::is_ascii_digit)
+            {
+                end += 1;
+            }
+        }
+    }
+    end
+}
+
+/// Check whether a character may be part of a unit name.
+fn is_symbol(c: char) -> bool
+{
+    !(c.is_whitespace() || MULTIPLY.contains(&c) || matches!(c, '/' | '(' | ')' | '^') || superscript(c).is_some())
+}
+
+/// Check whether a name can be read back as a single unit name: it may not start like a number (e.g. `2m`), contain
+/// operators, parentheses, whitespace or superscripts (e.g. `m/s` or `m²`), or end with digits read as a power (e.g. `m2`).
+pub(crate) fn is_unit_name(name: &str) -> bool
+{
+    !name.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && !name.ends_with(|c: char| c.is_ascii_digit())
+        && !name.is_empty() && name.chars().all(is_symbol)
+}
+
+struct Parser<'a>
+{
+    chars: Vec<char>,
+    position: usize,
+    registry: &'a UnitRegistry,
+}
+
+impl Parser<'_>
+{
+    fn error(&self, message: &str) -> RuntimeUnitError
+    {
+        RuntimeUnitError::ParseError(format!("{message} at position {} in unit \"{}\"", self.position, self.chars.iter().collect::<String>()))
+    }
+
+    fn peek(&self) -> Option<char>
+    {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self)
+    {
+        while self.peek().is_some_and(char::is_whitespace)
+        {
+            self.position += 1;
+        }
+    }
+
+    /// expression := term (('*' | '/' | whitespace) term)*
+    fn expression(&mut self) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        let mut unit = self.term()?;
+        loop
+        {
+            self.skip_whitespace();
+            match self.peek()
+            {
+                Some('/') =>
+                {
+                    self.position += 1;
+                    unit = unit.checked_div(self.term()?)?;
+                }
+                Some(c) if MULTIPLY.contains(&c) =>
+                {
+                    self.position += 1;
+                    unit = unit.checked_mul(self.term()?)?;
+                }
+                // Implicit multiplication, e.g. "N m"
+                Some(c) if c == '(' || is_symbol(c) => unit = unit.checked_mul(self.term()?)?,
+                _ => return Ok(unit)
+            }
+        }
+    }
+
+    /// term := factor exponent?
+    fn term(&mut self) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        let unit = self.factor()?;
+        match self.exponent()?
+        {
+            Some((numerator, 1)) => unit.checked_powi(numerator),
+            Some((numerator, denominator)) => unit.checked_powf(numerator as f64 / denominator as f64),
+            None => Ok(unit)
+        }
+    }
+
+    /// factor := '(' expression ')' | number | unit name
+    fn factor(&mut self) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        self.skip_whitespace();
+        match self.peek()
+        {
+            Some('(') =>
+            {
+                self.position += 1;
+                let unit = self.expression()?;
+                self.skip_whitespace();
+                if self.peek() != Some(')')
+                {
+                    return Err(self.error("Expected ')'"));
+                }
+                self.position += 1;
+                Ok(unit)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' =>
+            {
+                let start = self.position;
+                self.position = number_end(&self.chars, start);
+                let number: String = self.chars[start..self.position].iter().collect();
+                let multiplier: f64 = number.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: number.clone() })?;
+                Ok(UnitDefinition { multiplier, ..UnitDefinition::dimensionless() })
+            }
+            Some(c) if is_symbol(c) =>
+            {
+                let start = self.position;
+                while self.peek().is_some_and(is_symbol)
+                {
+                    self.position += 1;
+                }
+                let name: String = self.chars[start..self.position].iter().collect();
+                if let Some(unit) = lookup(&name, self.registry)?
+                {
+                    return Ok(unit);
+                }
+                // Trailing digits of an unknown name are a power, e.g. "m2" or "s-1"
+                let digits = name.trim_end_matches(|c: char| c.is_ascii_digit());
+                let base = digits.strip_suffix('-').unwrap_or(digits);
+                match lookup(base, self.registry)?
+                {
+                    Some(unit) if !base.is_empty() && digits.len() < name.len() =>
+                    {
+                        let exponent = &name[base.len()..];
+                        let numerator = exponent.parse().map_err(|_| RuntimeUnitError::InvalidNumber { input: exponent.to_owned() })?;
+                        unit.checked_powi(narrow(numerator, 1)?.0)
+                    }
+                    _ => Err(RuntimeUnitError::UnknownUnit { input: name, quantity: None })
+                }
+            }
+            _ => Err(self.error("Expected a unit"))
+        }
+    }
+
+    /// exponent := '^' integer | '^' '(' rational ')' | superscript+
+    fn exponent(&mut self) -> Result<Option<(i8, i8)>, RuntimeUnitError>
+    {
+        if self.peek() == Some('^')
+        {
+            self.position += 1;
+            self.skip_whitespace();
+            if self.peek() == Some('(')
+            {
+                self.position += 1;
+                let exponent = self.rational(true)?;
+                self.skip_whitespace();
+                if self.peek() != Some(')')
+                {
+                    return Err(self.error("Expected ')'"));
+                }
+                self.position += 1;
+                Ok(Some(exponent))
+            }
+            else
+            {
+                self.rational(false).map(Some)
+            }
+        }
+        else if self.peek().and_then(superscript).is_some()
+        {
+            let start = self.position;
+            while self.peek().and_then(superscript).is_some()
+            {
+                self.position += 1;
+            }
+            let exponent: String = self.chars[start..self.position].iter().filter_map(|&c| superscript(c)).collect();
+            let numerator = exponent.parse().map_err(|_|
+            {
+                self.position = start;
+                self.error("Invalid exponent")
+            })?;
+            narrow(numerator, 1).map(Some)
+        }
+        else
+        {
+            Ok(None)
+        }
+    }
+
+    /// rational := ['-' | '+'] integer ['/' integer], where the denominator is only allowed within parentheses (`fraction`)
+    fn rational(&mut self, fraction: bool) -> Result<(i8, i8), RuntimeUnitError>
+    {
+        let numerator = self.integer(true)?;
+        self.skip_whitespace();
+        // Without parentheses, "m^1/2" is "m^1 / 2" like "m^2/s" is "m^2 / s".
+        let denominator = if fraction && self.peek() == Some('/')
+        {
+            self.position += 1;
+            self.integer(false)?
+        }
+        else
+        {
+            1
+        };
+        if denominator == 0
+        {
+            return Err(self.error("Invalid exponent"));
+        }
+        narrow(numerator, denominator)
+    }
+
+    fn integer(&mut self, signed: bool) -> Result<i64, RuntimeUnitError>
+    {
+        self.skip_whitespace();
+        let start = self.position;
+        if signed && matches!(self.peek(), Some('-' | '+'))
+        {
+            self.position += 1;
+        }
+        while self.peek().is_some_and(|c| c.is_ascii_digit())
+        {
+            self.position += 1;
+        }
+        let integer: String = self.chars[start..self.position].iter().collect();
+        integer.parse().map_err(|_|
+        {
+            self.position = start;
+            self.error("Invalid exponent")
+        })
+    }
+}
@@ -2,31 +2,36 @@ use core::fmt::{Display, Debug};
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::errors::RuntimeUnitError;
-use crate::traits::ArbitraryQuantity;
+use crate::traits::{ArbitraryQuantity, CheckedAdditive, Numeric};
 use crate::units_base::UnitDefinition;
 
-impl crate::traits::ArbitraryQuantity for Quantity
+impl<T: Numeric> crate::traits::ArbitraryQuantity for Quantity<T>
 {
     fn unit(&self) -> UnitDefinition {
         self.unit
     }
 
-    fn convert(&self, unit: UnitDefinition)  -> Quantity
+    fn convert(&self, unit: UnitDefinition)  -> Quantity<T>
     {
-        Quantity { value: self.value() * self.unit.convert_unchecked(unit), unit }
+        Quantity { value: self.convert_unchecked(unit), unit }
     }
     
     fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError> {
-        self.value *= self.unit.try_convert(unit)?;
+        self.unit.try_convert(unit)?;
+        crate::slice::check_representable(core::slice::from_ref(&self.value), self.unit, unit)?;
+        self.convert_mut(unit);
         Ok(())
     }
     
     fn convert_mut(&mut self, unit: UnitDefinition) {
-        self.value *= self.unit.convert_unchecked(unit);
+        self.value = self.convert_unchecked(unit);
+        self.unit = unit;
     }
     
-    fn try_convert(&self, unit: UnitDefinition) -> Result<Quantity, RuntimeUnitError> {
-        Ok(Quantity { value : self.value * self.unit.try_convert(unit)?, unit })
+    fn try_convert(&self, unit: UnitDefinition) -> Result<Quantity<T>, RuntimeUnitError> {
+        self.unit.try_convert(unit)?;
+        crate::slice::check_representable(core::slice::from_ref(&self.value), self.unit, unit)?;
+        Ok(self.convert(unit))
     }
     
     fn unit_mut(&mut self) -> &mut UnitDefinition {
@@ -34,20 +39,20 @@ impl crate::traits::ArbitraryQuantity for Quantity
     }
 }
 
-#[doc = "A quantity of a unit, supports converting from one unit to another." ]
+#[doc = "A quantity of a unit, supports converting from one unit to another. Values are `f64` unless another `Numeric` type is given (e.g. `Quantity<f32>`)." ]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Copy, Clone)]
-pub struct Quantity
+pub struct Quantity<T = f64>
 {
-    pub(crate) value: f64,
+    pub(crate) value: T,
     pub(crate) unit: UnitDefinition
 }
-impl Quantity
+impl<T: Numeric> Quantity<T>
 {
     ///
     /// Create a new instance of `Quantity` with a given `value` and `unit` 
     ///
-    pub fn new(value: f64, unit: UnitDefinition) -> Self
+    pub fn new(value: T, unit: UnitDefinition) -> Self
     {        
         Self { value, unit }
     }
@@ -56,7 +61,7 @@ impl Quantity
     /// Amount of unit stored in quantity
     /// 
     #[inline]    
-    pub fn value(&self) -> f64
+    pub fn value(&self) -> T
     {
         self.value
     }
@@ -65,179 +70,274 @@ impl Quantity
     /// Get mutable reference to the value for this quantity.
     /// 
     #[inline]    
-    pub fn value_mut(&mut self) -> &mut f64
+    pub fn value_mut(&mut self) -> &mut T
     {
         &mut self.value
     }
 
+    ///
+    /// Convert to the `Quantities` variant matching the dimensions of this quantity, using the best matching named unit 
+    /// (see [`crate::Units::find`]), or the base unit of the first matching quantity if no unit matches the multiplier.
+    /// 
+    pub fn try_into_quantities(&self) -> Result<crate::Quantities, RuntimeUnitError>
+    {
+        let unit = match crate::Units::find(self.unit, 1e-9)
+        {
+            Some(unit) => unit,
+            None => crate::UnitTypes::matching(self.unit).iter().map(crate::UnitTypes::base_unit)
+                .find(|&unit| UnitDefinition::from(unit).is_absolute() == self.unit.is_absolute())
+                .ok_or_else(|| RuntimeUnitError::IncompatibleUnitConversion(format!("No quantity found with base units of {}", self.unit.unit_string())))?
+        };
+        Ok(crate::Quantities::new(self.convert_unchecked(unit.into()).to_f64(), unit))
+    }
+
     #[inline] 
     /// Convert from one unit to another (no check is made to ensure destination unit is valid).
-    pub(crate) fn convert_unchecked(&self, unit: UnitDefinition) -> f64
+    pub(crate) fn convert_unchecked(&self, unit: UnitDefinition) -> T
     {
         if self.unit == unit
         {
-            self.value
-        }
-        else
-        {
-            self.value * self.unit.multiplier / unit.multiplier()
+            return self.value;
         }
+        let (scale, shift) = self.unit.affine_conversion_unchecked(unit);
+        self.value.scale(scale, shift)
+    }
+
+    #[inline]
+    pub fn powf(&self, value: f64) -> Quantity<T>
+    {
+        Quantity { value: T::from_f64(self.relative_value().to_f64().powf(value)), unit: self.unit.powf(value) }
     }
 
     #[inline]
-    pub fn powf(&self, value: f64) -> Quantity
+    pub fn powi(&self, value: i8) -> Quantity<T>
+    {
+        Quantity { value: T::from_f64(self.relative_value().to_f64().powi(value as i32)), unit: self.unit.powi(value) }
+    }
+
+    /// Raise to a floating point power, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power of the 
+    /// units cannot be represented (e.g. `m^(1/10)`).
+    pub fn checked_powf(&self, value: f64) -> Result<Quantity<T>, RuntimeUnitError>
+    {
+        Ok(Quantity { value: T::from_f64(self.relative_value().to_f64().powf(value)), unit: self.unit.checked_powf(value)? })
+    }
+
+    /// Raise to an integer power, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power of the units 
+    /// cannot be represented (e.g. `m^8`).
+    pub fn checked_powi(&self, value: i8) -> Result<Quantity<T>, RuntimeUnitError>
+    {
+        Ok(Quantity { value: T::from_f64(self.relative_value().to_f64().powi(value as i32)), unit: self.unit.checked_powi(value)? })
+    }
+
+    /// Multiply two quantities, failing if a resulting power of the units cannot be represented.
+    pub fn checked_mul(&self, rhs: Quantity<T>) -> Result<Quantity<T>, RuntimeUnitError>
+    {
+        Ok(Quantity { value: self.relative_value()*rhs.relative_value(), unit: self.unit.checked_mul(rhs.unit)? })
+    }
+
+    /// Divide two quantities, failing if a resulting power of the units cannot be represented.
+    pub fn checked_div(&self, rhs: Quantity<T>) -> Result<Quantity<T>, RuntimeUnitError>
     {
-        let mut r = *self;
-        r.unit = r.unit.powf(value);
-        r.value = r.value.powf(value);
-        r
+        Ok(Quantity { value: self.relative_value()/rhs.relative_value(), unit: self.unit.checked_div(rhs.unit)? })
     }
 
+    /// Value measured from the zero of the scale of an absolute unit (e.g. 293.15 for 20 °C), see `UnitDefinition::relative_value`.
     #[inline]
-    pub fn powi(&self, value: i8) -> Quantity
+    pub(crate) fn relative_value(&self) -> T
     {
-        let mut r = *self;
-        r.unit = r.unit.powi(value);
-        r.value = r.value.powi(value as i32);
-        r
+        self.unit.relative_value(self.value)
     }
 
     #[inline]
-    pub fn inv(&self) -> Quantity
+    pub fn inv(&self) -> Quantity<T>
     {
         Quantity {
-            value: 1.0 / self.value,
+            value: T::from_f64(1.0) / self.relative_value(),
             unit: self.unit.inv(),
         }
     }
 
 
 }
-impl Debug for Quantity
+impl<T: Numeric> Debug for Quantity<T>
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result 
     {
         write!(f, "{} {:?}", self.value(), self.unit)
     }
 }
-impl Mul<f64> for Quantity
+impl<T: Numeric> Mul<f64> for Quantity<T>
 {
-    type Output = Quantity;
+    type Output = Quantity<T>;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        Self{ value: self.value*rhs, unit: self.unit }
+        Self{ value: self.value.mul_f64(rhs), unit: self.unit }
     }
 }
 
-impl Div<f64> for Quantity
+impl<T: Numeric> Div<f64> for Quantity<T>
 {
-    type Output = Quantity;
+    type Output = Quantity<T>;
 
     fn div(self, rhs: f64) -> Self::Output {
-        Self{ value: self.value/rhs, unit: self.unit }
+        Self{ value: self.value.div_f64(rhs), unit: self.unit }
     }
 }
-impl Mul<Quantity> for Quantity
+impl<T: Numeric> Mul<Quantity<T>> for Quantity<T>
 {
-    type Output = Quantity;
+    type Output = Quantity<T>;
 
-    fn mul(self, rhs: Quantity) -> Self::Output {
-        Self{ value: self.value*rhs.value, unit: self.unit*rhs.unit }
+    fn mul(self, rhs: Quantity<T>) -> Self::Output {
+        Self{ value: self.relative_value()*rhs.relative_value(), unit: self.unit*rhs.unit }
     }
 }
-impl Div<Quantity> for Quantity
+impl<T: Numeric> Div<Quantity<T>> for Quantity<T>
 {
-    type Output = Quantity;
+    type Output = Quantity<T>;
 
-    fn div(self, rhs: Quantity) -> Self::Output {
-        Self{ value: self.value/rhs.value, unit: self.unit/rhs.unit }
+    fn div(self, rhs: Quantity<T>) -> Self::Output {
+        Self{ value: self.relative_value()/rhs.relative_value(), unit: self.unit/rhs.unit }
     }
 }
-impl Add<Quantity> for Quantity
+impl<T: Numeric> CheckedAdditive for Quantity<T>
 {
-    type Output=Quantity;
+    /// Add two quantities. A relative quantity may be added to an absolute one (e.g. a temperature interval to a 
+    /// temperature), in the units of the absolute quantity whichever side it is on, but two absolute quantities cannot be added.
+    fn checked_add(self, rhs: Quantity<T>) -> Result<Quantity<T>, RuntimeUnitError> {
+        if self.unit.is_absolute() && rhs.unit.is_absolute()
+        {
+            return Err(RuntimeUnitError::AbsoluteRelativeMismatch { from: rhs.unit, to: self.unit.interval() });
+        }
+        if rhs.unit.is_absolute()
+        {
+            return rhs.checked_add(self);
+        }
+        let rhs = rhs.try_convert(self.unit.interval())?;
+        Ok(Self { value: self.value + rhs.value, unit: self.unit })
+    }
+
+    /// Subtract two quantities. The difference of two absolute quantities (e.g. two temperatures) is relative, 
+    /// and an absolute quantity cannot be subtracted from a relative one.
+    fn checked_sub(self, rhs: Quantity<T>) -> Result<Quantity<T>, RuntimeUnitError> {
+        if rhs.unit.is_absolute() && !self.unit.is_absolute()
+        {
+            return Err(RuntimeUnitError::AbsoluteRelativeMismatch { from: rhs.unit, to: self.unit });
+        }
+        let (target, unit) = if rhs.unit.is_absolute() { (self.unit, self.unit.interval()) } else { (self.unit.interval(), self.unit) };
+        let rhs = rhs.try_convert(target)?;
+        Ok(Self { value: self.value - rhs.value, unit })
+    }
 
-    fn add(self, rhs: Quantity) -> Self::Output {
-        let rhs = rhs.try_convert(self.unit).unwrap_or_else(|_| panic!("Addition failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
-        Self { value: self.value + rhs.value, unit: self.unit }
+    fn try_add_assign(&mut self, rhs: Quantity<T>) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_add(rhs)?;
+        Ok(())
     }
+
+    fn try_sub_assign(&mut self, rhs: Quantity<T>) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_sub(rhs)?;
+        Ok(())
+    }
+}
+
+/// Panic on a failed addition or subtraction (`operation`) of quantities in the given `units`.
+fn additive_panic(operation: &str, (lhs, rhs): (UnitDefinition, UnitDefinition), error: RuntimeUnitError) -> !
+{
+    let reason = if lhs.is_absolute() && rhs.is_absolute() { "absolute" } else { "incompatible" };
+    panic!("{operation} failed due to {reason} units `{lhs}` and `{rhs}`: {error}")
 }
-impl Sub<Quantity> for Quantity
+
+impl<T: Numeric> Add<Quantity<T>> for Quantity<T>
 {
-    type Output=Quantity;
+    type Output=Quantity<T>;
 
-    fn sub(self, rhs: Quantity) -> Self::Output {
-        let rhs = rhs.try_convert(self.unit).unwrap_or_else(|_| panic!("Subtraction failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
-        Self { value: self.value - rhs.value, unit: self.unit }
+    /// Add two quantities, see [`CheckedAdditive::checked_add`]. Panics on incompatible units.
+    fn add(self, rhs: Quantity<T>) -> Self::Output {
+        let units = (self.unit, rhs.unit);
+        self.checked_add(rhs).unwrap_or_else(|error| additive_panic("Addition", units, error))
     }
 }
+impl<T: Numeric> Sub<Quantity<T>> for Quantity<T>
+{
+    type Output=Quantity<T>;
 
-impl AddAssign for Quantity
+    /// Subtract two quantities, see [`CheckedAdditive::checked_sub`]. Panics on incompatible units.
+    fn sub(self, rhs: Quantity<T>) -> Self::Output {
+        let units = (self.unit, rhs.unit);
+        self.checked_sub(rhs).unwrap_or_else(|error| additive_panic("Subtraction", units, error))
+    }
+}
+
+impl<T: Numeric> AddAssign for Quantity<T>
 {
     fn add_assign(&mut self, rhs: Self) {
-        let rhs = rhs.try_convert(self.unit).unwrap_or_else(|_| panic!("Addition failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
-        self.value += rhs.value;
+        *self = *self + rhs;
     }
 }
 
-impl SubAssign for Quantity
+impl<T: Numeric> SubAssign for Quantity<T>
 {
     fn sub_assign(&mut self, rhs: Self) {
-        let rhs = rhs.try_convert(self.unit).unwrap_or_else(|_| panic!("Subtraction failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
-        self.value -= rhs.value;
+        *self = *self - rhs;
     }
 }
 
-impl MulAssign for Quantity
+impl<T: Numeric> MulAssign for Quantity<T>
 {
     fn mul_assign(&mut self, rhs: Self) {
-        self.value *= rhs.value;
-        self.unit *= rhs.unit;
+        *self = *self * rhs;
     }
 }
 
-impl MulAssign<f64> for Quantity
+impl<T: Numeric> MulAssign<f64> for Quantity<T>
 {
     fn mul_assign(&mut self, rhs: f64) {
-        self.value *= rhs;
+        self.value = self.value.mul_f64(rhs);
     }
 }
 
 
-impl DivAssign for Quantity
+impl<T: Numeric> DivAssign for Quantity<T>
 {
     fn div_assign(&mut self, rhs: Self) {
-        self.value /= rhs.value;
-        self.unit /= rhs.unit;
+        *self = *self / rhs;
     }
 }
 
-impl DivAssign<f64> for Quantity
+impl<T: Numeric> DivAssign<f64> for Quantity<T>
 {
     fn div_assign(&mut self, rhs: f64) {
-        self.value /= rhs;
+        self.value = self.value.div_f64(rhs);
     }
 }
 ///
 /// This only compares magnitudes...
 /// 
-impl PartialOrd<Quantity> for Quantity
+impl<T: Numeric> PartialOrd<Quantity<T>> for Quantity<T>
 {
-    fn partial_cmp(&self, other: &Quantity) -> Option<core::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Quantity<T>) -> Option<core::cmp::Ordering> {
         self.convert_unchecked(other.unit).partial_cmp(&other.value)
     }
 }
 
-impl PartialEq for Quantity
+impl<T: Numeric> PartialEq for Quantity<T>
 {
     fn eq(&self, other: &Self) -> bool {
-        self.unit.base == other.unit.base && self.convert_unchecked(other.unit) == other.value
+        self.unit.is_convertible(other.unit) && self.convert_unchecked(other.unit) == other.value
     }
 }
 
-impl Display for Quantity
+impl<T: Numeric> Display for Quantity<T>
 {
+    /// The alternate form (`{:#}`) displays the quantity in its most readable SI-prefixed unit (e.g. `123 nm`), where 
+    /// the quantity matches a named unit.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate()
+        {
+            if let Ok(quantity) = self.try_into_quantities()
+            {
+                return write!(f, "{}", quantity.to_human_string());
+            }
+        }
         write!(f, "{} {}", self.value, self.unit)
     }
 }
\ No newline at end of file
@@ -0,0 +1,141 @@
+//! Registry of units defined at run time (e.g. a hogshead, "hhd", or customer-specific aliases of existing units).
+//!
+//! Registered units are recognized by unit parsing (see [`crate::parser::parse_unit`]), used by the `Display` of
+//! `UnitDefinition` when no built-in unit matches, and can be used as conversion targets of any quantity. A global registry is shared by the whole
+//! process, while independent `UnitRegistry` values can be used explicitly (e.g. in tests). Whether a registered unit
+//! matches a built-in unit is decided once, when it is registered, so displaying a unit does not search the built-in units.
+use std::collections::HashMap;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use static_init::dynamic;
+
+use crate::errors::RuntimeUnitError;
+use crate::units_base::UnitDefinition;
+use crate::Units;
+
+#[dynamic]
+static GLOBAL_REGISTRY: RwLock<UnitRegistry> = RwLock::new(UnitRegistry::new());
+
+/// A unit registered at run time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegisteredUnit
+{
+    /// Name of the unit, used when displaying the unit.
+    pub name: String,
+    /// Alternative names of the unit.
+    pub aliases: Vec<String>,
+    /// Definition of the unit.
+    pub unit: UnitDefinition,
+}
+
+/// A collection of units defined at run time, in addition to the units built into the library.
+#[derive(Clone, Debug, Default)]
+pub struct UnitRegistry
+{
+    units: Vec<RegisteredUnit>,
+    names: HashMap<String, usize>,
+    /// Indices of the registered units that match no built-in unit, and are displayed by name.
+    displayed: Vec<usize>,
+}
+
+impl UnitRegistry
+{
+    /// Create an empty registry.
+    pub fn new() -> Self
+    {
+        Self { units: Vec::new(), names: HashMap::new(), displayed: Vec::new() }
+    }
+
+    /// Register a unit with a given `name` and `aliases`. Names may not be shared with built-in or already registered units
+    /// (`RuntimeUnitError::DuplicateUnit`), and must be parsed back as a single unit name (`RuntimeUnitError::InvalidUnitName`):
+    /// not empty, without operators, parentheses, whitespace or superscripts, and not ending with digits (e.g. `m2`).
+    pub fn register(&mut self, name: &str, aliases: &[&str], unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+    {
+        for &name in core::iter::once(&name).chain(aliases)
+        {
+            if !crate::parser::is_unit_name(name)
+            {
+                return Err(RuntimeUnitError::InvalidUnitName { name: name.to_owned() });
+            }
+            if self.names.contains_key(name) || !Units::candidates(name).is_empty()
+            {
+                return Err(RuntimeUnitError::DuplicateUnit { name: name.to_owned() });
+            }
+        }
+        let index = self.units.len();
+        if Units::find(unit, 1e-12).is_none()
+        {
+            self.displayed.push(index);
+        }
+        for &name in core::iter::once(&name).chain(aliases)
+        {
+            self.names.insert(name.to_owned(), index);
+        }
+        self.units.push(RegisteredUnit { name: name.to_owned(), aliases: aliases.iter().map(|&alias| alias.to_owned()).collect(), unit });
+        Ok(())
+    }
+
+    /// Register a unit defined by an `expression` of built-in and previously registered units (e.g. `"63 gal"`).
+    pub fn register_expression(&mut self, name: &str, aliases: &[&str], expression: &str) -> Result<(), RuntimeUnitError>
+    {
+        let unit = self.parse_unit(expression)?;
+        self.register(name, aliases, unit)
+    }
+
+    /// Retrieve the definition of a registered unit from its name or one of its aliases.
+    pub fn get(&self, name: &str) -> Option<UnitDefinition>
+    {
+        self.names.get(name).map(|&index| self.units[index].unit)
+    }
+
+    /// Find the registered unit matching a given `unit`, if any.
+    pub fn find(&self, unit: UnitDefinition) -> Option<&RegisteredUnit>
+    {
+        self.units.iter().find(|registered| registered.unit.approx_eq(unit, 1e-12))
+    }
+
+    /// Name of the first registered unit matching `unit` and no built-in unit, used to display `unit`.
+    pub(crate) fn display_name(&self, unit: UnitDefinition) -> Option<&str>
+    {
+        self.displayed.iter().map(|&index| &self.units[index]).find(|registered| registered.unit.approx_eq(unit, 1e-12)).map(|registered| registered.name.as_str())
+    }
+
+    /// Iterate over all registered units.
+    pub fn units(&self) -> impl Iterator<Item = &RegisteredUnit>
+    {
+        self.units.iter()
+    }
+
+    /// Parse a unit expression (see [`crate::parser::parse_unit`]) using the built-in units and the units of this registry.
+    pub fn parse_unit(&self, expression: &str) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        crate::parser::parse_unit_with(expression, self)
+    }
+}
+
+/// Retrieve the global registry.
+pub fn global() -> RwLockReadGuard<'static, UnitRegistry>
+{
+    GLOBAL_REGISTRY.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Retrieve the global registry for modification. Parsing or displaying units reads the global registry, so the guard
+/// must be dropped before doing so on the same thread.
+pub fn global_mut() -> RwLockWriteGuard<'static, UnitRegistry>
+{
+    GLOBAL_REGISTRY.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Register a unit in the global registry, see [`UnitRegistry::register`].
+pub fn register_unit(name: &str, aliases: &[&str], unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+{
+    global_mut().register(name, aliases, unit)
+}
+
+/// Register a unit defined by an `expression` in the global registry, see [`UnitRegistry::register_expression`].
+pub fn register_unit_expression(name: &str, aliases: &[&str], expression: &str) -> Result<(), RuntimeUnitError>
+{
+    // Parse before locking for writing, as parsing reads the global registry.
+    let unit = global().parse_unit(expression)?;
+    register_unit(name, aliases, unit)
+}
@@ -0,0 +1,142 @@
+//! Conversion of values stored in slices, without copying them into a `VecQuantity` or a typed `XxxVec`.
+//!
+//! Units may be given as `Units`, typed units (e.g. `LengthUnit::foot`) or `UnitDefinition`, and are validated before any
+//! value is modified, as are the converted values, which must be representable by the numeric type of the slice (see
+//! `Numeric::try_from_f64`). Besides contiguous slices, values may be laid out with a stride (e.g. a column of a row-major table of
+//! several quantities) or as columns of a column-major buffer. The loops over contiguous values are written to be
+//! auto-vectorized.
+use crate::errors::RuntimeUnitError;
+use crate::traits::Numeric;
+use crate::units_base::UnitDefinition;
+
+/// Compute the scale and shift converting `values` from `from` to `to`, failing on incompatible units or converted values
+/// that cannot be represented (see [`check_representable`]).
+fn conversion<'a, T: Numeric + 'a>(values: impl IntoIterator<Item = &'a T>, from: impl Into<UnitDefinition>, to: impl Into<UnitDefinition>) -> Result<(f64, f64), RuntimeUnitError>
+{
+    let (from, to) = (from.into(), to.into());
+    from.try_convert(to)?;
+    check_representable(values, from, to)?;
+    Ok(from.affine_conversion_unchecked(to))
+}
+
+/// Check that `input` and `output` have the same length, failing with `RuntimeUnitError::LengthMismatch` otherwise.
+pub(crate) fn check_lengths(input: usize, output: usize) -> Result<(), RuntimeUnitError>
+{
+    if input != output
+    {
+        return Err(RuntimeUnitError::LengthMismatch { expected: output, found: input });
+    }
+    Ok(())
+}
+
+/// Check that the numeric type of `values` can represent them converted from `from` to `to` (e.g. integers must remain
+/// whole numbers within their range), see `Numeric::try_scale`.
+pub(crate) fn check_representable<'a, T: Numeric + 'a>(values: impl IntoIterator<Item = &'a T>, from: UnitDefinition, to: UnitDefinition) -> Result<(), RuntimeUnitError>
+{
+    let (scale, shift) = from.affine_conversion_unchecked(to);
+    match values.into_iter().find(|value| value.try_scale(scale, shift).is_none())
+    {
+        Some(value) => Err(RuntimeUnitError::ValueOutOfRange { value: value.to_f64() * scale + shift }),
+        None => Ok(())
+    }
+}
+
+/// Apply `value * scale + shift` to all `values`.
+#[inline]
+pub(crate) fn scale_in_place<T: Numeric>(values: &mut [T], scale: f64, shift: f64)
+{
+    for value in values.iter_mut()
+    {
+        *value = value.scale(scale, shift);
+    }
+}
+
+/// Convert `values` from `from` to `to` in place, rounding and saturating values the numeric type cannot represent (see
+/// `Numeric::scale`).
+#[inline]
+pub(crate) fn convert_in_place_unchecked<T: Numeric>(values: &mut [T], from: UnitDefinition, to: UnitDefinition)
+{
+    let (scale, shift) = from.affine_conversion_unchecked(to);
+    scale_in_place(values, scale, shift);
+}
+
+/// Write `value * scale + shift` for all `input` values to `output` (of the same length).
+#[inline]
+pub(crate) fn scale_into<T: Numeric>(input: &[T], output: &mut [T], scale: f64, shift: f64)
+{
+    for (output, input) in output.iter_mut().zip(input)
+    {
+        *output = input.scale(scale, shift);
+    }
+}
+
+///
+/// Convert `values` from the unit `from` to the unit `to` in place (e.g. feet to meters).
+///
+pub fn convert_slice_in_place<T: Numeric>(values: &mut [T], from: impl Into<UnitDefinition>, to: impl Into<UnitDefinition>) -> Result<(), RuntimeUnitError>
+{
+    let (scale, shift) = conversion(values.iter(), from, to)?;
+    scale_in_place(values, scale, shift);
+    Ok(())
+}
+
+///
+/// Convert `input` from the unit `from` to the unit `to`, writing the result to `output` (of the same length as `input`).
+///
+pub fn convert_slice_into<T: Numeric>(input: &[T], output: &mut [T], from: impl Into<UnitDefinition>, to: impl Into<UnitDefinition>) -> Result<(), RuntimeUnitError>
+{
+    check_lengths(input.len(), output.len())?;
+    let (scale, shift) = conversion(input, from, to)?;
+    scale_into(input, output, scale, shift);
+    Ok(())
+}
+
+///
+/// Convert every `stride`-th value of `values`, starting at `offset`, in place (e.g. a column of a row-major table with
+/// `stride` columns, or a row of a column-major matrix with `stride` rows).
+///
+pub fn convert_strided_in_place<T: Numeric>(values: &mut [T], offset: usize, stride: usize, from: impl Into<UnitDefinition>, to: impl Into<UnitDefinition>) -> Result<(), RuntimeUnitError>
+{
+    if stride == 0
+    {
+        return Err(RuntimeUnitError::InvalidStride);
+    }
+    let (scale, shift) = conversion(values.iter().skip(offset).step_by(stride), from, to)?;
+    for value in values.iter_mut().skip(offset).step_by(stride)
+    {
+        *value = value.scale(scale, shift);
+    }
+    Ok(())
+}
+
+///
+/// Convert every `stride`-th value of `input`, starting at `offset`, writing the result to `output` (with the same
+/// layout as `input`). Other values of `output` are unchanged.
+///
+pub fn convert_strided_into<T: Numeric>(input: &[T], output: &mut [T], offset: usize, stride: usize, from: impl Into<UnitDefinition>, to: impl Into<UnitDefinition>) -> Result<(), RuntimeUnitError>
+{
+    check_lengths(input.len(), output.len())?;
+    if stride == 0
+    {
+        return Err(RuntimeUnitError::InvalidStride);
+    }
+    let (scale, shift) = conversion(input.iter().skip(offset).step_by(stride), from, to)?;
+    for (output, input) in output.iter_mut().zip(input).skip(offset).step_by(stride)
+    {
+        *output = input.scale(scale, shift);
+    }
+    Ok(())
+}
+
+///
+/// Convert the `column` of a column-major matrix with `rows` rows in place. The values of a column are contiguous, so
+/// the conversion is as fast as [`convert_slice_in_place`]. A matrix without rows has no columns.
+///
+pub fn convert_column_in_place<T: Numeric>(values: &mut [T], rows: usize, column: usize, from: impl Into<UnitDefinition>, to: impl Into<UnitDefinition>) -> Result<(), RuntimeUnitError>
+{
+    let columns = values.len().checked_div(rows).unwrap_or(0);
+    let values = column.checked_mul(rows).filter(|_| rows > 0)
+        .and_then(|start| values.get_mut(start..start.checked_add(rows)?))
+        .ok_or(RuntimeUnitError::IndexOutOfBounds { index: column, len: columns })?;
+    convert_slice_in_place(values, from, to)
+}
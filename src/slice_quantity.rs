@@ -0,0 +1,154 @@
+use crate::{errors::RuntimeUnitError, quantity::Quantity, traits::Numeric, units_base::UnitDefinition, vector_quantity::VecQuantity};
+use std::ops::{Deref, DerefMut};
+
+#[derive(Copy, Clone, Debug)]
+#[doc ="Unit attached to a borrowed slice of data (e.g. a memory-mapped file or a foreign buffer)"]
+pub struct QuantitySlice<'a, T = f64>
+{
+    pub unit: UnitDefinition,
+    pub values: &'a [T]
+}
+
+#[derive(Debug)]
+#[doc ="Unit attached to a mutably borrowed slice of data, which can be converted in place"]
+pub struct QuantitySliceMut<'a, T = f64>
+{
+    pub unit: UnitDefinition,
+    pub values: &'a mut [T]
+}
+
+impl<'a, T: Numeric> QuantitySlice<'a, T>
+{
+    /// Attach a `unit` to borrowed `values`
+    pub fn new(values: &'a [T], unit: UnitDefinition) -> Self
+    {
+        Self { unit, values }
+    }
+
+    /// Return the value at `index` as a `Quantity`
+    pub fn get(&self, index: usize) -> Option<Quantity<T>>
+    {
+        self.values.get(index).map(|&value| Quantity::new(value, self.unit))
+    }
+
+    /// Iterate over the values as `Quantity`
+    pub fn iter(&self) -> impl Iterator<Item = Quantity<T>> + 'a
+    {
+        let unit = self.unit;
+        self.values.iter().map(move |&value| Quantity::new(value, unit))
+    }
+
+    /// Convert the values to `unit`, writing them to `output` (of the same length)
+    pub fn convert_into(&self, output: &mut [T], unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+    {
+        crate::slice::convert_slice_into(self.values, output, self.unit, unit)
+    }
+
+    /// Convert the values to `unit` into a new `VecQuantity`
+    pub fn convert_to_vec(&self, unit: UnitDefinition) -> Result<VecQuantity<T>, RuntimeUnitError>
+    {
+        let mut values = self.values.to_vec();
+        crate::slice::convert_slice_in_place(&mut values, self.unit, unit)?;
+        Ok(VecQuantity { unit, values })
+    }
+
+    /// Copy the values into a new `VecQuantity`
+    pub fn to_vec(&self) -> VecQuantity<T>
+    {
+        VecQuantity { unit: self.unit, values: self.values.to_vec() }
+    }
+}
+
+impl<'a, T: Numeric> QuantitySliceMut<'a, T>
+{
+    /// Attach a `unit` to mutably borrowed `values`
+    pub fn new(values: &'a mut [T], unit: UnitDefinition) -> Self
+    {
+        Self { unit, values }
+    }
+
+    /// Borrow as an immutable `QuantitySlice`
+    pub fn as_quantity_slice(&self) -> QuantitySlice<'_, T>
+    {
+        QuantitySlice { unit: self.unit, values: self.values }
+    }
+
+    /// Return the value at `index` as a `Quantity`
+    pub fn get(&self, index: usize) -> Option<Quantity<T>>
+    {
+        self.as_quantity_slice().get(index)
+    }
+
+    /// Set the value at `index` from a `quantity` (converted to the unit of this slice). Fails with
+    /// `RuntimeUnitError::IndexOutOfBounds` if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, quantity: Quantity<T>) -> Result<(), RuntimeUnitError>
+    {
+        use crate::traits::ArbitraryQuantity;
+        let len = self.values.len();
+        let value = self.values.get_mut(index).ok_or(RuntimeUnitError::IndexOutOfBounds { index, len })?;
+        *value = quantity.try_convert(self.unit)?.value;
+        Ok(())
+    }
+
+    /// Iterate over the values as `Quantity`
+    pub fn iter(&self) -> impl Iterator<Item = Quantity<T>> + '_
+    {
+        let unit = self.unit;
+        self.values.iter().map(move |&value| Quantity::new(value, unit))
+    }
+
+    /// Convert the values to `unit`, writing them to `output` (of the same length)
+    pub fn convert_into(&self, output: &mut [T], unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+    {
+        self.as_quantity_slice().convert_into(output, unit)
+    }
+
+    /// Convert the values to `unit` in place, updating the unit of this slice
+    pub fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+    {
+        crate::slice::convert_slice_in_place(self.values, self.unit, unit)?;
+        self.unit = unit;
+        Ok(())
+    }
+}
+
+impl<T: Numeric> Deref for QuantitySlice<'_, T>
+{
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.values
+    }
+}
+
+impl<T: Numeric> Deref for QuantitySliceMut<'_, T>
+{
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.values
+    }
+}
+
+impl<T: Numeric> DerefMut for QuantitySliceMut<'_, T>
+{
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.values
+    }
+}
+
+impl<T: Numeric> VecQuantity<T>
+{
+    /// Borrow the values as a `QuantitySlice`
+    pub fn as_quantity_slice(&self) -> QuantitySlice<'_, T>
+    {
+        QuantitySlice { unit: self.unit, values: &self.values }
+    }
+}
+
+impl<const N: usize, T: Numeric> crate::array_quantity::ArrayQuantity<N, T>
+{
+    /// Borrow the values as a `QuantitySlice`
+    pub fn as_quantity_slice(&self) -> QuantitySlice<'_, T>
+    {
+        QuantitySlice { unit: self.unit, values: &self.values }
+    }
+}
@@ -0,0 +1,284 @@
+//! Tables of named columns, where every column has its own unit (e.g. time in ms, pressure in psi and temperature in K).
+//!
+//! Columns are stored as [`VecQuantity`] and may be added from a `VecQuantity` or a typed `QuantitiesVec`, whose named
+//! unit is kept with [`QuantityTable::push_quantities`] (e.g. `psi` rather than `lbf/in²`). All columns have the same
+//! number of rows. Rows are read as `Quantity` (or `Quantities`), and new columns can be derived from
+//! existing ones, with units computed from the units of the operands (e.g. power = force × velocity). Deserialized tables
+//! are checked in the same way: names must be unique and match the columns, which must have the same number of rows.
+use crate::errors::RuntimeUnitError;
+use crate::quantity::Quantity;
+use crate::traits::{ArbitraryQuantity, CheckedAdditive, Numeric};
+use crate::units_base::UnitDefinition;
+use crate::vector_quantity::VecQuantity;
+use crate::{QuantitiesVec, Units};
+
+#[derive(Clone)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature="serde", serde(try_from = "SerializedTable<T>", bound(deserialize = "T: Numeric + serde::Deserialize<'de>")))]
+#[doc ="Table of named columns of the same length, each with its own unit"]
+pub struct QuantityTable<T = f64>
+{
+    names: Vec<String>,
+    columns: Vec<VecQuantity<T>>,
+    #[cfg_attr(feature="serde", serde(skip))]
+    units: Vec<Option<ColumnUnit>>
+}
+
+/// Unit a column was added with, kept until the column is converted.
+#[derive(Clone, Debug, PartialEq)]
+enum ColumnUnit
+{
+    /// Named unit of a `QuantitiesVec` (e.g. `psi`)
+    Named(Units),
+    /// Unit expression of a unit without a name (e.g. `L·min⁻¹`)
+    Expression(String),
+}
+
+/// Fields of a deserialized `QuantityTable`, validated as if the columns were pushed one by one.
+#[cfg(feature="serde")]
+#[derive(serde::Deserialize)]
+struct SerializedTable<T>
+{
+    names: Vec<String>,
+    columns: Vec<VecQuantity<T>>
+}
+
+#[cfg(feature="serde")]
+impl<T: Numeric> TryFrom<SerializedTable<T>> for QuantityTable<T>
+{
+    type Error = RuntimeUnitError;
+    fn try_from(table: SerializedTable<T>) -> Result<Self, Self::Error>
+    {
+        crate::slice::check_lengths(table.names.len(), table.columns.len())?;
+        table.names.into_iter().zip(table.columns).try_fold(Self::new(), |table, (name, column)| table.with_column(name, column))
+    }
+}
+
+impl<T: Numeric> Default for QuantityTable<T>
+{
+    fn default() -> Self
+    {
+        Self { names: Vec::new(), columns: Vec::new(), units: Vec::new() }
+    }
+}
+
+impl<T: Numeric> QuantityTable<T>
+{
+    /// Create an empty table
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// Add a column `name`, failing if the name is already used or the number of rows differs from other columns
+    pub fn with_column(mut self, name: impl Into<String>, column: impl Into<VecQuantity<T>>) -> Result<Self, RuntimeUnitError>
+    {
+        self.push_column(name, column)?;
+        Ok(self)
+    }
+
+    /// Add a column `name`, failing if the name is already used or the number of rows differs from other columns
+    pub fn push_column(&mut self, name: impl Into<String>, column: impl Into<VecQuantity<T>>) -> Result<(), RuntimeUnitError>
+    {
+        let (name, column) = (name.into(), column.into());
+        if self.position(&name).is_some()
+        {
+            return Err(RuntimeUnitError::DuplicateColumn { name });
+        }
+        if !self.columns.is_empty() && column.values.len() != self.row_count()
+        {
+            return Err(RuntimeUnitError::RowCountMismatch { name, expected: self.row_count(), found: column.values.len() });
+        }
+        self.names.push(name);
+        self.columns.push(column);
+        self.units.push(None);
+        Ok(())
+    }
+
+    /// Remove the column `name`, returning it if it exists
+    pub fn remove_column(&mut self, name: &str) -> Option<VecQuantity<T>>
+    {
+        let index = self.position(name)?;
+        self.names.remove(index);
+        self.units.remove(index);
+        Some(self.columns.remove(index))
+    }
+
+    /// Number of rows (0 for a table without columns)
+    pub fn row_count(&self) -> usize
+    {
+        self.columns.first().map_or(0, |column| column.values.len())
+    }
+
+    /// Number of columns
+    pub fn column_count(&self) -> usize
+    {
+        self.columns.len()
+    }
+
+    /// Names of the columns, in order
+    pub fn names(&self) -> impl Iterator<Item = &str>
+    {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Retrieve the column `name`
+    pub fn column(&self, name: &str) -> Option<&VecQuantity<T>>
+    {
+        self.position(name).map(|index| &self.columns[index])
+    }
+
+    /// Retrieve the unit of the column `name`
+    pub fn unit(&self, name: &str) -> Option<UnitDefinition>
+    {
+        self.column(name).map(|column| column.unit)
+    }
+
+    /// Retrieve the named unit of the column `name`, if it was added with [`QuantityTable::push_quantities`] and not converted since
+    pub fn named_unit(&self, name: &str) -> Option<Units>
+    {
+        match self.position(name).and_then(|index| self.units[index].as_ref())
+        {
+            Some(ColumnUnit::Named(unit)) => Some(*unit),
+            _ => None
+        }
+    }
+
+    /// Retrieve the unit expression of the column `name` (e.g. `L·min⁻¹`), if it was read from a CSV header naming a unit
+    /// without a name in `Units` and not converted since
+    pub fn unit_expression(&self, name: &str) -> Option<&str>
+    {
+        match self.position(name).and_then(|index| self.units[index].as_ref())
+        {
+            Some(ColumnUnit::Expression(expression)) => Some(expression),
+            _ => None
+        }
+    }
+
+    /// Retrieve the row at `index`, with one quantity per column
+    pub fn row(&self, index: usize) -> Option<Vec<Quantity<T>>>
+    {
+        (index < self.row_count()).then(|| self.columns.iter().map(|column| Quantity::new(column.values[index], column.unit)).collect())
+    }
+
+    /// Iterate over the rows, with one quantity per column
+    pub fn rows(&self) -> impl Iterator<Item = Vec<Quantity<T>>> + '_
+    {
+        (0..self.row_count()).map(|index| self.columns.iter().map(|column| Quantity::new(column.values[index], column.unit)).collect())
+    }
+
+    /// Convert the column `name` to `unit`
+    pub fn try_convert_column(&mut self, name: &str, unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+    {
+        let index = self.index(name)?;
+        self.columns[index].try_convert_mut(unit)?;
+        self.units[index] = None;
+        Ok(())
+    }
+
+    ///
+    /// Convert every column to the first unit of `system` it can be converted to (e.g. `[meter, second, pascal]`).
+    /// Columns without a matching unit are unchanged.
+    ///
+    pub fn convert_to_system(&mut self, system: &[UnitDefinition])
+    {
+        for (column, named) in self.columns.iter_mut().zip(self.units.iter_mut())
+        {
+            if let Some(&unit) = system.iter().find(|unit| column.unit.is_convertible(**unit))
+            {
+                column.convert_mut(unit);
+                *named = None;
+            }
+        }
+    }
+
+    /// Convert every column to the coherent SI unit of its dimensions (e.g. psi to Pa, degrees Celsius to kelvin)
+    pub fn convert_to_base(&mut self)
+    {
+        for column in self.columns.iter_mut()
+        {
+            let unit = UnitDefinition { multiplier: 1.0, offset: column.unit.offset.map(|_| 0.0), ..column.unit };
+            column.convert_mut(unit);
+        }
+        self.units.fill(None);
+    }
+
+    /// Add the column `name` as the product of the columns `lhs` and `rhs` (e.g. power = force × velocity)
+    pub fn derive_product(&mut self, name: impl Into<String>, lhs: &str, rhs: &str) -> Result<(), RuntimeUnitError>
+    {
+        let column = self.get(lhs)?.checked_mul(self.get(rhs)?)?;
+        self.push_column(name, column)
+    }
+
+    /// Add the column `name` as the quotient of the columns `lhs` and `rhs` (e.g. velocity = distance / time)
+    pub fn derive_quotient(&mut self, name: impl Into<String>, lhs: &str, rhs: &str) -> Result<(), RuntimeUnitError>
+    {
+        let column = self.get(lhs)?.checked_div(self.get(rhs)?)?;
+        self.push_column(name, column)
+    }
+
+    /// Add the column `name` as the sum of the columns `lhs` and `rhs`, in the unit of `lhs`
+    pub fn derive_sum(&mut self, name: impl Into<String>, lhs: &str, rhs: &str) -> Result<(), RuntimeUnitError>
+    {
+        let column = self.get(lhs)?.clone().checked_add(self.get(rhs)?.clone())?;
+        self.push_column(name, column)
+    }
+
+    /// Add the column `name` as the difference of the columns `lhs` and `rhs`, in the unit of `lhs` (the difference of two
+    /// absolute temperatures is a temperature interval)
+    pub fn derive_difference(&mut self, name: impl Into<String>, lhs: &str, rhs: &str) -> Result<(), RuntimeUnitError>
+    {
+        let column = self.get(lhs)?.clone().checked_sub(self.get(rhs)?.clone())?;
+        self.push_column(name, column)
+    }
+
+    /// Add a column `name`, keeping the unit `expression` it was read with, failing like [`QuantityTable::push_column`]
+    pub(crate) fn push_expression_column(&mut self, name: impl Into<String>, column: VecQuantity<T>, expression: String) -> Result<(), RuntimeUnitError>
+    {
+        self.push_column(name, column)?;
+        *self.units.last_mut().expect("A column was added") = Some(ColumnUnit::Expression(expression));
+        Ok(())
+    }
+
+    fn position(&self, name: &str) -> Option<usize>
+    {
+        self.names.iter().position(|column| column == name)
+    }
+
+    fn get(&self, name: &str) -> Result<&VecQuantity<T>, RuntimeUnitError>
+    {
+        self.column(name).ok_or_else(|| RuntimeUnitError::UnknownColumn { name: name.to_owned() })
+    }
+
+    fn index(&self, name: &str) -> Result<usize, RuntimeUnitError>
+    {
+        self.position(name).ok_or_else(|| RuntimeUnitError::UnknownColumn { name: name.to_owned() })
+    }
+}
+
+impl QuantityTable<f64>
+{
+    /// Add a column `name`, keeping its named unit (see [`QuantityTable::named_unit`]), failing like [`QuantityTable::push_column`]
+    pub fn push_quantities(&mut self, name: impl Into<String>, column: QuantitiesVec) -> Result<(), RuntimeUnitError>
+    {
+        let unit = column.unit();
+        self.push_column(name, column)?;
+        *self.units.last_mut().expect("A column was added") = Some(ColumnUnit::Named(unit));
+        Ok(())
+    }
+
+    /// Add a column `name`, keeping its named unit, see [`QuantityTable::push_quantities`]
+    pub fn with_quantities(mut self, name: impl Into<String>, column: QuantitiesVec) -> Result<Self, RuntimeUnitError>
+    {
+        self.push_quantities(name, column)?;
+        Ok(self)
+    }
+
+    /// Retrieve the row at `index` as `Quantities`, with one quantity per column (see [`Quantity::try_into_quantities`])
+    pub fn try_row_quantities(&self, index: usize) -> Result<Vec<crate::Quantities>, RuntimeUnitError>
+    {
+        self.row(index)
+            .ok_or(RuntimeUnitError::IndexOutOfBounds { index, len: self.row_count() })?
+            .iter().map(Quantity::try_into_quantities).collect()
+    }
+}
@@ -346,4 +346,1409 @@ mod test
             assert_eq!(val_m*100.0, val_cm);
         }        
     }
-}
\ No newline at end of file
+
+    #[test]
+    #[cfg(any(feature="All", feature="ThermodynamicTemperature"))]
+    fn test_thermodynamic_temperature_conversion()
+    {
+        use crate::traits::FixedQuantity;
+        use crate::units::ThermodynamicTemperatureUnit;
+        use crate::ThermodynamicTemperature;
+        let freezing = ThermodynamicTemperature::degree_celsius(0.0);
+        assert!((freezing.to_kelvin().value() - 273.15).abs() < 1e-9);
+        assert!((freezing.to_degree_fahrenheit().value() - 32.0).abs() < 1e-9);
+        assert!((freezing.to_degree_rankine().value() - 491.67).abs() < 1e-9);
+        assert!((ThermodynamicTemperature::degree_fahrenheit(212.0).convert(ThermodynamicTemperatureUnit::degree_celsius).value() - 100.0).abs() < 1e-9);
+        assert_eq!(ThermodynamicTemperature::degree_celsius(-273.15), ThermodynamicTemperature::kelvin(0.0));
+        assert!(ThermodynamicTemperature::degree_celsius(1.0) > ThermodynamicTemperature::kelvin(274.0));
+        assert_eq!(ThermodynamicTemperatureUnit::try_from(crate::units_base::UnitDefinition::from(ThermodynamicTemperatureUnit::degree_celsius)).unwrap(), ThermodynamicTemperatureUnit::degree_celsius);
+    }
+
+    #[test]
+    #[cfg(any(feature="All", feature="ThermodynamicTemperature"))]
+    fn test_thermodynamic_temperature_ops()
+    {
+        use crate::{TemperatureInterval, ThermodynamicTemperature};
+        let difference = ThermodynamicTemperature::degree_celsius(25.0) - ThermodynamicTemperature::kelvin(273.15);
+        assert!((difference.value() - 25.0).abs() < 1e-9);
+        assert_eq!(difference.unit, crate::units::TemperatureIntervalUnit::degree_celsius);
+
+        let warmer = ThermodynamicTemperature::kelvin(273.15) + TemperatureInterval::degree_fahrenheit(9.0);
+        assert!((warmer.to_degree_celsius().value() - 5.0).abs() < 1e-9);
+        let mut cooler = ThermodynamicTemperature::degree_fahrenheit(50.0);
+        cooler -= TemperatureInterval::kelvin(5.0);
+        assert!((cooler.value() - 41.0).abs() < 1e-9);
+        assert_eq!(TemperatureInterval::kelvin(1.0) + ThermodynamicTemperature::kelvin(1.0), ThermodynamicTemperature::kelvin(2.0));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", feature="ThermodynamicTemperature"))]
+    fn test_absolute_quantity_ops()
+    {
+        use crate::traits::ArbitraryQuantity;
+        use crate::units::{TemperatureIntervalUnit, ThermodynamicTemperatureUnit};
+        use crate::{quantity::Quantity, TemperatureInterval, ThermodynamicTemperature};
+        let t1 = Quantity::from(ThermodynamicTemperature::degree_celsius(20.0));
+        let t2 = Quantity::from(ThermodynamicTemperature::degree_fahrenheit(50.0));
+        let difference = t1 - t2;
+        assert!(!difference.unit().is_absolute());
+        assert!((difference.value() - 10.0).abs() < 1e-9);
+
+        let sum = t2 + Quantity::from(TemperatureInterval::kelvin(10.0));
+        assert!(sum.unit().is_absolute());
+        assert!((sum.value() - 68.0).abs() < 1e-9);
+
+        // Temperatures cannot be converted to temperature intervals
+        assert!(matches!(t1.try_convert(TemperatureIntervalUnit::kelvin.into()), Err(crate::errors::RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!((t1.try_convert(ThermodynamicTemperatureUnit::kelvin.into()).unwrap().value() - 293.15).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "Addition failed due to absolute units")]
+    #[cfg(any(feature="All", feature="ThermodynamicTemperature"))]
+    fn test_invalid_absolute_addition()
+    {
+        use crate::{quantity::Quantity, ThermodynamicTemperature};
+        let _result = Quantity::from(ThermodynamicTemperature::kelvin(1.0)) + Quantity::from(ThermodynamicTemperature::kelvin(1.0));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="ThermodynamicTemperature", feature="HeatCapacity", feature="Energy")))]
+    fn test_absolute_quantity_product()
+    {
+        use crate::traits::ArbitraryQuantity;
+        use crate::units::{EnergyUnit, ThermodynamicTemperatureUnit};
+        use crate::{quantity::Quantity, HeatCapacity, ThermodynamicTemperature};
+        let celsius = Quantity::from(ThermodynamicTemperature::degree_celsius(20.0));
+        assert_eq!(format!("{celsius}"), "20 °C");
+        // Products are measured from absolute zero
+        let energy = celsius * Quantity::from(HeatCapacity::joule_per_kelvin(1.0));
+        assert!(!energy.unit.is_absolute());
+        assert!((energy.try_convert(EnergyUnit::joule.into()).unwrap().value - 293.15).abs() < 1e-9);
+        let typed = ThermodynamicTemperature::degree_fahrenheit(32.0) * HeatCapacity::joule_per_kelvin(2.0);
+        assert!((typed.try_convert(EnergyUnit::joule.into()).unwrap().value - 546.3).abs() < 1e-9);
+        assert!((celsius.powi(2).value - 293.15 * 293.15).abs() < 1e-6);
+        assert!(((celsius / Quantity::from(ThermodynamicTemperature::kelvin(293.15))).value - 1.0).abs() < 1e-12);
+        assert_eq!(format!("{}", crate::units_base::UnitDefinition::from(ThermodynamicTemperatureUnit::degree_fahrenheit)), "°F");
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Mass", feature="Time", feature="Energy", feature="Power", feature="TemperatureInterval", feature="ThermalConductivity")))]
+    fn test_parse_unit_expression()
+    {
+        use crate::parser::parse_unit;
+        use crate::units::{EnergyUnit, ThermalConductivityUnit};
+        use crate::units_base::UnitDefinition;
+        let joule: UnitDefinition = EnergyUnit::joule.into();
+        assert!(parse_unit("kg*m^2/s^2").unwrap().approx_eq(joule, 1e-12));
+        assert!(parse_unit("kg·m²·s⁻²").unwrap().approx_eq(joule, 1e-12));
+        assert!(parse_unit("kg m^2 s^-2").unwrap().approx_eq(joule, 1e-12));
+        assert!(parse_unit("g*(cm/s)^2").unwrap().approx_eq(EnergyUnit::erg.into(), 1e-12));
+        assert!(parse_unit("W/(m·K)").unwrap().approx_eq(ThermalConductivityUnit::watt_per_meter_kelvin.into(), 1e-12));
+        assert!(parse_unit("W / m / K").unwrap().approx_eq(ThermalConductivityUnit::watt_per_meter_kelvin.into(), 1e-12));
+        assert!(parse_unit("(m^3)^(1/3)").unwrap().approx_eq(crate::units::LengthUnit::meter.into(), 1e-12));
+        // Rational powers need parentheses
+        assert!(parse_unit("m^1/2").unwrap().approx_eq(parse_unit("0.5 m").unwrap(), 1e-12));
+        assert!(!parse_unit("m^1/2").unwrap().approx_eq(parse_unit("m^(1/2)").unwrap(), 1e-12));
+        // Digits following a unit name are a power
+        assert!(parse_unit("kg m2 s-2").unwrap().approx_eq(joule, 1e-12));
+        assert!(parse_unit("m2").unwrap().approx_eq(parse_unit("m²").unwrap(), 1e-12));
+        assert!(parse_unit("zz2").is_err());
+        assert!(parse_unit("1/s").unwrap().approx_eq(crate::units::FrequencyUnit::hertz.into(), 1e-12));
+        assert!(parse_unit("kg*m/").is_err());
+        assert!(parse_unit("kg*(m").is_err());
+        assert!(parse_unit("furlongs/fortnight").is_err());
+        assert!(matches!(parse_unit(""), Err(crate::errors::RuntimeUnitError::UnknownUnit { .. })));
+        assert!(matches!(parse_unit("  "), Err(crate::errors::RuntimeUnitError::UnknownUnit { .. })));
+        // Numbers in scientific notation are factors, not elementary charges
+        assert!(parse_unit("1e3 m").unwrap().approx_eq(crate::units::LengthUnit::kilometer.into(), 1e-12));
+        assert!(parse_unit("1E-3 m").unwrap().approx_eq(crate::units::LengthUnit::millimeter.into(), 1e-12));
+        assert!(parse_unit("2.5e-3 s").unwrap().approx_eq(UnitDefinition { multiplier: 2.5e-3, ..crate::units::TimeUnit::second.into() }, 1e-12));
+        assert!(parse_unit("2 e").unwrap().approx_eq(parse_unit("2*e").unwrap(), 1e-12));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Time", feature="Acceleration")))]
+    fn test_parse_quantity()
+    {
+        use crate::{quantity::Quantity, Acceleration};
+        let quantity: Quantity = "9.81 m/s^2".parse().unwrap();
+        assert_eq!(quantity, Acceleration::meter_per_second_squared(9.81));
+        assert!("9.81".parse::<Quantity>().is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Time", feature="Volume", feature="Velocity")))]
+    fn test_unit_registry()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::registry::UnitRegistry;
+        use crate::units::{VelocityUnit, VolumeUnit};
+        use crate::units_base::UnitDefinition;
+        let mut registry = UnitRegistry::new();
+        registry.register_expression("hogshead", &["hhd", "hogsheads"], "63 gal").unwrap();
+        registry.register_expression("furlong", &[], "220 yd").unwrap();
+        let gallon: UnitDefinition = VolumeUnit::gallon.into();
+        let hogshead = UnitDefinition { multiplier: 63.0 * gallon.multiplier, ..gallon };
+        assert!(registry.parse_unit("hhd").unwrap().approx_eq(hogshead, 1e-12));
+        assert!(registry.parse_unit("hogsheads").unwrap().approx_eq(hogshead, 1e-12));
+        assert!((registry.parse_unit("furlong").unwrap().multiplier - 201.168).abs() < 1e-12);
+        assert!(registry.parse_unit("furlong/min").unwrap().is_convertible(VelocityUnit::meter_per_second.into()));
+        assert!(!registry.parse_unit("furlong").unwrap().is_convertible(VelocityUnit::meter_per_second.into()));
+        assert_eq!(registry.find(hogshead).unwrap().name, "hogshead");
+        assert!(matches!(registry.register("gal", &[], gallon), Err(RuntimeUnitError::DuplicateUnit { name }) if name == "gal"));
+        assert!(matches!(registry.register("wine_hogshead", &["hhd"], gallon), Err(RuntimeUnitError::DuplicateUnit { name }) if name == "hhd"));
+        for name in ["", " ", "hhd2", "hhd/2", "hhd^2", "hhd²", "(hhd)", "wine hogshead", "wine*hogshead", "2hhd"]
+        {
+            assert!(matches!(registry.register(name, &[], gallon), Err(RuntimeUnitError::InvalidUnitName { .. })), "{name}");
+        }
+        // Units of an explicit registry are not visible globally
+        assert!(crate::parser::parse_unit("furlong").is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Volume")))]
+    fn test_global_unit_registry()
+    {
+        use crate::{quantity::Quantity, traits::ArbitraryQuantity, Volume};
+        crate::registry::register_unit_expression("test_hogshead", &["test_hhd"], "63 gal").unwrap();
+        let volume = Quantity::from(Volume::gallon(126.0)).try_convert_str("test_hhd").unwrap();
+        assert!((volume.value - 2.0).abs() < 1e-12);
+        assert_eq!(volume.unit.to_string(), "test_hogshead");
+        assert!(crate::registry::register_unit_expression("test_hhd", &[], "1 gal").is_err());
+        // Aliases of built-in units do not change how they are displayed
+        crate::registry::register_unit_expression("test_mtr", &[], "m").unwrap();
+        assert_eq!(crate::units_base::UnitDefinition::from(crate::units::LengthUnit::meter).to_string(), "m");
+        assert_eq!(volume.unit.to_string(), "test_hogshead");
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Time", feature="Velocity", feature="Force", feature="Energy", feature="TemperatureInterval", feature="ThermodynamicTemperature")))]
+    fn test_reverse_unit_lookup()
+    {
+        use crate::{quantity::Quantity, Energy, Force, Length, Quantities, ThermodynamicTemperature, Time, UnitTypes, Units};
+        use crate::units::{EnergyUnit, ThermodynamicTemperatureUnit, VelocityUnit};
+        use crate::units_base::UnitDefinition;
+        let speed = Quantity::from(Length::kilometer(36.0)) / Quantity::from(Time::hour(1.0));
+        assert_eq!(Units::find(speed.unit, 1e-9), Some(Units::Velocity(VelocityUnit::kilometer_per_hour)));
+        match speed.try_into_quantities().unwrap()
+        {
+            Quantities::Velocity(velocity) => assert!((velocity.value() - 36.0).abs() < 1e-9),
+            other => panic!("Unexpected quantity {other:?}"),
+        }
+        // No named unit of length is 3 meters long, the base unit is used instead
+        let length = Quantity { value: 2.0, unit: UnitDefinition { multiplier: 3.0, ..crate::units::LengthUnit::meter.into() } };
+        match length.try_into_quantities().unwrap()
+        {
+            Quantities::Length(length) => assert!((length.to_meter().value() - 6.0).abs() < 1e-12),
+            other => panic!("Unexpected quantity {other:?}"),
+        }
+        // Energy and torque share dimensions, the first quantity of the system is used
+        let work = Quantity::from(Force::newton(2.0)) * Quantity::from(Length::meter(3.0));
+        assert!(UnitTypes::matching(work.unit).len() > 1);
+        assert_eq!(Units::try_from(work.unit).unwrap(), Units::Energy(EnergyUnit::joule));
+        assert!(matches!(work.try_into_quantities().unwrap(), Quantities::Energy(x) if x == Energy::joule(6.0)));
+        // Absolute units only match absolute units
+        let temperature: UnitDefinition = ThermodynamicTemperatureUnit::degree_celsius.into();
+        assert_eq!(Units::find(temperature, 1e-9), Some(Units::ThermodynamicTemperature(ThermodynamicTemperatureUnit::degree_celsius)));
+        assert!(matches!(Quantity::from(ThermodynamicTemperature::kelvin(1.0)).try_into_quantities().unwrap(), Quantities::ThermodynamicTemperature(_)));
+        assert!(matches!(Quantity::new(1.0, temperature.interval()).try_into_quantities().unwrap(), Quantities::TemperatureInterval(_)));
+        assert!(Units::try_from(UnitDefinition { multiplier: 3.0, ..crate::units::LengthUnit::meter.into() }).is_err());
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Angle", feature="Ratio", feature="Information", feature="Time", feature="AngularVelocity", feature="Frequency", feature="Radioactivity", feature="Force", feature="Length", feature="Energy", feature="Torque")))]
+    fn test_quantity_kinds()
+    {
+        use crate::{quantity::Quantity, traits::ArbitraryQuantity, Angle, Energy, Force, Length, Ratio, Time, Torque};
+        use crate::units::{AngularVelocityUnit, EnergyUnit, FrequencyUnit, InformationUnit, TorqueUnit};
+        use crate::units_base::{QuantityKind, UnitDefinition};
+        let angle = Quantity::from(Angle::radian(1.0));
+        assert!(angle != Quantity::from(Ratio::ratio(1.0)));
+        #[cfg(not(feature="wide_dimensions"))]
+        {
+            assert!(matches!(angle.try_convert(InformationUnit::byte.into()), Err(crate::errors::RuntimeUnitError::IncompatibleKinds { from: Some(QuantityKind::Angle), to: Some(QuantityKind::Information) })));
+            assert!(angle.as_kind(None) == Quantity::from(Ratio::ratio(1.0)));
+        }
+        // Angles and information have their own base dimensions with wide dimensions
+        #[cfg(feature="wide_dimensions")]
+        {
+            assert!(matches!(angle.try_convert(InformationUnit::byte.into()), Err(crate::errors::RuntimeUnitError::IncompatibleDimensions { .. })));
+            assert_eq!(angle.unit.base.power(crate::units_base::BaseDimension::Angle), crate::units_base::Rational8::from(1i8));
+            assert_eq!(Quantity::from(crate::Information::byte(1.0)).unit.base.power(crate::units_base::BaseDimension::Information), crate::units_base::Rational8::from(1i8));
+            assert!(angle.as_kind(None) != Quantity::from(Ratio::ratio(1.0)));
+        }
+        // Kinds of angles are kept by quotients
+        let angular_velocity = angle / Quantity::from(Time::second(1.0));
+        assert_eq!(angular_velocity.unit.kind(), Some(QuantityKind::Angle));
+        assert!(angular_velocity.try_convert(AngularVelocityUnit::degree_per_second.into()).is_ok());
+        assert!(angular_velocity.try_convert(FrequencyUnit::hertz.into()).is_err());
+        // Frequencies and radioactivities share dimensions, but are different kinds
+        assert!(Quantity::from(crate::Frequency::hertz(1.0)).try_convert(crate::units::RadioactivityUnit::becquerel.into()).is_err());
+        assert!(Quantity::from(Time::second(1.0)).inv().try_convert(FrequencyUnit::hertz.into()).is_ok());
+        // Torque and energy share dimensions, but are different kinds
+        let torque = Quantity::from(Torque::newton_meter(2.0));
+        assert!(torque.try_convert(EnergyUnit::joule.into()).is_err());
+        assert!(torque != Quantity::from(Energy::joule(2.0)));
+        assert!(torque.as_kind(Some(QuantityKind::Energy)) == Quantity::from(Energy::joule(2.0)));
+        assert!(EnergyUnit::try_from(UnitDefinition::from(TorqueUnit::newton_meter)).is_err());
+        // Products have no kind, and may become either
+        let work = Quantity::from(Force::newton(2.0)) * Quantity::from(Length::meter(1.0));
+        assert_eq!(work.unit.kind(), None);
+        assert!(work == Quantity::from(Energy::joule(2.0)));
+        assert!(work == torque);
+        assert_eq!(Energy::from(work), Energy::joule(2.0));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Mass", feature="Time", feature="Information", feature="TemperatureInterval", feature="ThermodynamicTemperature")))]
+    fn test_auto_scale()
+    {
+        use crate::format::AutoScale;
+        use crate::{quantity::Quantity, Information, Length, Mass, Quantities, ThermodynamicTemperature, Time};
+        assert_eq!(Quantities::Length(Length::meter(0.000000123)).to_human_string(), "123 nm");
+        assert_eq!(Quantities::Length(Length::kilometer(-0.0015)).to_human_string(), "-1.5 m");
+        assert_eq!(Quantities::Length(Length::centimeter(250.0)).to_human_string(), "2.5 m");
+        assert_eq!(Quantities::Length(Length::meter(500.0)).to_human_string_with(&AutoScale::si().with_range(0.1, 100.0)), "0.5 km");
+        assert_eq!(Quantities::Mass(Mass::kilogram(0.0012)).to_human_string(), "1.2 g");
+        assert_eq!(Quantities::Time(Time::second(0.002)).to_human_string(), "2 ms");
+        assert_eq!(Quantities::Time(Time::minute(5.0)).to_human_string(), "5 min");
+        assert_eq!(Quantities::Information(Information::byte(1610612736.0)).to_human_string_with(&AutoScale::binary()), "1.5 GiB");
+        assert_eq!(Quantities::Information(Information::byte(1610612736.0)).to_human_string(), "1.610612736 GB");
+        assert_eq!(Quantities::ThermodynamicTemperature(ThermodynamicTemperature::degree_celsius(5000.0)).to_human_string(), "5000 °C");
+        assert_eq!(Length::meter(0.000000123).auto_scale(&AutoScale::default()), Length::nanometer(123.0));
+        assert_eq!(format!("{:#}", Quantity::from(Length::kilometer(0.0015))), "1.5 m");
+        assert_eq!(format!("{:#}", Quantity::from(Length::meter(1.0)) / Quantity::from(Time::second(1.0))), "1 m/s");
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Mass", feature="Time", feature="Acceleration")))]
+    fn test_quantity_formatter()
+    {
+        use crate::format::{AutoScale, Notation, QuantityFormatter, UnitName, UnitStyle};
+        use crate::{quantity::Quantity, Acceleration, Length, Mass, Quantities, Time};
+        let formatter = QuantityFormatter::default();
+        assert_eq!(formatter.format_value(0.000123), "0.000123");
+        assert_eq!(formatter.with_decimals(2).format_value(1.23456), "1.23");
+        assert_eq!(formatter.with_significant_figures(3).format_value(123456.0), "123000");
+        assert_eq!(formatter.with_significant_figures(3).format_value(0.0012345), "0.00123");
+        assert_eq!(formatter.with_significant_figures(3).format_value(9.996), "10.0");
+        let scientific = formatter.with_notation(Notation::Scientific).with_significant_figures(3);
+        assert_eq!(scientific.format_value(0.000123456), "1.23e-4");
+        assert_eq!(scientific.format_value(9.996), "1.00e1");
+        assert_eq!(scientific.with_unit_style(UnitStyle::Unicode).format_value(-12345.0), "-1.23×10⁴");
+        assert_eq!(scientific.with_unit_style(UnitStyle::Latex).format_value(12345.0), r"1.23 \times 10^{4}");
+        let engineering = formatter.with_notation(Notation::Engineering).with_significant_figures(3);
+        assert_eq!(engineering.format_value(0.000123456), "123e-6");
+        assert_eq!(engineering.format_value(999.6), "1.00e3");
+
+        let acceleration = Quantities::Acceleration(Acceleration::meter_per_second_squared(9.80665));
+        assert_eq!(formatter.with_significant_figures(3).format(&acceleration), "9.81 m/s^2");
+        assert_eq!(formatter.with_significant_figures(3).with_unit_style(UnitStyle::Unicode).format(&acceleration), "9.81 m·s⁻²");
+        assert_eq!(formatter.with_significant_figures(3).with_unit_style(UnitStyle::Latex).format(&acceleration), r"9.81\,\mathrm{m\,s^{-2}}");
+        let length = Quantities::Length(Length::kilometer(1.0));
+        assert_eq!(formatter.with_unit_name(UnitName::Agreement).format(&length), "1 kilometer");
+        assert_eq!(formatter.with_unit_name(UnitName::Agreement).format(&Quantities::Length(Length::kilometer(2.5))), "2.5 kilometers");
+        assert_eq!(formatter.with_unit_name(UnitName::Plural).format(&length), "1 kilometers");
+        assert_eq!(formatter.with_auto_scale(AutoScale::default()).format(&Quantities::Length(Length::meter(0.000000123))), "123 nm");
+
+        let unnamed = Quantity::from(Length::meter(4.0)).powf(0.5) * Quantity::from(Mass::gram(1000.0)) / Quantity::from(Time::second(1.0)).powi(3);
+        assert_eq!(formatter.format_quantity(&unnamed), "2 m^(1/2)*kg/s^3");
+        assert_eq!(formatter.with_unit_style(UnitStyle::Unicode).format_quantity(&unnamed), "2 m^(1/2)·kg·s⁻³");
+        assert_eq!(formatter.with_unit_style(UnitStyle::Latex).format_quantity(&unnamed), r"2\,\mathrm{m^{1/2}\,kg\,s^{-3}}");
+        assert_eq!(formatter.format_quantity(&Quantity::from(Length::kilometer(1.5))), "1.5 km");
+        assert_eq!(formatter.format_unit_definition(Quantity::from(Length::meter(1.0)).powi(2).inv().unit), "1/m^2");
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Time", feature="ThermodynamicTemperature")))]
+    fn test_checked_arithmetic()
+    {
+        use crate::traits::CheckedAdditive;
+        use crate::{array_quantity::ArrayQuantity, quantity::Quantity, vector_quantity::VecQuantity};
+        use crate::{units::LengthUnit, Length, LengthArray, LengthVec, Quantities, TemperatureInterval, ThermodynamicTemperature, Time, Units};
+        let meter = Quantity::from(Length::meter(1.0));
+        let second = Quantity::from(Time::second(1.0));
+        assert!(meter.checked_add(second).is_err());
+        assert!(meter.checked_sub(second).is_err());
+        assert_eq!(meter.checked_add(Quantity::from(Length::centimeter(50.0))).unwrap().value(), 1.5);
+        let mut sum = meter;
+        assert!(sum.try_add_assign(second).is_err());
+        assert_eq!(sum.value(), 1.0);
+        sum.try_sub_assign(Quantity::from(Length::centimeter(50.0))).unwrap();
+        assert_eq!(sum.value(), 0.5);
+
+        let celsius = Quantity::from(ThermodynamicTemperature::degree_celsius(20.0));
+        assert!(celsius.checked_add(celsius).is_err());
+        assert!(Quantity::from(TemperatureInterval::kelvin(1.0)).checked_sub(celsius).is_err());
+        let warmer = Quantity::from(TemperatureInterval::kelvin(1.0)).checked_add(celsius).unwrap();
+        assert_eq!((warmer.value(), warmer.unit), (21.0, celsius.unit));
+        let interval = celsius.checked_sub(Quantity::from(ThermodynamicTemperature::degree_celsius(5.0))).unwrap();
+        assert!(!interval.unit.is_absolute());
+        assert_eq!(interval.value(), 15.0);
+
+        let length = Length::meter(1.0);
+        assert!(length.checked_add(second).is_err());
+        assert_eq!(length.checked_add(Quantity::from(Length::kilometer(1.0))).unwrap(), Length::meter(1001.0));
+        assert_eq!(length.checked_sub(Length::centimeter(50.0)).unwrap(), Length::meter(0.5));
+
+        let vec = VecQuantity { unit: meter.unit, values: vec![1.0, 2.0] };
+        assert!(vec.clone().checked_add(VecQuantity { unit: meter.unit, values: vec![1.0] }).is_err());
+        assert!(vec.clone().checked_add(VecQuantity { unit: second.unit, values: vec![1.0, 2.0] }).is_err());
+        assert_eq!(vec.clone().checked_sub(vec.clone()).unwrap().values, vec![0.0, 0.0]);
+        let array = ArrayQuantity { unit: meter.unit, values: [1.0, 2.0] };
+        assert!(array.checked_add(ArrayQuantity { unit: second.unit, values: [1.0, 2.0] }).is_err());
+        assert_eq!(array.checked_add(array).unwrap().values, [2.0, 4.0]);
+        let mut lengths = LengthVec::new(vec![1.0, 2.0], LengthUnit::meter);
+        assert!(lengths.try_add_assign(LengthVec::new(vec![1.0], LengthUnit::meter)).is_err());
+        assert_eq!(LengthArray::new([1.0], LengthUnit::meter).checked_add(LengthArray::new([100.0], LengthUnit::centimeter)).unwrap().values, [2.0]);
+
+        let km = Quantities::Length(Length::kilometer(1.0));
+        assert!(km.try_add(&Quantities::Time(Time::second(1.0))).is_err());
+        let total = km.try_add(&Quantities::Length(Length::meter(500.0))).unwrap();
+        assert_eq!(total.unit(), Units::Length(LengthUnit::kilometer));
+        assert_eq!(total.value(), 1.5);
+        let difference = Quantities::ThermodynamicTemperature(ThermodynamicTemperature::degree_celsius(20.0))
+            .try_sub(&Quantities::ThermodynamicTemperature(ThermodynamicTemperature::kelvin(283.15))).unwrap();
+        assert!(matches!(difference, Quantities::TemperatureInterval(_)));
+        assert!((difference.value() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    #[cfg(feature="All")]
+    fn test_structured_errors()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::parser::parse_unit;
+        use crate::traits::ArbitraryQuantity;
+        use crate::traits::CheckedAdditive;
+        use crate::units::{EnergyUnit, LengthUnit, ThermodynamicTemperatureUnit, TimeUnit, TorqueUnit};
+        use crate::units_base::UnitDefinition;
+        use crate::{quantity::Quantity, Length, Quantities, TemperatureInterval, ThermodynamicTemperature, Time, UnitTypes, Units};
+        let meter = Quantity::from(Length::meter(1.0));
+        let second = Quantity::from(Time::second(1.0));
+        match meter.try_convert(second.unit)
+        {
+            Err(RuntimeUnitError::IncompatibleDimensions { from, to }) => 
+            {
+                assert_eq!(from, meter.unit.base);
+                assert_eq!(to, second.unit.base);
+            }
+            other => panic!("Unexpected result {other:?}")
+        }
+        assert_eq!(meter.try_convert(second.unit).unwrap_err().to_string(), "Could not convert from base units of m to s");
+        assert!(matches!(LengthUnit::try_from(second.unit), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        // Arithmetic and conversions between quantity types keep the structured error of the conversion
+        assert!(matches!(meter.checked_add(second), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(meter.checked_sub(second), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(CheckedAdditive::checked_add(Length::meter(1.0), second), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        let celsius = Quantity::from(ThermodynamicTemperature::degree_celsius(20.0));
+        assert!(matches!(celsius.checked_add(celsius), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(Quantity::from(TemperatureInterval::kelvin(1.0)).checked_sub(celsius), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        let joule: UnitDefinition = EnergyUnit::joule.into();
+        assert!(matches!(TorqueUnit::try_from(joule), Err(RuntimeUnitError::IncompatibleKinds { .. })));
+        assert!(matches!(LengthUnit::try_from(UnitDefinition { multiplier: 3.0, ..meter.unit }), Err(RuntimeUnitError::UnknownUnit { quantity: Some(UnitTypes::Length), .. })));
+        assert!(matches!(LengthUnit::try_from(Units::Time(TimeUnit::second)), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(TorqueUnit::try_from(Units::Energy(EnergyUnit::joule)), Err(RuntimeUnitError::IncompatibleKinds { .. })));
+        assert!(matches!(Length::try_from(Quantities::Time(Time::second(1.0))), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(Units::try_from(UnitDefinition { multiplier: 3.0, ..meter.unit }), Err(RuntimeUnitError::UnknownUnit { quantity: None, .. })));
+
+        match UnitTypes::Length.to_unit("fortnight")
+        {
+            Err(RuntimeUnitError::UnknownUnit { input, quantity: Some(UnitTypes::Length) }) => assert_eq!(input, "fortnight"),
+            other => panic!("Unexpected result {other:?}")
+        }
+        assert_eq!(LengthUnit::try_from("fortnight").unwrap_err().to_string(), "Unknown unit \"fortnight\" for Length");
+        assert!(matches!("furlong".parse::<Units>(), Err(RuntimeUnitError::UnknownUnit { quantity: None, .. })));
+        assert!(matches!(parse_unit("kg*furlong/s"), Err(RuntimeUnitError::UnknownUnit { input, quantity: None }) if input == "furlong"));
+
+        match Units::parse_unique("h")
+        {
+            Err(RuntimeUnitError::AmbiguousUnit { input, candidates }) => 
+            {
+                assert_eq!(input, "h");
+                assert!(candidates.contains(&Units::Time(TimeUnit::hour)));
+                assert!(candidates.len() > 1);
+            }
+            other => panic!("Unexpected result {other:?}")
+        }
+        assert_eq!(Units::parse_unique("min").unwrap(), Units::Time(TimeUnit::minute));
+        assert!(matches!(parse_unit("h"), Err(RuntimeUnitError::AmbiguousUnit { input, .. }) if input == "h"));
+        assert!(matches!(parse_unit("kg*h"), Err(RuntimeUnitError::AmbiguousUnit { .. })));
+        // A temperature scale on its own is absolute, as in `eval`, and a temperature interval within an expression
+        assert!(parse_unit("°C").unwrap().is_absolute());
+        assert!(!parse_unit("J/(kg·°C)").unwrap().is_absolute());
+        assert!(parse_unit("K").unwrap().is_absolute());
+        assert!(parse_unit("°R").unwrap().is_absolute());
+        assert_eq!("20 °C".parse::<Quantity>().unwrap(), crate::eval("20 °C").unwrap());
+        assert_eq!("20 °C".parse::<Quantities>().unwrap(), Quantities::ThermodynamicTemperature(ThermodynamicTemperature::degree_celsius(20.0)));
+        assert_eq!("K".parse::<Units>().unwrap(), Units::ThermodynamicTemperature(ThermodynamicTemperatureUnit::kelvin));
+        assert!(matches!("h".parse::<Units>(), Err(RuntimeUnitError::AmbiguousUnit { .. })));
+        assert!(matches!("1 h".parse::<Quantities>(), Err(RuntimeUnitError::AmbiguousUnit { .. })));
+        assert_eq!("1 min".parse::<Quantities>().unwrap(), Quantities::Time(Time::minute(1.0)));
+        assert!(matches!(Units::parse_unique("furlong"), Err(RuntimeUnitError::UnknownUnit { .. })));
+
+        assert!(matches!("ten m".parse::<Quantities>(), Err(RuntimeUnitError::InvalidNumber { input }) if input == "ten"));
+        assert!(matches!("1.2.3 m".parse::<Quantity>(), Err(RuntimeUnitError::InvalidNumber { input }) if input == "1.2.3"));
+        assert!(matches!(parse_unit("1.2.3/s"), Err(RuntimeUnitError::InvalidNumber { .. })));
+        assert!(matches!(parse_unit("m^200"), Err(RuntimeUnitError::ExponentOverflow { numerator: 200, denominator: 1 })));
+        assert!(matches!(parse_unit("m^(1/300)"), Err(RuntimeUnitError::ExponentOverflow { numerator: 1, denominator: 300 })));
+        assert!(matches!(parse_unit("m^(1/"), Err(RuntimeUnitError::ParseError(_))));
+    }
+
+    #[test]
+    #[cfg(all(any(feature="All", feature="Length"), not(feature="wide_dimensions")))]
+    fn test_exponent_overflow()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::units_base::Rational8;
+        use crate::{quantity::Quantity, Length};
+        assert_eq!(Rational8::try_new(2, -4).unwrap(), Rational8::from(-0.5));
+        assert!(matches!(Rational8::try_new(8, 1), Err(RuntimeUnitError::ExponentOverflow { numerator: 8, denominator: 1 })));
+        assert!(matches!(Rational8::try_new(1, 8), Err(RuntimeUnitError::ExponentOverflow { numerator: 1, denominator: 8 })));
+        assert_eq!(Rational8::from(7i8).checked_add(Rational8::from(-8i8)).unwrap(), Rational8::from(-1i8));
+        assert_eq!(Rational8::try_new(1, 2).unwrap().checked_add(Rational8::try_new(1, 3).unwrap()).unwrap(), Rational8::try_new(5, 6).unwrap());
+        assert!(Rational8::try_new(1, 2).unwrap().checked_add(Rational8::try_new(1, 5).unwrap()).is_err());
+        assert!(matches!(Rational8::try_from_f64(0.1), Err(RuntimeUnitError::ExponentOverflow { numerator: 1, denominator: 10 })));
+
+        let area = Quantity::from(Length::meter(2.0)).powi(4);
+        assert!(matches!(area.checked_mul(area), Err(RuntimeUnitError::ExponentOverflow { numerator: 8, denominator: 1 })));
+        let inverse = area.inv().checked_div(area).unwrap();
+        assert!(matches!(inverse.checked_div(Quantity::from(Length::meter(1.0))), Err(RuntimeUnitError::ExponentOverflow { numerator: -9, denominator: 1 })));
+        assert_eq!(area.checked_div(area).unwrap().unit.base, Quantity::from(Length::meter(1.0)).powi(0).unit.base);
+        let length = Quantity::from(Length::meter(4.0));
+        assert!(length.checked_powi(8).is_err());
+        assert_eq!(length.checked_powi(-8).unwrap().value(), 4f64.powi(-8));
+        assert_eq!(length.checked_powf(0.5).unwrap().value(), 2.0);
+        assert!(matches!(length.checked_powf(0.1), Err(RuntimeUnitError::ExponentOverflow { numerator: 1, denominator: 10 })));
+        assert!(length.unit.checked_powf(1.0/3.0).unwrap().checked_mul(length.unit.checked_powf(0.25).unwrap()).is_err());
+        // The most negative power cannot be negated, but is still displayed
+        let inverse = crate::units_base::UnitDefinition::new(1.0, -8, 0, 0, 0, 0, 0, 0);
+        assert_eq!(format!("{inverse}"), "1/m^8");
+        assert_eq!(inverse.base.to_string_styled(crate::format::UnitStyle::Unicode), "m⁻⁸");
+        assert_eq!(format!("{}", crate::parser::parse_unit("m^-8").unwrap()), "1/m^8");
+    }
+
+    #[test]
+    #[cfg(all(any(feature="All", feature="Length"), not(feature="wide_dimensions")))]
+    #[should_panic(expected = "Exponent 8 is out of range")]
+    fn test_exponent_overflow_panics()
+    {
+        use crate::{quantity::Quantity, Length};
+        let area = Quantity::from(Length::meter(2.0)).powi(4);
+        let _ = area * area;
+    }
+
+    #[test]
+    #[cfg(all(feature="wide_dimensions", any(feature="All", all(feature="Length", feature="Time"))))]
+    fn test_wide_dimensions()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::parser::parse_unit;
+        use crate::traits::ArbitraryQuantity;
+        use crate::units_base::{BaseDimension, Rational8, UnitDefinition};
+        use crate::{quantity::Quantity, Length, Time};
+        let length = Quantity::from(Length::meter(2.0));
+        let large = length.checked_powi(8).unwrap().checked_mul(length.powi(8)).unwrap();
+        assert_eq!(large.unit.base.power(BaseDimension::Length), Rational8::from(16i8));
+        assert_eq!(large.value(), 2f64.powi(16));
+        assert_eq!(large.unit.base.to_string(), "m^16");
+        assert!(matches!(length.checked_powi(127).unwrap().checked_mul(length), Err(RuntimeUnitError::ExponentOverflow { numerator: 128, denominator: 1 })));
+        assert_eq!(parse_unit("m^20/s^12").unwrap().base, length.unit.powi(20).base.checked_div(Quantity::from(Time::second(1.0)).unit.powi(12).base).unwrap());
+
+        let dollar = UnitDefinition::dimensionless().with_power(BaseDimension::Count, Rational8::from(1i8));
+        let cent = UnitDefinition { multiplier: 0.01, ..dollar };
+        let price = Quantity::new(3.0, dollar / length.unit);
+        assert_eq!(price.unit.base.to_string(), "count/m");
+        assert_eq!(Quantity::new(250.0, cent).try_convert(dollar).unwrap().value(), 2.5);
+        assert!(Quantity::new(1.0, dollar).try_convert(UnitDefinition::dimensionless()).is_err());
+        let bits = UnitDefinition::dimensionless().with_power(BaseDimension::Information, Rational8::from(1i8));
+        assert_eq!((bits / Quantity::from(Time::second(1.0)).unit).base.power(BaseDimension::Information), Rational8::from(1i8));
+        assert_eq!(BaseDimension::ALL.len(), 10);
+        #[cfg(feature="serde")]
+        {
+            let json = serde_json::to_value(price.unit).unwrap();
+            assert_eq!(json["base"], serde_json::json!({ "Length": [-1, 1], "Count": [1, 1] }));
+            assert_eq!(serde_json::from_value::<UnitDefinition>(json).unwrap(), price.unit);
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature="wide_dimensions", any(feature="All", feature="Length")))]
+    fn test_exponent_overflow_wide()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::units_base::Rational8;
+        use crate::{quantity::Quantity, Length};
+        assert_eq!(Rational8::try_new(-128, 1).unwrap().numerator(), -128);
+        assert!(matches!(Rational8::try_new(128, 1), Err(RuntimeUnitError::ExponentOverflow { numerator: 128, denominator: 1 })));
+        assert!(matches!(Rational8::try_new(1, 8), Err(RuntimeUnitError::ExponentOverflow { numerator: 1, denominator: 8 })));
+        assert_eq!(Rational8::from(127i8).checked_add(Rational8::from(-128i8)).unwrap(), Rational8::from(-1i8));
+        assert!(Rational8::from(127i8).checked_add(Rational8::from(1i8)).is_err());
+        assert!(Rational8::try_new(1, 2).unwrap().checked_add(Rational8::try_new(1, 5).unwrap()).is_err());
+
+        let length = Quantity::from(Length::meter(2.0));
+        let large = length.checked_powi(64).unwrap();
+        assert!(matches!(large.checked_mul(large), Err(RuntimeUnitError::ExponentOverflow { numerator: 128, denominator: 1 })));
+        assert!(matches!(large.inv().checked_div(large.checked_mul(length).unwrap()), Err(RuntimeUnitError::ExponentOverflow { numerator: -129, denominator: 1 })));
+        assert!(length.checked_powi(-128).is_ok());
+        assert!(length.checked_powi(i8::MAX).unwrap().checked_mul(length).is_err());
+        // The most negative power cannot be negated, but is still displayed
+        assert_eq!(length.checked_powi(-128).unwrap().unit.base.to_string(), "1/m^128");
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Force", feature="Area", feature="Pressure", feature="MassDensity", feature="Velocity", feature="DynamicViscosity")))]
+    fn test_dimensional_analysis()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::analysis::{combine, dimensionless_groups, rank, solve, Exponent};
+        use crate::units::{AreaUnit, DynamicViscosityUnit, ForceUnit, LengthUnit, MassDensityUnit, PressureUnit, TimeUnit, VelocityUnit};
+        use crate::units_base::UnitDefinition;
+        let force: UnitDefinition = ForceUnit::newton.into();
+        let area: UnitDefinition = AreaUnit::square_meter.into();
+        let pressure: UnitDefinition = PressureUnit::pascal.into();
+        assert_eq!(solve(&[force, area], pressure.base).unwrap(), vec![Exponent::from_integer(1), Exponent::from_integer(-1)]);
+        assert!(combine(&[force, area], &solve(&[force, area], pressure.base).unwrap()).unwrap().approx_eq(pressure, 1e-12));
+        let time: UnitDefinition = TimeUnit::second.into();
+        assert!(matches!(solve(&[force, time], pressure.base), Err(RuntimeUnitError::IncompatibleDimensions { from, to }) if from != to && to == pressure.base));
+        assert!(matches!(combine(&[force, time], &[Exponent::from_integer(1)]), Err(RuntimeUnitError::LengthMismatch { expected: 2, found: 1 })));
+        let length: UnitDefinition = LengthUnit::meter.into();
+        assert_eq!(solve(&[area], length.base).unwrap(), vec![Exponent::new(1, 2)]);
+        assert!(combine(&[area], &[Exponent::new(1, 2)]).unwrap().approx_eq(length, 1e-12));
+
+        // Reynolds number: density * velocity * length / viscosity
+        let density: UnitDefinition = MassDensityUnit::kilogram_per_cubic_meter.into();
+        let velocity: UnitDefinition = VelocityUnit::meter_per_second.into();
+        let viscosity: UnitDefinition = DynamicViscosityUnit::pascal_second.into();
+        assert_eq!(rank(&[density, velocity, length, viscosity]), 3);
+        assert_eq!(dimensionless_groups(&[density, velocity, length, viscosity]), vec![vec![1, 1, 1, -1]]);
+        // Drag: two groups, the drag coefficient and the Reynolds number
+        let groups = dimensionless_groups(&[force, density, velocity, length, viscosity]);
+        assert_eq!(groups.len(), 2);
+        for group in &groups
+        {
+            let exponents: Vec<Exponent> = group.iter().map(|&e| Exponent::from_integer(e)).collect();
+            assert_eq!(combine(&[force, density, velocity, length, viscosity], &exponents).unwrap().base, UnitDefinition::dimensionless().base);
+        }
+        assert_eq!(dimensionless_groups(&[area, length]), vec![vec![1, -2]]);
+        assert!(dimensionless_groups(&[force, time]).is_empty());
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Velocity", feature="Action", feature="Mass")))]
+    fn test_constants()
+    {
+        use crate::constants::{self, GRAVITATIONAL_CONSTANT, SPEED_OF_LIGHT};
+        use crate::Velocity;
+        use crate::units_base::UnitDefinition;
+        assert_eq!(constants::speed_of_light(), Velocity::meter_per_second(299_792_458.0));
+        assert_eq!(constants::planck_constant().value, 6.626_070_15E-34);
+        assert_eq!(constants::electron_mass().value, 9.109_383_713_9E-31);
+        assert_eq!(SPEED_OF_LIGHT.quantity().unit, UnitDefinition::new(1.0, 1, 0, -1, 0, 0, 0, 0));
+        assert!(SPEED_OF_LIGHT.is_exact());
+        assert!(!GRAVITATIONAL_CONSTANT.is_exact());
+        assert!((GRAVITATIONAL_CONSTANT.relative_uncertainty() - 2.2e-5).abs() < 1e-6);
+        assert_eq!(constants::find("c"), Some(SPEED_OF_LIGHT));
+        assert_eq!(constants::find("speed_of_light"), Some(SPEED_OF_LIGHT));
+        assert_eq!(constants::find("speed_of_sound"), None);
+        // Constants in unit expressions, after the built-in units
+        let energy = crate::parser::parse_unit("kg*speed_of_light^2").unwrap();
+        assert_eq!(energy.base, UnitDefinition::new(1.0, 2, 1, -2, 0, 0, 0, 0).base);
+        assert!((energy.multiplier - 299_792_458.0f64.powi(2)).abs() / energy.multiplier < 1e-12);
+        assert_eq!(crate::parser::parse_unit("m").unwrap(), UnitDefinition::new(1.0, 1, 0, 0, 0, 0, 0, 0));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Force", feature="Power", feature="Time", feature="Area", feature="Action", feature="ThermodynamicTemperature", feature="TemperatureInterval")))]
+    fn test_eval()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::expression::Context;
+        use crate::traits::ArbitraryQuantity;
+        use crate::units::{AreaUnit, LengthUnit, PowerUnit};
+        use crate::{Length, Quantity, ThermodynamicTemperature};
+        let length = crate::eval("3 ft + 20 cm * 2").unwrap();
+        assert!((length.try_convert(LengthUnit::meter.into()).unwrap().value() - (3.0*0.3048 + 0.4)).abs() < 1e-12);
+        assert_eq!(length.unit(), LengthUnit::foot.into());
+        let power = crate::eval("(5 kN * 2 m) / 10 s").unwrap();
+        assert!((power.try_convert(PowerUnit::watt.into()).unwrap().value() - 1000.0).abs() < 1e-9);
+        assert!((crate::eval("-2^2 + 1.5e1").unwrap().value() - 11.0).abs() < 1e-12);
+        assert!((crate::eval("10 m / 2 s * 3 s").unwrap().value() - 15.0).abs() < 1e-12);
+        assert_eq!(crate::eval("sqrt(4 m²)").unwrap(), Quantity::from(Length::meter(2.0)));
+        assert_eq!(crate::eval("pow(3 m, 2)").unwrap().unit().base, crate::units_base::UnitDefinition::from(AreaUnit::square_meter).base);
+        assert_eq!(crate::eval("abs(-2 m)").unwrap(), Quantity::from(Length::meter(2.0)));
+        assert_eq!(crate::eval("min(1 m, 50 cm, 2 ft)").unwrap(), Quantity::from(Length::centimeter(50.0)));
+        assert_eq!(crate::eval("max(1 m, 50 cm, 2 ft)").unwrap(), Quantity::from(Length::meter(1.0)));
+
+        let context = Context::new().with_variable("width", Length::meter(2.0).into()).with_variable("height", Length::centimeter(50.0).into());
+        let area = context.eval("width * height + 1 m^2").unwrap();
+        assert!((area.try_convert(AreaUnit::square_meter.into()).unwrap().value() - 2.0).abs() < 1e-12);
+
+        assert!(matches!(crate::eval("1 m + 1 s"), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(crate::eval("max(1 m, 1 s)"), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(crate::eval("2^(1 m)"), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(crate::eval("3 furlongs_per_fortnight"), Err(RuntimeUnitError::UnknownUnit { .. })));
+        assert!(matches!(crate::eval("(1 m"), Err(RuntimeUnitError::ParseError(_))));
+        assert!(matches!(crate::eval("pow(1 m)"), Err(RuntimeUnitError::ParseError(_))));
+        assert!(matches!(crate::eval("2 h"), Err(RuntimeUnitError::AmbiguousUnit { .. })));
+
+        // Absolute temperatures can be offset but not scaled
+        let temperature = crate::eval("20 °C + 5 K").unwrap();
+        assert_eq!(temperature, Quantity::from(ThermodynamicTemperature::degree_celsius(25.0)));
+        assert!((crate::eval("25 °C - 20 °C").unwrap().value() - 5.0).abs() < 1e-12);
+        assert_eq!(crate::eval("20 °C + 5 °C").unwrap(), temperature);
+        let difference = crate::eval("300 K - 20 °C").unwrap();
+        assert!(!difference.unit.is_absolute());
+        assert!((difference.value() - 6.85).abs() < 1e-9);
+        assert!((crate::eval("300 K * 2").unwrap().value() - 600.0).abs() < 1e-12);
+        assert!(matches!(crate::eval("20 °C * 2"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(crate::eval("20 °C / 2 s"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(crate::eval("abs(-20 °C)"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(crate::eval("20 °C m"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(crate::eval("pow(20 °C, 2)"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(crate::eval("(20 °C)^2"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(crate::eval("sqrt(20 °C)"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!((crate::eval("sqrt(400 K)").unwrap().value() - 20.0).abs() < 1e-12);
+        assert_eq!(crate::eval("abs(-20 K)").unwrap().value(), 20.0);
+        let context = Context::new().with_variable("t", ThermodynamicTemperature::degree_celsius(20.0).into());
+        assert!(matches!(context.eval("2 * t"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!(matches!(context.eval("abs(t)"), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!((crate::eval("10 K / 2 s").unwrap().value() - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Mass")))]
+    fn test_generic_numeric()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::traits::{ArbitraryQuantity, CheckedAdditive, FixedQuantity, FixedSliceQuantity};
+        use crate::units::{LengthUnit, MassUnit};
+        use crate::{array_quantity::ArrayQuantity, quantity::Quantity, vector_quantity::VecQuantity};
+        use crate::{Length, LengthVec, Mass};
+        let length = Length::new(1.5f32, LengthUnit::meter);
+        assert_eq!(length.convert(LengthUnit::centimeter).value(), 150.0f32);
+        assert_eq!(length * 2.0, Length::new(3.0f32, LengthUnit::meter));
+        assert!(Length::new(1.0f32, LengthUnit::meter) > Length::new(90.0f32, LengthUnit::centimeter));
+
+        // Integer conversions fail rather than round or saturate
+        let grams = Quantity::new(1500i64, MassUnit::gram.into());
+        assert!(matches!(grams.try_convert(MassUnit::kilogram.into()), Err(RuntimeUnitError::ValueOutOfRange { value }) if value == 1.5));
+        assert_eq!(Quantity::new(3000i64, MassUnit::gram.into()).try_convert(MassUnit::kilogram.into()).unwrap().value(), 3);
+        let centimeters = Quantity::new(200u8, LengthUnit::centimeter.into());
+        assert!(matches!(centimeters.try_convert(LengthUnit::millimeter.into()), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert!(Length::new(200u8, LengthUnit::centimeter).try_convert(LengthUnit::millimeter.into()).is_err());
+        assert_eq!(Length::new(3u16, LengthUnit::kilometer).try_convert(LengthUnit::meter.into()).unwrap().value(), 3000);
+        let meters = Length::new(200u8, LengthUnit::meter);
+        assert!(matches!(CheckedAdditive::checked_add(meters, Length::new(100u8, LengthUnit::meter)), Err(RuntimeUnitError::ValueOutOfRange { value }) if value == 300.0));
+        assert!(matches!(CheckedAdditive::checked_sub(Length::new(100u8, LengthUnit::meter), meters), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert!(matches!(CheckedAdditive::checked_add(Length::new(2u8, LengthUnit::meter), Length::new(1u8, LengthUnit::kilometer)), Err(RuntimeUnitError::ValueOutOfRange { value }) if value == 1000.0));
+        assert_eq!(CheckedAdditive::checked_add(Length::new(2u16, LengthUnit::meter), Length::new(1u16, LengthUnit::kilometer)).unwrap().value(), 1002);
+        let mut total = meters;
+        assert!(total.try_add_assign(meters).is_err());
+        assert_eq!(total, meters);
+        assert!(VecQuantity { unit: LengthUnit::meter.into(), values: vec![1i32, -3] }.try_convert(LengthUnit::kilometer.into()).is_err());
+        let mass = Mass::new(3i32, MassUnit::kilogram);
+        assert_eq!(mass.convert(MassUnit::gram).value(), 3000);
+        assert_eq!((mass / Mass::new(2i32, MassUnit::kilogram)).value(), 1);
+
+        let lengths = LengthVec::new(vec![1.0f32, 2.0], LengthUnit::meter);
+        assert_eq!(lengths.convert(LengthUnit::millimeter).values(), &[1000.0f32, 2000.0]);
+        let mut vec = VecQuantity { unit: LengthUnit::kilometer.into(), values: vec![1u32, 2] };
+        vec.convert_mut(LengthUnit::meter.into());
+        assert_eq!(vec.values, vec![1000, 2000]);
+        let array = ArrayQuantity { unit: LengthUnit::meter.into(), values: [0.5f32, 1.0] };
+        assert_eq!(array.convert(LengthUnit::centimeter.into()).values, [50.0, 100.0]);
+
+        // f64 remains the default value type
+        let default: Quantity = Length::meter(1.0).into();
+        assert_eq!(default.value(), 1.0f64);
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Area", feature="ThermodynamicTemperature", feature="Time")))]
+    fn test_measured_quantity()
+    {
+        use crate::traits::{ArbitraryQuantity, CheckedAdditive};
+        use crate::units::{LengthUnit, ThermodynamicTemperatureUnit, TimeUnit};
+        use crate::units_base::UnitDefinition;
+        use crate::{Length, MeasuredQuantity, Quantity};
+        let length: MeasuredQuantity = "12.3 ± 0.2 mm".parse().unwrap();
+        assert_eq!(length, MeasuredQuantity::new(12.3, 0.2, LengthUnit::millimeter.into()));
+        assert_eq!("12.3 +/- 0.2 mm".parse::<MeasuredQuantity>().unwrap(), length);
+        assert_eq!("12.3(2) mm".parse::<MeasuredQuantity>().unwrap(), length);
+        let gravitation: MeasuredQuantity = "6.67430(15)e-11 m^3/(kg*s^2)".parse().unwrap();
+        assert!((gravitation.uncertainty() - 1.5e-15).abs() < 1e-25);
+        assert!("12.3(2 mm".parse::<MeasuredQuantity>().is_err());
+        assert!("12.3 ± mm".parse::<MeasuredQuantity>().is_err());
+
+        let meters = length.try_convert(LengthUnit::meter.into()).unwrap();
+        assert!((meters.value() - 0.0123).abs() < 1e-15 && (meters.uncertainty() - 0.0002).abs() < 1e-15);
+        assert!(length.try_convert(TimeUnit::second.into()).is_err());
+        let temperature = MeasuredQuantity::new(20.0, 0.5, ThermodynamicTemperatureUnit::degree_celsius.into());
+        let kelvin = temperature.convert(ThermodynamicTemperatureUnit::kelvin.into());
+        assert!((kelvin.value() - 293.15).abs() < 1e-9 && (kelvin.uncertainty() - 0.5).abs() < 1e-12);
+        // Quotients of absolute quantities are computed from the zero of their scale
+        let ratio = temperature / kelvin;
+        assert!((ratio.value() - 1.0).abs() < 1e-12 && (ratio.uncertainty() - 0.5 / 293.15 * 2f64.sqrt()).abs() < 1e-12);
+        let per_second = temperature / MeasuredQuantity::from_quantity(crate::Time::second(2.0), 0.0);
+        assert!((per_second.value() - 146.575).abs() < 1e-9 && (per_second.uncertainty() - 0.25).abs() < 1e-12);
+
+        // First-order propagation of uncorrelated uncertainties
+        let x = MeasuredQuantity::from_quantity(Length::meter(3.0), 0.3);
+        let y = MeasuredQuantity::from_quantity(Length::centimeter(400.0), 40.0);
+        let sum = x + y;
+        assert!((sum.value() - 7.0).abs() < 1e-12 && (sum.uncertainty() - 0.5).abs() < 1e-12);
+        assert!(((x - y).value() + 1.0).abs() < 1e-12);
+        let area = x * y;
+        assert_eq!(area.unit().base, UnitDefinition::from(crate::units::AreaUnit::square_meter).base);
+        assert!((area.relative_uncertainty() - 0.02f64.sqrt()).abs() < 1e-12);
+        assert!(((x / y).relative_uncertainty() - 0.02f64.sqrt()).abs() < 1e-12);
+        assert!((x.powf(2.0).relative_uncertainty() - 0.2).abs() < 1e-12);
+        assert!((x.powi(-1).uncertainty() - 0.3 / 9.0).abs() < 1e-12);
+        assert!(((x * 2.0).uncertainty() - 0.6).abs() < 1e-12);
+        assert!(x.checked_add(MeasuredQuantity::new(1.0, 0.1, TimeUnit::second.into())).is_err());
+
+        // Fully correlated quantities
+        assert_eq!(x.sub_correlated(x, 1.0).unwrap().uncertainty(), 0.0);
+        assert!((x.add_correlated(x, 1.0).unwrap().uncertainty() - 0.6).abs() < 1e-12);
+        assert!(x.div_correlated(x, 1.0).uncertainty() < 1e-12);
+
+        // Interoperability with exact quantities
+        let doubled = x * Quantity::from(Length::meter(2.0));
+        assert!((doubled.uncertainty() - 0.6).abs() < 1e-12);
+        assert_eq!(MeasuredQuantity::from(Quantity::from(Length::meter(2.0))).uncertainty(), 0.0);
+        assert_eq!(x.quantity(), Quantity::from(Length::meter(3.0)));
+        assert_eq!(format!("{x}"), "3 ± 0.3 m");
+        #[cfg(feature="serde")]
+        assert_eq!(serde_json::from_value::<MeasuredQuantity>(serde_json::json!(x)).unwrap(), x);
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="ElectricalResistance", feature="ElectricPotential", feature="ElectricCurrent", feature="Angle", feature="Length")))]
+    fn test_complex_quantity()
+    {
+        use crate::traits::{ArbitraryQuantity, CheckedAdditive, FixedQuantity};
+        use crate::units::{AngleUnit, ElectricalResistanceUnit, ElectricPotentialUnit, LengthUnit};
+        use crate::units_base::UnitDefinition;
+        use crate::{Angle, Complex, ComplexElectricalResistance, ComplexElectricCurrent, ComplexElectricPotential, ComplexQuantity, ElectricalResistance, ElectricPotential, Length, Quantity};
+        let z = Complex::new(3.0, 4.0);
+        assert_eq!(z.norm(), 5.0);
+        assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+        assert_eq!(z / z, Complex::new(1.0, 0.0));
+        assert_eq!(format!("{}", z.conj()), "3-4i");
+
+        // Impedance of a resistor in series with an inductor
+        let impedance = ComplexElectricalResistance::new(Complex::new(30.0, 40.0), ElectricalResistanceUnit::ohm);
+        assert_eq!(impedance.magnitude(), ElectricalResistance::ohm(50.0));
+        assert_eq!(impedance.real(), ElectricalResistance::ohm(30.0));
+        assert_eq!(impedance.imag(), ElectricalResistance::ohm(40.0));
+        assert!((impedance.phase().convert(AngleUnit::radian).value() - (4.0f64).atan2(3.0)).abs() < 1e-12);
+        let kiloohm = impedance.convert(ElectricalResistanceUnit::kiloohm);
+        assert!((kiloohm.value().re - 0.03).abs() < 1e-15 && (kiloohm.value().im - 0.04).abs() < 1e-15);
+        let ohm = ComplexElectricalResistance::new(Complex::new(1.0, 0.0), ElectricalResistanceUnit::ohm);
+        assert_eq!(ohm, ComplexElectricalResistance::new(Complex::new(1000.0, 0.0), ElectricalResistanceUnit::milliohm));
+        assert_ne!(ohm, ComplexElectricalResistance::new(Complex::new(1.0, 0.0), ElectricalResistanceUnit::milliohm));
+
+        // Ohm's law with phasors yields the derived units
+        let current = ComplexElectricCurrent::from_polar(crate::ElectricCurrent::ampere(2.0), Angle::degree(0.0));
+        let voltage = impedance * current;
+        assert_eq!(voltage.unit().base, UnitDefinition::from(ElectricPotentialUnit::volt).base);
+        let voltage = ComplexElectricPotential::try_from(voltage).unwrap();
+        assert_eq!(voltage.unit(), ElectricPotentialUnit::volt);
+        assert_eq!(voltage.magnitude(), ElectricPotential::volt(100.0));
+        assert!(ComplexElectricCurrent::try_from(ComplexQuantity::from(impedance)).is_err());
+        let admittance = (ComplexQuantity::from(current) / voltage).inv();
+        assert!((admittance.value().re - 30.0).abs() < 1e-12 && (admittance.value().im - 40.0).abs() < 1e-12);
+
+        // Arithmetic and conversions of generic complex quantities
+        let a = ComplexQuantity::new(Complex::new(1.0, 1.0), LengthUnit::meter.into());
+        let b = ComplexQuantity::new(Complex::new(50.0, -100.0), LengthUnit::centimeter.into());
+        assert_eq!((a + b).value(), Complex::new(1.5, 0.0));
+        assert_eq!((a - b).value(), Complex::new(0.5, 2.0));
+        assert!(a.checked_add(ComplexQuantity::from(impedance)).is_err());
+        assert_eq!(a.convert(LengthUnit::centimeter.into()).value(), Complex::new(100.0, 100.0));
+        assert_eq!(a.convert(LengthUnit::centimeter.into()), a);
+        assert_eq!((a * Length::meter(2.0)).value(), Complex::new(2.0, 2.0));
+        assert_eq!((a * Quantity::from(Length::meter(2.0))).unit().base, UnitDefinition::from(crate::units::AreaUnit::square_meter).base);
+        assert_eq!(a.magnitude(), Quantity::from(Length::meter(2.0f64.sqrt())));
+        // Products and quotients of absolute quantities are computed from the zero of their scale, as for `Quantity`
+        #[cfg(any(feature="All", feature="ThermodynamicTemperature"))]
+        {
+            let temperature = ComplexQuantity::new(Complex::new(20.0, 0.0), crate::units::ThermodynamicTemperatureUnit::degree_celsius.into());
+            assert!(((temperature * Quantity::from(Length::meter(2.0))).value() - Complex::new(586.3, 0.0)).norm() < 1e-9);
+            assert!(((temperature / Quantity::from(Length::meter(2.0))).value() - Complex::new(146.575, 0.0)).norm() < 1e-9);
+            assert!(matches!(temperature.checked_add(temperature), Err(crate::errors::RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        }
+        assert_eq!(format!("{impedance}"), "(30+40i) Ω");
+        #[cfg(feature="serde")]
+        assert_eq!(serde_json::from_value::<ComplexElectricalResistance>(serde_json::json!(impedance)).unwrap(), impedance);
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Power", feature="ElectricPotential", feature="Pressure", feature="MolarConcentration")))]
+    fn test_level()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::level::LevelKind;
+        use crate::traits::{ArbitraryQuantity, CheckedAdditive};
+        use crate::units::{MolarConcentrationUnit, PowerUnit};
+        use crate::{ElectricPotential, Level, LevelUnit, MolarConcentration, Power, Pressure, Quantity};
+        let close = |level: Level, value: f64| (level.value() - value).abs() < 1e-9;
+
+        // Power quantities follow the 10·log rule, field quantities the 20·log rule
+        let level = Level::from_quantity(Power::watt(1.0), LevelUnit::decibel_milliwatt).unwrap();
+        assert!(close(level, 30.0));
+        assert!(close(level.try_convert(LevelUnit::decibel_watt).unwrap(), 0.0));
+        let power = Level::new(20.0, LevelUnit::decibel_watt).to_quantity().unwrap();
+        assert!((power.try_convert(PowerUnit::watt.into()).unwrap().value() - 100.0).abs() < 1e-9);
+        assert!(close(Level::from_quantity(ElectricPotential::volt(10.0), LevelUnit::decibel_volt).unwrap(), 20.0));
+        assert!(close(Level::new(0.0, LevelUnit::decibel_volt).try_convert(LevelUnit::decibel_microvolt).unwrap(), 120.0));
+        assert!(close(Level::from_quantity(Pressure::pascal(20.0), LevelUnit::decibel_sound_pressure_level).unwrap(), 120.0));
+        let ph = Level::from_quantity(MolarConcentration::mole_per_liter(1E-7), LevelUnit::ph).unwrap();
+        assert!(close(ph, 7.0));
+        assert!((ph.to_quantity().unwrap().try_convert(MolarConcentrationUnit::mole_per_liter.into()).unwrap().value() - 1E-7).abs() < 1e-20);
+        assert_eq!(LevelUnit::decibel_sound_pressure_level.kind(), LevelKind::Field);
+        assert_eq!(LevelUnit::decibel_milliwatt.reference().unwrap(), Quantity::from(Power::milliwatt(1.0)));
+
+        // Ratios
+        let gain = Level::from_power_ratio(100.0, LevelUnit::decibel).unwrap();
+        assert!(close(gain, 20.0));
+        assert!((gain.field_ratio().unwrap() - 10.0).abs() < 1e-9);
+        assert!(close(Level::from_field_ratio(100.0, LevelUnit::bel).unwrap(), 4.0));
+        assert!(close(gain.try_convert(LevelUnit::neper).unwrap(), core::f64::consts::LN_10));
+        assert!(close(Level::new(1.0, LevelUnit::neper).try_convert(LevelUnit::decibel).unwrap(), 20.0 / core::f64::consts::LN_10));
+        assert!(close(level.apply_gain(Level::new(1.0, LevelUnit::bel)).unwrap(), 40.0));
+
+        // Incoherent sources
+        let noise = Level::new(90.0, LevelUnit::decibel_sound_pressure_level);
+        assert!(close(noise + noise, 90.0 + 10.0 * 2f64.log10()));
+        assert!(close((noise + noise) - noise, 90.0));
+        assert!(close(Level::new(30.0, LevelUnit::decibel_milliwatt) + Level::new(0.0, LevelUnit::decibel_watt), 30.0 + 10.0 * 2f64.log10()));
+        assert!(matches!(noise.checked_sub(noise + noise), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        // Gains are applied, not added
+        let gain = Level::new(20.0, LevelUnit::decibel);
+        assert!(matches!(gain.checked_add(gain), Err(RuntimeUnitError::IncompatibleLevels { .. })));
+        assert!(gain.checked_sub(gain).is_err());
+        assert!(noise.checked_add(gain).is_err());
+        assert!(close(noise.apply_gain(gain).unwrap(), 110.0));
+
+        // Incompatible units
+        assert!(level.try_convert(LevelUnit::decibel_volt).is_err());
+        assert!(matches!(level.try_convert(LevelUnit::decibel), Err(RuntimeUnitError::IncompatibleLevels { from: LevelUnit::decibel_milliwatt, to: LevelUnit::decibel })));
+        assert!(matches!(gain.to_quantity(), Err(RuntimeUnitError::NoReference { unit: LevelUnit::decibel })));
+        assert!(matches!(Level::from_quantity(Power::watt(1.0), LevelUnit::decibel_volt), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(Level::from_power_ratio(2.0, LevelUnit::decibel_watt), Err(RuntimeUnitError::UnexpectedLevelKind { expected: LevelKind::Ratio, .. })));
+        assert!(matches!(ph.checked_add(ph), Err(RuntimeUnitError::IncompatibleLevels { .. })));
+        assert!(matches!(level.apply_gain(level), Err(RuntimeUnitError::UnexpectedLevelKind { .. })));
+        assert!(matches!(noise.apply_gain(ph), Err(RuntimeUnitError::UnexpectedLevelKind { .. })));
+        assert!(matches!(ph.apply_gain(gain), Err(RuntimeUnitError::IncompatibleLevels { .. })));
+        // Logarithms of quantities and ratios that are not positive are not defined
+        assert!(matches!(Level::from_quantity(Power::watt(0.0), LevelUnit::decibel_milliwatt), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert!(matches!(Level::from_quantity(Power::watt(-1.0), LevelUnit::decibel_milliwatt), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert!(matches!(Level::from_field_ratio(0.0, LevelUnit::decibel), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+
+        assert_eq!("94 dB SPL".parse::<Level>().unwrap(), Level::new(94.0, LevelUnit::decibel_sound_pressure_level));
+        assert_eq!("pH 7.4".parse::<Level>().unwrap(), Level::new(7.4, LevelUnit::ph));
+        assert!("3 dBx".parse::<Level>().is_err());
+        assert_eq!(Level::new(30.0, LevelUnit::decibel_milliwatt).to_string(), "30 dBm");
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Time", feature="ThermodynamicTemperature")))]
+    fn test_slice_conversion()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::slice::{convert_column_in_place, convert_slice_in_place, convert_slice_into, convert_strided_in_place, convert_strided_into};
+        use crate::traits::Unit;
+        use crate::units::{LengthUnit, ThermodynamicTemperatureUnit, TimeUnit};
+        use crate::Units;
+        let mut values = [1.0, 2.0, 10.0];
+        convert_slice_in_place(&mut values, Units::Length(LengthUnit::foot), Units::Length(LengthUnit::meter)).unwrap();
+        assert_eq!(values, [0.3048, 0.6096, 3.048]);
+        assert!(convert_slice_in_place(&mut values, LengthUnit::meter, TimeUnit::second).is_err());
+        assert_eq!(values, [0.3048, 0.6096, 3.048]);
+
+        let mut output = [0.0; 3];
+        convert_slice_into(&[0.0, 100.0, -40.0], &mut output, ThermodynamicTemperatureUnit::degree_celsius, ThermodynamicTemperatureUnit::degree_fahrenheit).unwrap();
+        assert!(output.iter().zip([32.0f64, 212.0, -40.0]).all(|(value, expected)| (value - expected).abs() < 1e-9));
+        assert!(matches!(convert_slice_into(&[1.0, 2.0], &mut output, LengthUnit::meter, LengthUnit::centimeter), Err(RuntimeUnitError::LengthMismatch { expected: 3, found: 2 })));
+
+        // Row-major table of lengths (m) and times (s)
+        let mut table = [1.0, 60.0, 2.0, 120.0, 3.0, 180.0];
+        convert_strided_in_place(&mut table, 0, 2, LengthUnit::meter, LengthUnit::centimeter).unwrap();
+        convert_strided_in_place(&mut table, 1, 2, TimeUnit::second, TimeUnit::minute).unwrap();
+        assert_eq!(table, [100.0, 1.0, 200.0, 2.0, 300.0, 3.0]);
+        let mut output = [0.0; 6];
+        convert_strided_into(&table, &mut output, 1, 2, TimeUnit::minute, TimeUnit::second).unwrap();
+        assert_eq!(output, [0.0, 60.0, 0.0, 120.0, 0.0, 180.0]);
+        assert!(matches!(convert_strided_in_place(&mut table, 0, 0, LengthUnit::meter, LengthUnit::meter), Err(RuntimeUnitError::InvalidStride)));
+
+        // Column-major matrix with 2 rows: a column of kilometers and a column of seconds
+        let mut matrix = [1.0f32, 2.0, 30.0, 60.0];
+        convert_column_in_place(&mut matrix, 2, 0, LengthUnit::kilometer, LengthUnit::meter).unwrap();
+        assert_eq!(matrix, [1000.0, 2000.0, 30.0, 60.0]);
+        assert!(matches!(convert_column_in_place(&mut matrix, 2, 2, LengthUnit::kilometer, LengthUnit::meter), Err(RuntimeUnitError::IndexOutOfBounds { index: 2, len: 2 })));
+        assert!(matches!(convert_column_in_place(&mut matrix, 2, usize::MAX, LengthUnit::kilometer, LengthUnit::meter), Err(RuntimeUnitError::IndexOutOfBounds { len: 2, .. })));
+        assert!(matches!(convert_column_in_place(&mut matrix, 0, 0, LengthUnit::kilometer, LengthUnit::meter), Err(RuntimeUnitError::IndexOutOfBounds { index: 0, len: 0 })));
+
+        // Integer values are left unchanged if any converted value cannot be represented
+        let mut values = [1i32, 1500];
+        assert!(matches!(convert_slice_in_place(&mut values, LengthUnit::meter, LengthUnit::kilometer), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert_eq!(values, [1, 1500]);
+        let mut output = [0u8; 2];
+        assert!(matches!(convert_slice_into(&[1u8, 3], &mut output, LengthUnit::meter, LengthUnit::centimeter), Err(RuntimeUnitError::ValueOutOfRange { value: 300.0 })));
+        let mut table = [1u8, 200, 2, 1];
+        assert!(matches!(convert_strided_in_place(&mut table, 1, 2, LengthUnit::meter, LengthUnit::decimeter), Err(RuntimeUnitError::ValueOutOfRange { value: 2000.0 })));
+        convert_strided_in_place(&mut table, 0, 2, LengthUnit::meter, LengthUnit::decimeter).unwrap();
+        assert_eq!(table, [10, 200, 20, 1]);
+        assert!(convert_strided_into(&table, &mut [0u8; 4], 1, 2, LengthUnit::meter, LengthUnit::decimeter).is_err());
+
+        let mut values = vec![1i64, 2, 3];
+        LengthUnit::kilometer.convert_slice_in_place(&mut values, LengthUnit::meter).unwrap();
+        assert_eq!(values, vec![1000, 2000, 3000]);
+        let mut bytes = [1u8, 3];
+        assert!(matches!(LengthUnit::meter.convert_slice_in_place(&mut bytes, LengthUnit::centimeter), Err(RuntimeUnitError::ValueOutOfRange { value: 300.0 })));
+        assert_eq!(bytes, [1, 3]);
+        assert!(matches!(LengthUnit::meter.convert_slice_into(&bytes, &mut [0u8; 2], LengthUnit::centimeter), Err(RuntimeUnitError::ValueOutOfRange { value: 300.0 })));
+        let mut output = [0.0; 2];
+        LengthUnit::inch.convert_slice_into(&[1.0, 2.0], &mut output, LengthUnit::centimeter).unwrap();
+        assert_eq!(output, [2.54, 5.08]);
+        assert!(matches!(LengthUnit::inch.convert_slice_into(&[1.0], &mut output, LengthUnit::centimeter), Err(RuntimeUnitError::LengthMismatch { expected: 2, found: 1 })));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Time")))]
+    fn test_quantity_slice()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::slice_quantity::{QuantitySlice, QuantitySliceMut};
+        use crate::units::{LengthUnit, TimeUnit};
+        use crate::{Length, LengthSlice, LengthSliceMut, LengthVec};
+        let buffer = [1.0, 2.0, 10.0];
+        let slice = QuantitySlice::new(&buffer, LengthUnit::foot.into());
+        assert_eq!(slice.len(), 3);
+        assert_eq!(slice.get(1).unwrap().value, 2.0);
+        assert!(slice.get(3).is_none());
+        assert_eq!(slice.iter().count(), 3);
+        let mut output = [0.0; 3];
+        slice.convert_into(&mut output, LengthUnit::meter.into()).unwrap();
+        assert_eq!(output, [0.3048, 0.6096, 3.048]);
+        assert!(slice.convert_into(&mut output, TimeUnit::second.into()).is_err());
+        let converted = slice.convert_to_vec(LengthUnit::meter.into()).unwrap();
+        assert_eq!(converted.values, vec![0.3048, 0.6096, 3.048]);
+        assert_eq!(slice.to_vec().values, buffer.to_vec());
+
+        let mut buffer = [1.0, 2.0, 10.0];
+        let mut slice = QuantitySliceMut::new(&mut buffer, LengthUnit::kilometer.into());
+        slice.try_convert_mut(LengthUnit::meter.into()).unwrap();
+        assert!(slice.try_convert_mut(TimeUnit::second.into()).is_err());
+        slice.set(0, Length::centimeter(50.0).into()).unwrap();
+        assert!(matches!(slice.set(3, Length::meter(1.0).into()), Err(RuntimeUnitError::IndexOutOfBounds { index: 3, len: 3 })));
+        assert!(slice.set(0, crate::Time::second(1.0).into()).is_err());
+        slice[1] = 3.0;
+        assert_eq!(slice.as_quantity_slice().values, &[0.5, 3.0, 10000.0]);
+        assert_eq!(buffer, [0.5, 3.0, 10000.0]);
+
+        // Typed views
+        let buffer = [1.0f32, 2.0];
+        let slice = LengthSlice::new(&buffer, LengthUnit::inch);
+        let lengths: Vec<Length<f32>> = slice.iter().collect();
+        assert_eq!(lengths[1].unit, LengthUnit::inch);
+        assert_eq!(slice.get(0).unwrap().value, 1.0);
+        let mut output = [0.0f32; 2];
+        slice.convert_into(&mut output, LengthUnit::centimeter).unwrap();
+        assert_eq!(output, [2.54, 5.08]);
+        assert_eq!(slice.convert_to_vec(LengthUnit::centimeter).values, vec![2.54f32, 5.08]);
+        assert_eq!(QuantitySlice::from(slice).unit, LengthUnit::inch.into());
+        assert!(LengthSlice::try_from(QuantitySlice::new(&buffer, TimeUnit::second.into())).is_err());
+
+        let mut buffer = vec![1.0, 2.0];
+        let mut slice = LengthSliceMut::new(&mut buffer, LengthUnit::meter);
+        slice.convert_mut(LengthUnit::centimeter);
+        slice.set(1, Length::meter(1.0)).unwrap();
+        assert!(matches!(slice.set(2, Length::meter(1.0)), Err(RuntimeUnitError::IndexOutOfBounds { index: 2, len: 2 })));
+        assert_eq!(slice.unit(), LengthUnit::centimeter);
+        assert_eq!(slice.get(1).unwrap().value, 100.0);
+        assert_eq!(buffer, vec![100.0, 100.0]);
+
+        // Integer views check that converted values are representable
+        let mut bytes = [1u8, 2];
+        let mut slice = LengthSliceMut::new(&mut bytes, LengthUnit::meter);
+        assert!(matches!(slice.try_convert_mut(LengthUnit::millimeter), Err(RuntimeUnitError::ValueOutOfRange { value: 1000.0 })));
+        assert_eq!(slice.unit(), LengthUnit::meter);
+        assert!(matches!(slice.set(0, Length::new(3u8, LengthUnit::kilometer)), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        slice.try_convert_mut(LengthUnit::decimeter).unwrap();
+        assert!(matches!(slice.as_quantity_slice().convert_into(&mut [0u8; 2], LengthUnit::millimeter), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert_eq!(bytes, [10, 20]);
+        let mut integers = [1i32, 2];
+        let mut slice = QuantitySliceMut::new(&mut integers, LengthUnit::meter.into());
+        assert!(matches!(slice.try_convert_mut(LengthUnit::kilometer.into()), Err(RuntimeUnitError::ValueOutOfRange { .. })));
+        assert_eq!(integers, [1, 2]);
+
+        let lengths = LengthVec::new(vec![1.0, 2.0], LengthUnit::meter);
+        assert_eq!(lengths.as_quantity_slice().values(), &[1.0, 2.0]);
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Time", feature="Pressure", feature="ThermodynamicTemperature", feature="Force", feature="Velocity", feature="Power")))]
+    fn test_quantity_table()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::traits::ArbitraryQuantity;
+        use crate::units::{ForceUnit, PowerUnit, PressureUnit, ThermodynamicTemperatureUnit, TimeUnit, VelocityUnit};
+        use crate::vector_quantity::VecQuantity;
+        use crate::{Quantities, QuantitiesVec, QuantityTable, Units};
+        let mut table = QuantityTable::new()
+            .with_quantities("time", QuantitiesVec::new(vec![0.0, 500.0], Units::Time(TimeUnit::millisecond))).unwrap()
+            .with_column("pressure", VecQuantity { unit: PressureUnit::psi.into(), values: vec![1.0, 2.0] }).unwrap()
+            .with_column("temperature", VecQuantity { unit: ThermodynamicTemperatureUnit::degree_celsius.into(), values: vec![20.0, 25.0] }).unwrap();
+        assert_eq!(table.row_count(), 2);
+        assert_eq!(table.column_count(), 3);
+        assert_eq!(table.names().collect::<Vec<_>>(), vec!["time", "pressure", "temperature"]);
+        assert!(matches!(table.push_column("time", VecQuantity { unit: TimeUnit::second.into(), values: vec![0.0, 1.0] }), Err(RuntimeUnitError::DuplicateColumn { name }) if name == "time"));
+        assert!(matches!(table.push_column("short", VecQuantity { unit: TimeUnit::second.into(), values: vec![0.0] }), Err(RuntimeUnitError::RowCountMismatch { expected: 2, found: 1, .. })));
+
+        let row = table.row(1).unwrap();
+        assert_eq!(row[0].value(), 500.0);
+        assert_eq!(row[1].unit(), PressureUnit::psi.into());
+        assert!(table.row(2).is_none());
+        assert_eq!(table.rows().count(), 2);
+        let row = table.try_row_quantities(1).unwrap();
+        assert!(matches!(row[0], Quantities::Time(_)));
+        assert!(matches!(row[2], Quantities::ThermodynamicTemperature(_)));
+        assert!(matches!(table.try_row_quantities(2), Err(RuntimeUnitError::IndexOutOfBounds { index: 2, len: 2 })));
+
+        assert_eq!(table.named_unit("time"), Some(Units::Time(TimeUnit::millisecond)));
+        assert_eq!(table.named_unit("pressure"), None);
+        table.try_convert_column("time", TimeUnit::second.into()).unwrap();
+        assert_eq!(table.named_unit("time"), None);
+        assert_eq!(table.column("time").unwrap().values, vec![0.0, 0.5]);
+        assert!(table.try_convert_column("time", PressureUnit::pascal.into()).is_err());
+        assert!(matches!(table.try_convert_column("missing", TimeUnit::second.into()), Err(RuntimeUnitError::UnknownColumn { name }) if name == "missing"));
+
+        table.convert_to_system(&[PressureUnit::kilopascal.into(), ThermodynamicTemperatureUnit::kelvin.into()]);
+        assert_eq!(table.unit("time"), Some(TimeUnit::second.into()));
+        assert!((table.column("pressure").unwrap().values[0] - 6.894757889515779).abs() < 1e-12);
+        assert!((table.column("temperature").unwrap().values[1] - 298.15).abs() < 1e-12);
+        table.convert_to_base();
+        assert!((table.column("pressure").unwrap().values[1] - 13789.515779031558).abs() < 1e-9);
+
+        let mut table = QuantityTable::new()
+            .with_column("force", VecQuantity { unit: ForceUnit::kilonewton.into(), values: vec![1.0, 2.0] }).unwrap()
+            .with_column("velocity", VecQuantity { unit: VelocityUnit::meter_per_second.into(), values: vec![3.0, 4.0] }).unwrap();
+        table.derive_product("power", "force", "velocity").unwrap();
+        let power = table.column("power").unwrap().try_convert(PowerUnit::watt.into()).unwrap();
+        assert_eq!(power.values, vec![3000.0, 8000.0]);
+        table.derive_quotient("time", "power", "power").unwrap();
+        assert_eq!(table.column("time").unwrap().values, vec![1.0, 1.0]);
+        assert!(table.derive_sum("sum", "force", "velocity").is_err());
+        table.derive_difference("difference", "force", "force").unwrap();
+        assert_eq!(table.column("difference").unwrap().values, vec![0.0, 0.0]);
+        assert!(matches!(table.derive_product("missing", "force", "missing"), Err(RuntimeUnitError::UnknownColumn { .. })));
+        assert!(table.remove_column("time").is_some());
+        assert_eq!(table.column_count(), 4);
+
+        #[cfg(feature="serde")]
+        {
+            let json = serde_json::to_value(&table).unwrap();
+            let read: QuantityTable = serde_json::from_value(json.clone()).unwrap();
+            assert_eq!(read.names().collect::<Vec<_>>(), table.names().collect::<Vec<_>>());
+            assert_eq!(read.column("power").unwrap().values, table.column("power").unwrap().values);
+            let mut names = json.clone();
+            names["names"][1] = "force".into();
+            assert!(serde_json::from_value::<QuantityTable>(names).is_err());
+            let mut names = json.clone();
+            names["names"].as_array_mut().unwrap().pop();
+            assert!(serde_json::from_value::<QuantityTable>(names).is_err());
+            let mut rows = json;
+            rows["columns"][1]["values"].as_array_mut().unwrap().pop();
+            assert!(serde_json::from_value::<QuantityTable>(rows).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Time", feature="Pressure", feature="TemperatureInterval", feature="ThermodynamicTemperature", feature="VolumeRate", feature="Power", feature="Length", feature="Dimensionless", feature="Frequency")))]
+    fn test_csv()
+    {
+        use crate::csv::{parse_header, CsvReader, CsvWriter, HeaderStyle};
+        use crate::errors::RuntimeUnitError;
+        use crate::traits::ArbitraryQuantity;
+        use crate::units::{DimensionlessUnit, PressureUnit, ThermodynamicTemperatureUnit, TimeUnit, VolumeRateUnit};
+        use crate::units_base::UnitDefinition;
+        use crate::{QuantitiesVec, Units};
+        assert_eq!(parse_header("time [s]").unwrap(), ("time".to_owned(), TimeUnit::second.into()));
+        assert_eq!(parse_header(" pressure (kPa) ").unwrap(), ("pressure".to_owned(), PressureUnit::kilopascal.into()));
+        assert_eq!(parse_header("flow_rate/L·min⁻¹").unwrap().1, crate::parser::parse_unit("L/min").unwrap());
+        assert_eq!(parse_header("conductivity (W/(m·K))").unwrap().0, "conductivity");
+        assert_eq!(parse_header("count").unwrap(), ("count".to_owned(), UnitDefinition::dimensionless()));
+        assert!(parse_header("time [furlongs per fortnight]").is_err());
+        assert_eq!(parse_header("time [h]").unwrap().1, TimeUnit::hour.into());
+        assert!(matches!(parse_header("information [b]"), Err(RuntimeUnitError::AmbiguousUnit { .. })));
+        assert_eq!(parse_header("a/b").unwrap(), ("a/b".to_owned(), UnitDefinition::dimensionless()));
+        assert!(parse_header("temperature (°C)").unwrap().1.is_absolute());
+        assert!(parse_header("temperature [K]").unwrap().1.is_absolute());
+
+        let input = "time [ms];\"pressure (psi)\";temperature (°C);flow_rate/L·min⁻¹\r\n0;14.5;20;60\r\n500;;25;120\n\n";
+        let columns = CsvReader::new().with_delimiter(';').read_columns_str(input).unwrap();
+        assert_eq!(columns.len(), 4);
+        assert_eq!(columns[0].0, "time");
+        assert_eq!(columns[0].1.unit(), Units::Time(TimeUnit::millisecond));
+        assert_eq!(columns[1].1.unit(), Units::Pressure(PressureUnit::psi));
+        assert_eq!(columns[2].1.unit(), Units::ThermodynamicTemperature(ThermodynamicTemperatureUnit::degree_celsius));
+        assert!(columns[1].1.values()[1].is_nan());
+        let flow = crate::vector_quantity::VecQuantity::from(columns[3].1.clone()).try_convert(VolumeRateUnit::liter_per_second.into()).unwrap();
+        assert!((flow.values[1] - 2.0).abs() < 1e-12);
+        assert!(CsvReader::new().read_columns_str("time [s],length [m]\n1,2\n3\n").is_err());
+        assert!(CsvReader::new().read_columns_str("time [s]\nabc\n").is_err());
+        assert!(CsvReader::new().read_columns_str("").is_err());
+        let columns = CsvReader::new().read_columns_str("x,rate [1/s]\n1,2\n").unwrap();
+        assert_eq!(columns[0].1.unit(), Units::Dimensionless(DimensionlessUnit::scalar));
+        assert_eq!(columns[1].1.unit(), Units::Frequency(crate::units::FrequencyUnit::hertz));
+
+        let table = CsvReader::new().with_delimiter(';').read_table_str(input).unwrap();
+        assert_eq!(table.named_unit("pressure"), Some(Units::Pressure(PressureUnit::psi)));
+        let output = CsvWriter::new().write_table_string(&table).unwrap();
+        assert_eq!(output.lines().next().unwrap(), "time [ms],pressure [psi],temperature [°C],flow_rate [L·min⁻¹]");
+        assert!(output.lines().nth(2).unwrap().ends_with(",120"));
+        assert_eq!(CsvWriter::new().write_table_string(&CsvReader::new().read_table_str(&output).unwrap()).unwrap(), output);
+        let output = CsvWriter::new().with_header_style(HeaderStyle::Slash).with_base_units()
+            .with_column_unit("time", TimeUnit::second).with_column_unit("flow_rate", VolumeRateUnit::liter_per_second)
+            .write_table_string(&table).unwrap();
+        let mut lines = output.lines();
+        assert_eq!(lines.next().unwrap(), "time/s,pressure/Pa,temperature/K,flow_rate/L/s");
+        assert!(lines.nth(1).unwrap().starts_with("0.5,,298.15,2"));
+        assert!(CsvWriter::new().with_column_unit("time", PressureUnit::pascal).write_table_string(&table).is_err());
+        let read = CsvReader::new().read_table_str(&CsvWriter::new().with_base_units().write_table_string(&table).unwrap()).unwrap();
+        assert!(read.unit("temperature").unwrap().is_absolute());
+        assert!((read.column("temperature").unwrap().values[0] - 293.15).abs() < 1e-9);
+
+        // Units without a name, and columns without a unit, are written back unchanged
+        let input = "x,rate [1/s],a/b\n1,2,3\n";
+        let unnamed = CsvReader::new().read_table_str(input).unwrap();
+        assert_eq!(unnamed.unit_expression("rate"), Some("1/s"));
+        assert_eq!(CsvWriter::new().write_table_string(&unnamed).unwrap(), input);
+
+        // Round trip, with quoted headers
+        let output = CsvWriter::new().with_header_style(HeaderStyle::Parentheses).with_column_unit("pressure", PressureUnit::psi).write_table_string(&table).unwrap();
+        let table = CsvReader::new().read_table_str(&output).unwrap();
+        assert_eq!(table.unit("pressure"), Some(PressureUnit::psi.into()));
+        assert_eq!(table.unit("temperature"), Some(ThermodynamicTemperatureUnit::degree_celsius.into()));
+        assert_eq!(table.column("temperature").unwrap().values, vec![20.0, 25.0]);
+        assert_eq!(table.column("time").unwrap().values, vec![0.0, 500.0]);
+        let table = crate::QuantityTable::new().with_column("a,\"b\"", QuantitiesVec::new(vec![1.0], Units::Time(TimeUnit::second))).unwrap();
+        let output = CsvWriter::new().write_table_string(&table).unwrap();
+        assert_eq!(output, "\"a,\"\"b\"\" [s]\"\n1\n");
+        assert_eq!(CsvReader::new().read_table_str(&output).unwrap().names().next(), Some("a,\"b\""));
+    }
+
+    #[test]
+    #[cfg(all(feature="arrow", any(feature="All", all(feature="Time", feature="Length", feature="TemperatureInterval", feature="ThermodynamicTemperature"))))]
+    fn test_arrow()
+    {
+        use crate::arrow::{field_unit, unit_from_metadata, unit_to_metadata, UNIT_METADATA_KEY};
+        use crate::errors::RuntimeUnitError;
+        use crate::units::{LengthUnit, TemperatureIntervalUnit, ThermodynamicTemperatureUnit, TimeUnit};
+        use crate::units_base::UnitDefinition;
+        use crate::vector_quantity::VecQuantity;
+        use crate::{QuantitiesVec, QuantityTable, Units};
+        use arrow_array::{Array, Float64Array, Int32Array};
+        use arrow_schema::{DataType, Field};
+        let unit = Units::TemperatureInterval(TemperatureIntervalUnit::degree_celsius);
+        assert_eq!(unit.canonical_name(), "TemperatureInterval::degree_celsius");
+        assert_eq!(Units::from_canonical_name("TemperatureInterval::degree_celsius").unwrap(), unit);
+        assert!(Units::from_canonical_name("TemperatureInterval::furlong").is_err());
+        assert!(Units::from_canonical_name("degree_celsius").is_err());
+
+        // Exact round trip of `Units`, including units sharing an abbreviation
+        let temperatures = QuantitiesVec::new(vec![20.0, 25.0], unit);
+        let (field, array) = temperatures.to_arrow("temperature");
+        assert_eq!(field.data_type(), &DataType::Float64);
+        assert_eq!(field.metadata()[UNIT_METADATA_KEY], "TemperatureInterval::degree_celsius");
+        let read = QuantitiesVec::try_from_arrow(&field, &array).unwrap();
+        assert_eq!(read.unit(), unit);
+        assert_eq!(read.values(), vec![20.0, 25.0]);
+        let absolute = QuantitiesVec::new(vec![20.0], Units::ThermodynamicTemperature(ThermodynamicTemperatureUnit::degree_celsius));
+        let (field, array) = absolute.to_arrow("temperature");
+        assert_eq!(QuantitiesVec::try_from_arrow(&field, &array).unwrap().unit(), absolute.unit());
+
+        // f32 values, and units without a name
+        let lengths = VecQuantity { unit: LengthUnit::foot.into(), values: vec![1.0f32, 2.0] };
+        let (field, array) = lengths.to_arrow("length");
+        assert_eq!(field.data_type(), &DataType::Float32);
+        assert_eq!(VecQuantity::<f32>::try_from_arrow(&field, &array).unwrap().values, vec![1.0, 2.0]);
+        assert_eq!(VecQuantity::<f64>::try_from_arrow(&field, &array).unwrap().unit, LengthUnit::foot.into());
+        let unnamed = UnitDefinition::new(1234.5, 1, 0, 0, 0, 0, 0, 0);
+        assert_eq!(unit_to_metadata(unnamed), "1234.5 m");
+        assert_eq!(unit_from_metadata("1234.5 m").unwrap(), unnamed);
+        let percent = UnitDefinition::new(0.01, 0, 0, 0, 0, 0, 0, 0);
+        assert_eq!(unit_from_metadata(&unit_to_metadata(percent)).unwrap(), percent);
+        let (field, array) = VecQuantity { unit: unnamed, values: vec![1.0] }.to_arrow("length");
+        let read = QuantitiesVec::try_from_arrow(&field, &array).unwrap();
+        assert_eq!(read.unit(), Units::Length(LengthUnit::meter));
+        assert_eq!(read.values(), vec![1234.5]);
+
+        // Nulls, missing metadata and other array types
+        let field = Field::new("time", DataType::Float64, true).with_metadata([(UNIT_METADATA_KEY.to_owned(), "s".to_owned())].into());
+        let array = Float64Array::from(vec![Some(1.0), None]);
+        let read = VecQuantity::<f64>::try_from_arrow(&field, &array).unwrap();
+        assert_eq!(field_unit(&field).unwrap(), TimeUnit::second.into());
+        assert!(read.values[1].is_nan());
+        assert!(matches!(VecQuantity::<f64>::try_from_arrow(&Field::new("time", DataType::Float64, false), &array), Err(RuntimeUnitError::MissingUnit { field }) if field == "time"));
+        assert!(matches!(VecQuantity::<f64>::try_from_arrow(&field, &Int32Array::from(vec![1])), Err(RuntimeUnitError::UnsupportedType { .. })));
+        // NaN values are written as nulls of nullable fields
+        let (field, array) = read.to_arrow("time");
+        assert!(field.is_nullable());
+        assert_eq!((array.null_count(), array.value(0)), (1, 1.0));
+        let (field, array) = QuantitiesVec::new(vec![f64::NAN], Units::Time(TimeUnit::second)).to_arrow("time");
+        assert!(field.is_nullable() && array.is_null(0));
+
+        // Tables as record batches
+        let table = QuantityTable::new()
+            .with_column("time", QuantitiesVec::new(vec![0.0, 0.5], Units::Time(TimeUnit::millisecond))).unwrap()
+            .with_column("temperature", temperatures).unwrap();
+        let batch = table.to_record_batch();
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.num_rows(), 2);
+        let read = QuantityTable::try_from_record_batch(&batch).unwrap();
+        assert_eq!(read.names().collect::<Vec<_>>(), vec!["time", "temperature"]);
+        assert_eq!(read.unit("time"), Some(TimeUnit::millisecond.into()));
+        assert_eq!(read.column("temperature").unwrap().values, vec![20.0, 25.0]);
+        assert_eq!(QuantityTable::<f64>::new().to_record_batch().num_columns(), 0);
+    }
+
+    #[test]
+    #[cfg(all(feature="ndarray", any(feature="All", all(feature="Length", feature="Time", feature="Pressure", feature="Force", feature="Area", feature="ThermodynamicTemperature"))))]
+    fn test_quantity_nd()
+    {
+        use crate::errors::RuntimeUnitError;
+        use crate::traits::{ArbitraryQuantity, CheckedAdditive};
+        use crate::units::{AreaUnit, ForceUnit, LengthUnit, PressureUnit, ThermodynamicTemperatureUnit, TimeUnit, TemperatureIntervalUnit};
+        use crate::{Area, Length, Pressure, QuantityNd};
+        use ndarray::{array, Array3};
+        let mut pressure = QuantityNd::new(Array3::from_elem((2, 3, 4), 1.0), PressureUnit::bar.into());
+        pressure[[1, 2, 3]] = 2.0;
+        assert_eq!(pressure.get([1, 2, 3]).unwrap().value(), 2.0);
+        assert!(pressure.get([2, 0, 0]).is_none());
+        let value: Pressure = pressure.try_get_as([1, 2, 3], PressureUnit::kilopascal).unwrap();
+        assert!((value.value() - 200.0).abs() < 1e-9);
+        assert!(matches!(pressure.try_get_as::<_, _, Length>([0, 0, 0], LengthUnit::meter), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(pressure.try_get_as::<_, _, Pressure>([1, 3, 0], PressureUnit::pascal), Err(RuntimeUnitError::IndexOutOfBounds { index: 3, len: 3 })));
+        assert_eq!(pressure.iter().count(), 24);
+
+        // In-place conversion, of contiguous and non-contiguous arrays
+        pressure.try_convert_mut(PressureUnit::kilopascal.into()).unwrap();
+        assert!((pressure[[0, 0, 0]] - 100.0).abs() < 1e-9);
+        assert!(pressure.try_convert_mut(TimeUnit::second.into()).is_err());
+        let mut transposed = QuantityNd::new(array![[1.0, 2.0], [3.0, 4.0]].reversed_axes(), LengthUnit::kilometer.into());
+        transposed.convert_mut(LengthUnit::meter.into());
+        assert_eq!(transposed.values, array![[1000.0, 3000.0], [2000.0, 4000.0]]);
+
+        // Broadcasting products combine units
+        let areas = QuantityNd::new(array![[1.0], [2.0]], AreaUnit::square_meter.into());
+        assert!(matches!(pressure.checked_mul(&areas), Err(RuntimeUnitError::ShapeMismatch { lhs, rhs }) if lhs == [2, 3, 4] && rhs == [2, 1]));
+        let grid = QuantityNd::new(Array3::from_elem((2, 3, 1), 2.0), PressureUnit::kilopascal.into());
+        let force = grid.clone() * QuantityNd::new(array![1.0, 2.0], AreaUnit::square_meter.into());
+        assert_eq!(force.shape(), &[2, 3, 2]);
+        assert!(force.unit.is_convertible(ForceUnit::newton.into()));
+        assert!((force.try_convert(ForceUnit::kilonewton.into()).unwrap()[[0, 0, 1]] - 4.0).abs() < 1e-12);
+        let ratio = force.clone() / force;
+        assert_eq!(ratio.unit.unit_string(), "dimensionless");
+        let force = grid.clone() * Area::square_meter(2.0);
+        assert!((force.try_convert(ForceUnit::newton.into()).unwrap()[[1, 2, 0]] - 4000.0).abs() < 1e-9);
+        let mut scaled = grid.clone() * 2.0 / 4.0;
+        scaled /= QuantityNd::new(array![2.0], TimeUnit::second.into());
+        assert_eq!(scaled[[0, 0, 0]], 0.5);
+
+        // Unit-checked sums broadcast the right-hand side
+        let mut sum = grid.clone().checked_add(QuantityNd::new(array![1.0], PressureUnit::bar.into())).unwrap();
+        assert!((sum[[1, 1, 0]] - 102.0).abs() < 1e-9);
+        assert!(matches!(sum.clone().checked_add(QuantityNd::new(array![1.0], TimeUnit::second.into())), Err(RuntimeUnitError::IncompatibleDimensions { .. })));
+        assert!(matches!(sum.clone().checked_add(QuantityNd::new(array![1.0, 2.0, 3.0, 4.0], PressureUnit::bar.into())), Err(RuntimeUnitError::ShapeMismatch { .. })));
+        sum -= grid.clone();
+        assert!((sum[[0, 0, 0]] - 100.0).abs() < 1e-9);
+        let celsius = QuantityNd::new(array![20.0, 30.0], ThermodynamicTemperatureUnit::degree_celsius.into());
+        let difference = celsius.clone() - QuantityNd::new(array![273.15], ThermodynamicTemperatureUnit::kelvin.into());
+        assert_eq!(difference.unit, TemperatureIntervalUnit::degree_celsius.into());
+        assert!((difference[1] - 30.0).abs() < 1e-9);
+        let warmer = QuantityNd::new(array![10.0, 10.0], TemperatureIntervalUnit::kelvin.into()).checked_add(celsius.clone()).unwrap();
+        assert_eq!(warmer.unit, ThermodynamicTemperatureUnit::degree_celsius.into());
+        assert!((warmer[0] - 30.0).abs() < 1e-9);
+        assert!(matches!(celsius.clone().checked_add(celsius), Err(RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+    }
+
+    #[test]
+    #[cfg(any(feature="All", all(feature="Length", feature="Force", feature="Torque", feature="Velocity", feature="Time", feature="Energy", feature="ThermodynamicTemperature")))]
+    fn test_vector3_quantity()
+    {
+        use crate::traits::CheckedAdditive;
+        use crate::units::{ForceUnit, LengthUnit, TorqueUnit, VelocityUnit};
+        use crate::units_base::UnitDefinition;
+        use crate::{Energy, Force, ForceVector3, Length, LengthVector3, Quantity, Time, TorqueVector3, Vector3Quantity, VelocityVector3};
+        let position = LengthVector3::new([0.5, 0.0, 0.0], LengthUnit::meter);
+        let force = ForceVector3::new([0.0, 10.0, 0.0], ForceUnit::newton);
+
+        // Cross products derive the units of both operands
+        let torque = TorqueVector3::try_from(position.cross(force)).unwrap();
+        assert_eq!(torque.unit(), TorqueUnit::newton_meter);
+        assert_eq!(torque.value(), [0.0, 0.0, 5.0]);
+        assert_eq!(format!("{torque}"), "(0, 0, 5) N · m");
+
+        // Dot products are scalar quantities
+        let displacement = LengthVector3::new([3.0, 4.0, 0.0], LengthUnit::meter);
+        let work = force.dot(displacement);
+        assert_eq!(work.unit.base, UnitDefinition::from(crate::units::EnergyUnit::joule).base);
+        assert_eq!(Energy::from(work), Energy::joule(40.0));
+
+        // Norms are typed scalars
+        assert_eq!(displacement.norm(), Length::meter(5.0));
+        assert_eq!(force.y(), Force::newton(10.0));
+        let components = LengthVector3::from_components(Length::meter(1.0), Length::centimeter(200.0), Length::millimeter(3000.0));
+        assert_eq!(components.value(), [1.0, 2.0, 3.0]);
+
+        // Conversions and arithmetic
+        let centimeters = displacement.convert(LengthUnit::centimeter);
+        assert_eq!(centimeters.value(), [300.0, 400.0, 0.0]);
+        assert_eq!((displacement + centimeters).value(), [6.0, 8.0, 0.0]);
+        assert_eq!((-displacement * 2.0).value(), [-6.0, -8.0, 0.0]);
+        let velocity = VelocityVector3::try_from(displacement / Time::second(2.0)).unwrap();
+        assert_eq!(velocity.unit(), VelocityUnit::meter_per_second);
+        assert_eq!(velocity.norm(), crate::Velocity::meter_per_second(2.5));
+
+        // Generic vector quantities
+        let a = Vector3Quantity::new([1.0, 2.0, 3.0], LengthUnit::meter.into());
+        let b = Vector3Quantity::from_components(Length::centimeter(100.0), Length::meter(0.0), Length::meter(-1.0)).unwrap();
+        assert_eq!(b.unit(), LengthUnit::centimeter.into());
+        assert_eq!(b.value(), [100.0, 0.0, -100.0]);
+        assert_eq!(a.dot(b), Quantity::new(-200.0, UnitDefinition::from(LengthUnit::meter) * LengthUnit::centimeter.into()));
+        assert_eq!((a + b).value(), [2.0, 2.0, 2.0]);
+        assert_eq!(a.try_convert(LengthUnit::centimeter.into()).unwrap().value(), [100.0, 200.0, 300.0]);
+        assert!(a.try_convert(ForceUnit::newton.into()).is_err());
+        assert!(matches!(a.checked_add(Vector3Quantity::from(force)), Err(crate::errors::RuntimeUnitError::IncompatibleDimensions { .. })));
+        let celsius = Vector3Quantity::new([20.0, 0.0, 0.0], crate::units::ThermodynamicTemperatureUnit::degree_celsius.into());
+        assert!(matches!(celsius.checked_add(celsius), Err(crate::errors::RuntimeUnitError::AbsoluteRelativeMismatch { .. })));
+        assert!((celsius.dot(a).value() - 1658.9).abs() < 1e-9);
+        assert!((celsius.cross(a).value()[2] - 313.15).abs() < 1e-9);
+        assert_eq!(LengthVector3::new([1.0, 2.0, 3.0], LengthUnit::meter), LengthVector3::new([100.0, 200.0, 300.0], LengthUnit::centimeter));
+        assert_ne!(LengthVector3::new([1.0, 2.0, 3.0], LengthUnit::meter), LengthVector3::new([1.0, 2.0, 3.0], LengthUnit::centimeter));
+        assert!(Vector3Quantity::from_components(Length::meter(1.0), Force::newton(1.0), Length::meter(1.0)).is_err());
+        assert!(TorqueVector3::try_from(a).is_err());
+        assert_eq!(format!("{}", Vector3Quantity::from(force)), format!("(0, 10, 0) {}", UnitDefinition::from(ForceUnit::newton)));
+
+        #[cfg(feature="nalgebra")]
+        {
+            let vector = position.to_nalgebra();
+            assert_eq!(vector, nalgebra::Vector3::new(0.5, 0.0, 0.0));
+            assert_eq!(LengthVector3::from_nalgebra(vector.cross(&force.to_nalgebra()), LengthUnit::meter).value(), [0.0, 0.0, 5.0]);
+            assert_eq!(Vector3Quantity::from_nalgebra(vector, LengthUnit::meter.into()).value(), [0.5, 0.0, 0.0]);
+        }
+
+        #[cfg(feature="glam")]
+        {
+            let vector = force.to_glam();
+            assert_eq!(vector, glam::DVec3::new(0.0, 10.0, 0.0));
+            assert_eq!(ForceVector3::from_glam(vector * 2.0, ForceUnit::kilonewton).y(), Force::kilonewton(20.0));
+            assert_eq!(Vector3Quantity::from(position).to_glam().length(), 0.5);
+        }
+    }
+}
@@ -1,13 +1,172 @@
-use crate::{errors::RuntimeUnitError, units_base::{UnitBase, UnitDefinition}, Units};
-use core::ops::{Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign };
+use crate::{errors::RuntimeUnitError, units_base::{QuantityKind, UnitDefinition}, Units};
+use core::fmt::{Debug, Display};
+use core::ops::{Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign };
 
 
 pub(crate) trait IsScalarQuantity
 {
-    fn value(&self) -> f64;
+    type Value;
+    fn value(&self) -> Self::Value;
     fn unit(&self) -> UnitDefinition;
 }
 
+pub(crate) trait IsComplexQuantity
+{
+    fn complex_value(&self) -> crate::complex_quantity::Complex;
+    fn unit(&self) -> UnitDefinition;
+}
+
+///
+/// Numeric type storing the values of quantities (`f64` by default, but also `f32`, integers or a user type such as a 
+/// decimal). Conversions between units still scale values by the `f64` multipliers of their `UnitDefinition`: implementations
+/// only define how to apply such factors (e.g. rounding for integers).
+/// 
+pub trait Numeric where Self: Copy + PartialOrd + Debug + Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// Convert from an `f64` (e.g. a conversion factor). Integers round to the nearest value and saturate at the bounds of
+    /// their type (NaN is 0), like `as`; see `try_from_f64` for a checked conversion.
+    fn from_f64(value: f64) -> Self;
+    /// Convert from an `f64`, returning `None` if `value` is out of range (or, for integers, not a whole number).
+    #[inline]
+    fn try_from_f64(value: f64) -> Option<Self>
+    {
+        Some(Self::from_f64(value))
+    }
+    /// Convert to an `f64`, possibly losing precision.
+    fn to_f64(self) -> f64;
+    /// Multiply by an `f64` factor.
+    #[inline]
+    fn mul_f64(self, rhs: f64) -> Self
+    {
+        self * Self::from_f64(rhs)
+    }
+    /// Divide by an `f64` factor.
+    #[inline]
+    fn div_f64(self, rhs: f64) -> Self
+    {
+        self / Self::from_f64(rhs)
+    }
+    /// Compute `self * scale + shift`, e.g. to convert a value to another unit (see `UnitDefinition::affine_conversion_unchecked`).
+    #[inline]
+    fn scale(self, scale: f64, shift: f64) -> Self
+    {
+        self.mul_f64(scale) + Self::from_f64(shift)
+    }
+    /// Compute `self * scale + shift`, returning `None` if the result cannot be represented (see `Numeric::try_from_f64`).
+    #[inline]
+    fn try_scale(self, scale: f64, shift: f64) -> Option<Self>
+    {
+        Some(self.scale(scale, shift))
+    }
+    /// Compute `self + rhs`, returning `None` if the result cannot be represented (e.g. it overflows an integer type).
+    #[inline]
+    fn try_add(self, rhs: Self) -> Option<Self>
+    {
+        Some(self + rhs)
+    }
+    /// Compute `self - rhs`, returning `None` if the result cannot be represented (e.g. it overflows an integer type).
+    #[inline]
+    fn try_sub(self, rhs: Self) -> Option<Self>
+    {
+        Some(self - rhs)
+    }
+}
+
+impl Numeric for f64
+{
+    #[inline]
+    fn from_f64(value: f64) -> Self
+    {
+        value
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64
+    {
+        self
+    }
+}
+
+/// Implement `Numeric` for primitive types by computing in `f64`, rounding integers to the nearest value.
+/// `from_f64`, and so `scale` and unchecked conversions, silently saturate out-of-range values (e.g. 300 as `u8` is 255).
+/// `try_from_f64` rejects values outside of the range of the type and, for integers, values that are not whole numbers,
+/// and integers are added and subtracted with their checked operations.
+macro_rules! impl_numeric {
+    ($($numeric:ty: $round:expr, $integer:literal $(, $checked_add:path, $checked_sub:path)?);+) =>
+    {
+        $(
+            impl Numeric for $numeric
+            {
+                #[inline]
+                fn from_f64(value: f64) -> Self
+                {
+                    $round(value) as $numeric
+                }
+
+                #[inline]
+                fn try_from_f64(value: f64) -> Option<Self>
+                {
+                    let rounded = $round(value);
+                    // Allow for the rounding errors of conversion factors, e.g. 3 km is 2999.9999999999995 m.
+                    let exact = !$integer || (value - rounded).abs() <= 1e-9 * value.abs().max(1.0);
+                    let in_range = !value.is_finite() || (rounded >= <$numeric>::MIN as f64 && rounded < <$numeric>::MAX as f64 + 1.0);
+                    (exact && in_range && (value.is_finite() || !$integer)).then(|| rounded as $numeric)
+                }
+
+                #[inline]
+                fn to_f64(self) -> f64
+                {
+                    self as f64
+                }
+
+                #[inline]
+                fn mul_f64(self, rhs: f64) -> Self
+                {
+                    Self::from_f64(self as f64 * rhs)
+                }
+
+                #[inline]
+                fn div_f64(self, rhs: f64) -> Self
+                {
+                    Self::from_f64(self as f64 / rhs)
+                }
+
+                #[inline]
+                fn scale(self, scale: f64, shift: f64) -> Self
+                {
+                    Self::from_f64(self as f64 * scale + shift)
+                }
+
+                #[inline]
+                fn try_scale(self, scale: f64, shift: f64) -> Option<Self>
+                {
+                    Self::try_from_f64(self as f64 * scale + shift)
+                }
+                $(
+                    #[inline]
+                    fn try_add(self, rhs: Self) -> Option<Self>
+                    {
+                        $checked_add(self, rhs)
+                    }
+
+                    #[inline]
+                    fn try_sub(self, rhs: Self) -> Option<Self>
+                    {
+                        $checked_sub(self, rhs)
+                    }
+                )?
+            }
+        )+
+    };
+}
+impl_numeric!(f32: core::convert::identity, false;
+    i8: f64::round, true, i8::checked_add, i8::checked_sub; i16: f64::round, true, i16::checked_add, i16::checked_sub;
+    i32: f64::round, true, i32::checked_add, i32::checked_sub; i64: f64::round, true, i64::checked_add, i64::checked_sub;
+    i128: f64::round, true, i128::checked_add, i128::checked_sub; isize: f64::round, true, isize::checked_add, isize::checked_sub;
+    u8: f64::round, true, u8::checked_add, u8::checked_sub; u16: f64::round, true, u16::checked_add, u16::checked_sub;
+    u32: f64::round, true, u32::checked_add, u32::checked_sub; u64: f64::round, true, u64::checked_add, u64::checked_sub;
+    u128: f64::round, true, u128::checked_add, u128::checked_sub; usize: f64::round, true, usize::checked_add, usize::checked_sub);
+
 ///
 /// Trait that implements conversion of an arbitrary quantity into another
 /// 
@@ -25,28 +184,48 @@ pub trait ArbitraryQuantity where Self: Sized + Div<f64> + Mul<f64> + DivAssign<
     {
         self.try_convert(unit.into())
     }
+    #[inline]
+    /// Try to convert from this unit to the unit given by an expression such as `"km/h"` or a registered unit name (creates a copy)
+    fn try_convert_str(&self, unit: &str) -> Result<Self, RuntimeUnitError>
+    {
+        self.try_convert(crate::parser::parse_unit(unit)?)
+    }
+    #[inline]
+    /// Change the kind of this quantity (e.g. to treat a torque as an energy). The value is unchanged.
+    fn as_kind(&self, kind: Option<QuantityKind>) -> Self where Self: Clone
+    {
+        let mut quantity = self.clone();
+        *quantity.unit_mut() = self.unit().as_kind(kind);
+        quantity
+    }
     /// Try to convert from this unit to another (modifies current quantity)
     fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError>;    
-    /// Convert from this unit to another (creates a copy). No validation of base unit is made.
+    /// Convert from this unit to another (creates a copy). No validation of base unit is made, and integer values are
+    /// rounded and saturated silently (see `Numeric::from_f64`), use `try_convert` for a checked conversion.
     fn convert(&self, unit: UnitDefinition) -> Self;
-    /// Convert from this unit to another (modifies current quantity). No validation of base unit is made.
+    /// Convert from this unit to another (modifies current quantity). No validation of base unit is made, and integer
+    /// values are rounded and saturated silently, use `try_convert_mut` for a checked conversion.
     fn convert_mut(&mut self, unit: UnitDefinition);
 }
 
 ///
-/// Trait that implements conversion of a quantity within a given unit type (e.g. m->cm, kg->g)
+/// Trait that implements conversion of a quantity within a given unit type (e.g. m->cm, kg->g). 
+/// Quantities on an absolute scale (e.g. `ThermodynamicTemperature`) cannot be added to themselves, so addition is not required here.
 /// 
-pub trait FixedQuantity<UnitType: Unit> where Self: Sized + Div<f64> + Mul<f64> + DivAssign<f64> + MulAssign<f64> + AddAssign<Self> + SubAssign<Self> 
+pub trait FixedQuantity<UnitType: Unit> where Self: Sized + Div<f64> + Mul<f64> + DivAssign<f64> + MulAssign<f64>
 {
     /// Return unit associated with this quantity
     fn unit(&self) -> UnitType;    
     /// Return mutable unit associated with this quantity
     fn unit_mut(&mut self) -> &mut UnitType;    
-    /// Convert from this unit to another (creates a copy). No validation of base unit is made.
+    /// Convert from this unit to another (creates a copy). No validation of base unit is made, and integer values are
+    /// rounded and saturated silently (e.g. 300 cm as `u8` millimeters is 255 mm), use `try_convert` for a checked conversion.
     fn convert(&self, unit: UnitType) -> Self;
-    /// Convert from this unit to another (modifies current quantity). No validation of base unit is made.
+    /// Convert from this unit to another (modifies current quantity). No validation of base unit is made, and integer
+    /// values are rounded and saturated silently, see `convert`.
     fn convert_mut(&mut self, unit: UnitType);
-    /// Try to convert from this unit to another (creates a copy)
+    /// Try to convert from this unit to another (creates a copy), failing with `RuntimeUnitError::ValueOutOfRange` for
+    /// values the numeric type cannot represent
     fn try_convert(&self, unit: crate::Units) -> Result<Self, RuntimeUnitError> where Self: Sized;
 }
 
@@ -63,15 +242,34 @@ pub trait FixedSliceQuantity<UnitType: Unit, Element>
     fn values_mut(&mut self) -> &mut [Element];
     /// Return number of values in quantity
     fn len(&self) -> usize;
-    /// Convert a unit of one `UnitType` to another of the same type. No validation of base unit is made.
+    /// Convert a unit of one `UnitType` to another of the same type. No validation of base unit is made, and integer
+    /// values are rounded and saturated silently (see `Numeric::from_f64`).
     fn convert(&self, unit: UnitType) -> Self;
-    /// Mutate current quantity, convering  a unit of one `UnitType` to another of the same type. No validation of base unit is made.
+    /// Mutate current quantity, convering  a unit of one `UnitType` to another of the same type. No validation of base unit
+    /// is made, and integer values are rounded and saturated silently.
     fn convert_mut(&mut self, unit: UnitType);
     /// Attempt to convert the unit given in `unit` to a `UnitType`. Base unit validation is made here.
     fn try_convert(&self, unit: Units) -> Result<Self, RuntimeUnitError> where Self: Sized;
 }
 
 
+///
+/// Trait that implements addition and subtraction returning an error, instead of panicking, on incompatible units 
+/// (or on values of different lengths).
+/// 
+pub trait CheckedAdditive<Rhs = Self> where Self: Sized
+{
+    /// Add `rhs` to this quantity (result in the units of this quantity, except for a relative `Quantity` plus an absolute
+    /// one, e.g. a temperature interval plus a temperature, whose result is absolute and in the units of `rhs`).
+    fn checked_add(self, rhs: Rhs) -> Result<Self, RuntimeUnitError>;
+    /// Subtract `rhs` from this quantity (result in the units of this quantity).
+    fn checked_sub(self, rhs: Rhs) -> Result<Self, RuntimeUnitError>;
+    /// Add `rhs` to this quantity (modifies current quantity). The quantity is unchanged on error.
+    fn try_add_assign(&mut self, rhs: Rhs) -> Result<(), RuntimeUnitError>;
+    /// Subtract `rhs` from this quantity (modifies current quantity). The quantity is unchanged on error.
+    fn try_sub_assign(&mut self, rhs: Rhs) -> Result<(), RuntimeUnitError>;
+}
+
 ///
 /// Trait to define a type of unit (e.g. `LengthUnit`,`MassUnit`)
 /// 
@@ -85,14 +283,8 @@ pub trait Unit where Self:Sized
     fn try_convert(&self, unit: UnitDefinition) -> Result<f64, RuntimeUnitError>
     {
         let definition = self.definition();
-        if definition.is_convertible(unit)
-        {
-            Ok(unit.multiplier() / definition.multiplier())
-        }
-        else
-        {
-            Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from base units of {} to {}", definition.unit_string(), unit.unit_string())))
-        }
+        definition.try_convert(unit)?;
+        Ok(unit.multiplier() / definition.multiplier())
     }
     /// Compute conversion factor from this unit to another (no check of unit compatibility is made).
     #[inline]
@@ -101,4 +293,25 @@ pub trait Unit where Self:Sized
         let definition = self.definition();
         definition.multiplier() / unit.definition().multiplier()
     }
+    /// Convert `values` from this unit to another of the same type in place (e.g. feet to meters), see [`crate::slice`].
+    /// Fails with `RuntimeUnitError::ValueOutOfRange`, leaving the values unchanged, if the numeric type cannot represent a
+    /// converted value.
+    #[inline]
+    fn convert_slice_in_place<T: Numeric>(&self, values: &mut [T], unit: Self) -> Result<(), RuntimeUnitError>
+    {
+        crate::slice::check_representable(values.iter(), self.definition(), unit.definition())?;
+        crate::slice::convert_in_place_unchecked(values, self.definition(), unit.definition());
+        Ok(())
+    }
+    /// Convert `input` from this unit to another of the same type, writing the result to `output` (of the same length).
+    /// Fails with `RuntimeUnitError::LengthMismatch` or `ValueOutOfRange`, leaving `output` unchanged.
+    #[inline]
+    fn convert_slice_into<T: Numeric>(&self, input: &[T], output: &mut [T], unit: Self) -> Result<(), RuntimeUnitError>
+    {
+        crate::slice::check_lengths(input.len(), output.len())?;
+        crate::slice::check_representable(input, self.definition(), unit.definition())?;
+        let (scale, shift) = self.definition().affine_conversion_unchecked(unit.definition());
+        crate::slice::scale_into(input, output, scale, shift);
+        Ok(())
+    }
 }
\ No newline at end of file
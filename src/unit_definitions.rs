@@ -101,7 +101,7 @@ system!(
         TemperatureGradient,
         TemperatureInterval,
         ThermalConductivity,
-        //ThermodynamicTemperature,
+        ThermodynamicTemperature,
         Time,
         Torque,
         Velocity,
@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::AbsorbedDoseKind);
+    kind: AbsorbedDose;
     units {        
         /// Radiation UnitDefinition (defined in CGS units in 1953)
         @rad: prefix!(centi); "rad", "rad", "rad";
@@ -51,7 +51,7 @@ use crate::traits::Unit;
 
     #[test]
     fn check_dimension() {
-        assert_eq!(AbsorbedDoseUnit::base(), UnitBase::new_length().powi(2)/UnitBase::new_time().powi(2));
+        assert_eq!(AbsorbedDoseUnit::base(), UnitBase::new_length().checked_powi(2).unwrap()/UnitBase::new_time().checked_powi(2).unwrap());
     }
     #[test]
     fn check_units() {
@@ -76,7 +76,7 @@ use crate::traits::Unit;
 
     #[test]
     fn check_dimension() {
-        assert_eq!(AccelerationUnit::base(), UnitBase::new_length()/UnitBase::new_time().powi(2));
+        assert_eq!(AccelerationUnit::base(), UnitBase::new_length()/UnitBase::new_time().checked_powi(2).unwrap());
     }
     #[test]
     fn check_units() {
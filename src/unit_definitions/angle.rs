@@ -15,7 +15,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::AngleKind);
+    kind: Angle;
     units {
         /// SI derived UnitDefinition of angle. It is the angle subtended at the center of a circle by an
         /// arc that is equal in length to the radius of the circle.
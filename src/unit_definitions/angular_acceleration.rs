@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::AngleKind);
+    kind: Angle;
     units {
         /// Derived UnitDefinition of angular acceleration.
         @radian_per_second_squared: 1.0; "rad/s²", "radian per second squared",
@@ -29,7 +29,7 @@ use crate::traits::Unit;
     
     #[test]
     fn check_dimension() {
-        assert_eq!(AngularAccelerationUnit::base(), AngleUnit::base() / TimeUnit::base().powi(2));
+        assert_eq!(AngularAccelerationUnit::base(), AngleUnit::base() / TimeUnit::base().checked_powi(2).unwrap());
     }
 
     #[test]
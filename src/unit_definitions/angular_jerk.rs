@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::AngleKind);
+    kind: Angle;
     units {
         /// Derived UnitDefinition of angular jerk.
         @radian_per_second_cubed: 1.0; "rad/s³", "radian per second cubed",
@@ -28,7 +28,7 @@ use crate::traits::Unit;
     
     #[test]
     fn check_dimension() {
-        assert_eq!(AngularJerkUnit::base(), AngleUnit::base() / TimeUnit::base().powi(3));
+        assert_eq!(AngularJerkUnit::base(), AngleUnit::base() / TimeUnit::base().checked_powi(3).unwrap());
     }
 
     #[test]
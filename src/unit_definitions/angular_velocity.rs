@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::AngleKind);
+    kind: Angle;
     units {
         /// Derived UnitDefinition of angular velocity.
         @radian_per_second: 1.0_E0; "rad/s", "radian per second", "radians per second";
@@ -47,4 +47,4 @@ use crate::traits::Unit;
     fn test_unit(value: AngularVelocityUnit, time: TimeUnit, angle: AngleUnit) {
         assert_eq!(Into::<UnitDefinition>::into(value), Into::<UnitDefinition>::into(angle) / Into::<UnitDefinition>::into(time));
     }
-}
\ No newline at end of file
+}
@@ -76,7 +76,7 @@ use crate::traits::Unit;
 
     #[test]
     fn check_dimension() {
-        assert_eq!(AreaUnit::base(),  LengthUnit::base().powi(2));
+        assert_eq!(AreaUnit::base(),  LengthUnit::base().checked_powi(2).unwrap());
     }
 
     #[test]
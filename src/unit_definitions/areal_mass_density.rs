@@ -32,7 +32,7 @@ use crate::traits::Unit;
     
     #[test]
     fn check_dimension() {
-        assert_eq!(ArealMassDensityUnit::base(),  MassUnit::base() / LengthUnit::base().powi(2));
+        assert_eq!(ArealMassDensityUnit::base(),  MassUnit::base() / LengthUnit::base().checked_powi(2).unwrap());
     }
 
    #[test]
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @per_square_kilometer: prefix!(none) / prefix!(kilo) / prefix!(kilo); "km⁻²",
             "per square kilometer", "per square kilometer";
@@ -50,7 +50,7 @@ mod test {
 
     #[test]
     fn check_dimension() {
-        assert_eq!(ArealNumberDensityUnit::base(),  LengthUnit::base().powi(-2));
+        assert_eq!(ArealNumberDensityUnit::base(),  LengthUnit::base().checked_powi(-2).unwrap());
     }
 
     #[test]
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @per_square_meter_second: prefix!(none); "m⁻² · s⁻¹", "per square meter second",
             "per square meter second";
@@ -48,7 +48,7 @@ mod test {
 
     #[test]
     fn check_dimension() {
-        assert_eq!(ArealNumberRateUnit::base(),  LengthUnit::base().powi(-2) / TimeUnit::base());
+        assert_eq!(ArealNumberRateUnit::base(),  LengthUnit::base().checked_powi(-2).unwrap() / TimeUnit::base());
     }
 
     #[test]
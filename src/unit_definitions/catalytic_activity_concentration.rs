@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         1.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @yottakatal_per_cubic_meter: prefix!(yotta); "Ykat/m³",
             "yottakatal per cubic meter", "yottakatals per cubic meter";
@@ -289,7 +289,7 @@ mod test {
 
     #[test]
     fn check_dimension() {
-        assert_eq!(CatalyticActivityConcentrationUnit::base(),  AmountOfSubstanceUnit::base() / TimeUnit::base() / LengthUnit::base().powi(3));
+        assert_eq!(CatalyticActivityConcentrationUnit::base(),  AmountOfSubstanceUnit::base() / TimeUnit::base() / LengthUnit::base().checked_powi(3).unwrap());
     }
 
     #[test]
@@ -33,7 +33,7 @@ mod tests {
 
     #[test]
     fn check_dimension() {
-        assert_eq!(CubeRootScaledLengthUnit::base(), LengthUnit::base() / EnergyUnit::base().powf(1.0/3.0));
+        assert_eq!(CubeRootScaledLengthUnit::base(), LengthUnit::base() / EnergyUnit::base().checked_powf(1.0/3.0).unwrap());
     }
 
     #[test]
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::DoseEquivalentKind);
+    kind: DoseEquivalent;
     units {
       
         /// Roentgen equivalent man
@@ -61,7 +61,7 @@ use crate::traits::Unit;
     use crate::{DoseEquivalent, units::DoseEquivalentUnit};
     #[test]
     fn check_dimension() {
-        assert_eq!(DoseEquivalentUnit::base(), UnitBase::new_length().powi(2) / UnitBase::new_time().powi(2));
+        assert_eq!(DoseEquivalentUnit::base(), UnitBase::new_length().checked_powi(2).unwrap() / UnitBase::new_time().checked_powi(2).unwrap());
     }
     #[test]
     fn check_units() {
@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-        kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+        kind: ConstituentConcentration;
     units {
         @coulomb_per_square_meter: prefix!(none); "C/m²", "coulomb per square meter",
             "coulombs per square meter";
@@ -40,7 +40,7 @@ use crate::traits::Unit;
     }
     fn test_unit(charge: ElectricChargeUnit, area: AreaUnit, value: ElectricChargeArealDensityUnit)
     {
-        assert_eq!(Into::<UnitDefinition>::into(value), (Into::<UnitDefinition>::into(charge) / Into::<UnitDefinition>::into(area)));
+        assert_eq!(Into::<UnitDefinition>::into(value).as_kind(None), (Into::<UnitDefinition>::into(charge) / Into::<UnitDefinition>::into(area)));
     }
     
 }
@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-        kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+        kind: ConstituentConcentration;
     units {
         @coulomb_per_meter: prefix!(none); "C/m", "coulomb per meter", "coulombs per meter";
         @coulomb_per_centimeter: prefix!(none) / prefix!(centi); "C/cm", "coulomb per centimeter",
@@ -38,6 +38,6 @@ use crate::traits::Unit;
     }
     fn test_unit(charge: ElectricChargeUnit, length: LengthUnit, value: ElectricChargeLinearDensityUnit)
     {
-        assert_eq!(Into::<UnitDefinition>::into(value), (Into::<UnitDefinition>::into(charge) / Into::<UnitDefinition>::into(length)));
+        assert_eq!(Into::<UnitDefinition>::into(value).as_kind(None), (Into::<UnitDefinition>::into(charge) / Into::<UnitDefinition>::into(length)));
     }
 }
@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-        kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+        kind: ConstituentConcentration;
     units {
         @coulomb_per_cubic_meter: prefix!(none); "C/m³", "coulomb per cubic meter",
             "coulombs per cubic meter";
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-        kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+        kind: ConstituentConcentration;
     units {
         @ampere_per_square_meter: prefix!(none); "A/m²", "ampere per square meter",
             "amperes per square meter";
@@ -42,6 +42,6 @@ use crate::traits::Unit;
     }
     fn test_unit(value: ElectricCurrentDensityUnit, current: ElectricCurrentUnit, area: AreaUnit)
     {
-        assert_eq!(Into::<UnitDefinition>::into(value), (Into::<UnitDefinition>::into(current) / Into::<UnitDefinition>::into(area)));
+        assert_eq!(Into::<UnitDefinition>::into(value).as_kind(None), (Into::<UnitDefinition>::into(current) / Into::<UnitDefinition>::into(area)));
     }
 }
@@ -48,7 +48,7 @@ use crate::traits::Unit;
     
     #[test]
     fn check_dimension() {
-        assert_eq!(ElectricPotentialUnit::base(),  LengthUnit::base().powi(2) * MassUnit::base() / ElectricCurrentUnit::base() / TimeUnit::base().powi(3));
+        assert_eq!(ElectricPotentialUnit::base(),  LengthUnit::base().checked_powi(2).unwrap() * MassUnit::base() / ElectricCurrentUnit::base() / TimeUnit::base().checked_powi(3).unwrap());
     }
 
     #[test]
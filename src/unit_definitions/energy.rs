@@ -12,6 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
+    kind: Energy;
     units {
         @yottajoule: prefix!(yotta); "YJ", "yottajoule", "yottajoules";
         @zettajoule: prefix!(zetta); "ZJ", "zettajoule", "zettajoules";
@@ -97,7 +98,7 @@ use crate::traits::Unit;
     
     #[test]
     fn check_dimension() {
-        assert_eq!(EnergyUnit::base(), MassUnit::base()*LengthUnit::base().powi(2) / TimeUnit::base().powi(2));
+        assert_eq!(EnergyUnit::base(), MassUnit::base()*LengthUnit::base().checked_powi(2).unwrap() / TimeUnit::base().checked_powi(2).unwrap());
     }
 
     #[test]
@@ -12,6 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
+    kind: Frequency;
     units {
         @yottahertz: prefix!(yotta); "YHz", "yottahertz", "yottahertz";
         @zettahertz: prefix!(zetta); "ZHz", "zettahertz", "zettahertz";
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::InformationKind);
+    kind: Information;
     units {
         // Base-2.
         @yobibit: prefix!(yobi) / 8.0; "Yib", "yobibit", "yobibits";
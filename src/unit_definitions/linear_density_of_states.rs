@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @state_per_meter_joule: prefix!(none); "1/(m · J)", "state per meter joule",
             "states per meter joule";
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @yottagram_per_cubic_meter: prefix!(yotta) / prefix!(kilo); "Yg/m³",
             "yottagram per cubic meter", "yottagrams per cubic meter";
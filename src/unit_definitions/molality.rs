@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         1.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @mole_per_kilogram: prefix!(none); "mol/kg", "mole per kilogram", "moles per kilogram";
     }
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         1.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @yottamole_per_cubic_meter: prefix!(yotta); "Ymol/m³",
             "yottamole per cubic meter", "yottamoles per cubic meter";
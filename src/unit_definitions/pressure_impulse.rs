@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::PressureImpulseKind);
+    kind: PressureImpulse;
     units {
         @yottapascal_sec : prefix!(yotta); "YPa-sec", "yottapascal-sec", "yottapascals-sec";
         @zettapascal_sec : prefix!(zetta); "ZPa-sec", "zettapascal-sec", "zettapascals-sec";
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @yottabecquerel: prefix!(yotta); "YBq", "yottabecquerel", "yottabecquerels";
         @zettabecquerel: prefix!(zetta); "ZBq", "zettabecquerel", "zettabecquerels";
@@ -12,6 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
+    kind: Ratio;
     units {
         @ratio: 1.0; "", "", "";
         @part_per_hundred: 1.0_E-2; "parts per hundred", "part per hundred", "parts per hundred";
@@ -12,7 +12,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::SolidAngleKind);
+    kind: SolidAngle;
     units {
         /// SI derived UnitDefinition of solid angle is steradians. It is the solid angle subtended at the
         /// center of a UnitDefinition sphere by a UnitDefinition area on its surface.
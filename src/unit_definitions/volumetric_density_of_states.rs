@@ -13,7 +13,7 @@ quantity! {
         0.0,     // thermodynamic temperature
         0.0,     // amount of substance
         0.0];    // luminous intensity
-    kind: dyn (crate::si::marker::ConstituentConcentrationKind);
+    kind: ConstituentConcentration;
     units {
         @state_per_cubic_meter_joule: prefix!(none); "1/(m³ · J)",
             "state per cubic meter joule", "states per cubic meter joule";
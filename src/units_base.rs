@@ -3,16 +3,33 @@ use std::ops::{Add, Neg, Sub};
 use bitfield_struct::bitfield;
 
 use crate::errors::RuntimeUnitError;
+use crate::format::UnitStyle;
+use crate::traits::Numeric;
 type Ratio8 = num_rational::Ratio<i8>;
+#[cfg(not(feature="wide_dimensions"))]
 #[bitfield(u8, default=false)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Eq, Hash)]
+#[doc="Power of a base unit, with a numerator within `-8..=7` and a denominator within `1..=7`."]
 pub struct Rational8 {
     #[bits(4)]
     pub numerator: i8,
     #[bits(4)]
     pub denominator: i8,
 }
+#[cfg(feature="wide_dimensions")]
+#[bitfield(u16, default=false)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Hash)]
+#[doc="Power of a base unit, with a numerator within `-128..=127` and a denominator within `1..=7`."]
+pub struct Rational8 {
+    #[bits(8)]
+    pub numerator: i8,
+    #[bits(4)]
+    pub denominator: i8,
+    #[bits(4)]
+    __: u8,
+}
 impl Default for Rational8
 {
     #[inline]
@@ -69,6 +86,80 @@ impl Rational8
     {
         self.numerator() as f64 / self.denominator() as f64
     }
+
+    /// Range of numerators that can be represented (`-8..=7`, or `-128..=127` with the `wide_dimensions` feature).
+    #[cfg(not(feature="wide_dimensions"))]
+    pub const NUMERATORS: core::ops::RangeInclusive<i64> = -8..=7;
+    /// Range of numerators that can be represented (`-8..=7`, or `-128..=127` with the `wide_dimensions` feature).
+    #[cfg(feature="wide_dimensions")]
+    pub const NUMERATORS: core::ops::RangeInclusive<i64> = -128..=127;
+    /// Range of denominators that can be represented.
+    pub const DENOMINATORS: core::ops::RangeInclusive<i64> = 1..=7;
+
+    ///
+    /// Create a power from a `numerator` and `denominator`, reduced to lowest terms. Fails with 
+    /// `RuntimeUnitError::ExponentOverflow` unless the numerator is within `NUMERATORS` and the denominator within `DENOMINATORS`.
+    /// 
+    pub fn try_new(numerator: i64, denominator: i64) -> Result<Self, RuntimeUnitError>
+    {
+        if denominator == 0
+        {
+            return Err(RuntimeUnitError::ExponentOverflow { numerator, denominator });
+        }
+        let ratio = num_rational::Ratio::new(numerator, denominator);
+        let (numerator, denominator) = (*ratio.numer(), *ratio.denom());
+        if Self::NUMERATORS.contains(&numerator) && Self::DENOMINATORS.contains(&denominator)
+        {
+            Ok(Self::new().with_numerator(numerator as i8).with_denominator(denominator as i8))
+        }
+        else
+        {
+            Err(RuntimeUnitError::ExponentOverflow { numerator, denominator })
+        }
+    }
+
+    ///
+    /// Convert a floating point power (e.g. `0.5`) to the `Rational8` representing it, see [`Rational8::try_new`].
+    /// 
+    pub fn try_from_f64(value: f64) -> Result<Self, RuntimeUnitError>
+    {
+        for denominator in 1..=7
+        {
+            let numerator = value * denominator as f64;
+            if (numerator - numerator.round()).abs() < 1e-9
+            {
+                return Self::try_new(numerator.round() as i64, denominator);
+            }
+        }
+        let ratio = num_rational::Ratio::<i64>::approximate_float(value).unwrap_or_else(|| num_rational::Ratio::from_integer(value as i64));
+        Err(RuntimeUnitError::ExponentOverflow { numerator: *ratio.numer(), denominator: *ratio.denom() })
+    }
+
+    /// Add two powers, failing if the result cannot be represented.
+    pub fn checked_add(self, rhs: Rational8) -> Result<Self, RuntimeUnitError>
+    {
+        let (numerator, denominator) = (self.numerator() as i64, self.denominator() as i64);
+        Self::try_new(numerator * rhs.denominator() as i64 + rhs.numerator() as i64 * denominator, denominator * rhs.denominator() as i64)
+    }
+
+    /// Subtract two powers, failing if the result cannot be represented.
+    pub fn checked_sub(self, rhs: Rational8) -> Result<Self, RuntimeUnitError>
+    {
+        let (numerator, denominator) = (self.numerator() as i64, self.denominator() as i64);
+        Self::try_new(numerator * rhs.denominator() as i64 - rhs.numerator() as i64 * denominator, denominator * rhs.denominator() as i64)
+    }
+
+    /// Multiply a power by an integer, failing if the result cannot be represented.
+    pub fn checked_mul(self, rhs: i8) -> Result<Self, RuntimeUnitError>
+    {
+        Self::try_new(self.numerator() as i64 * rhs as i64, self.denominator() as i64)
+    }
+
+    /// Multiply a power by a floating point value, failing if the result cannot be represented.
+    pub fn checked_mul_f64(self, rhs: f64) -> Result<Self, RuntimeUnitError>
+    {
+        Self::try_from_f64(self.fraction() * rhs)
+    }
 }
 impl From<i8> for Rational8
 {
@@ -107,8 +198,9 @@ impl Into<f64> for Rational8
 }
 impl From<f64> for Rational8
 {
+    /// Convert a power to a `Rational8`, panicking if it cannot be represented (see [`Rational8::try_from_f64`]).
     fn from(value: f64) -> Self {        
-        Ratio8::approximate_float(value).unwrap().into()
+        Rational8::try_from_f64(value).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 impl Add<Rational8> for Rational8
@@ -116,15 +208,7 @@ impl Add<Rational8> for Rational8
     type Output=Rational8;
     #[inline]
     fn add(self, rhs: Rational8) -> Self::Output {
-        if self.is_fraction() || rhs.is_fraction()
-        {
-            Ratio8::approximate_float(self.fraction() + rhs.fraction()).unwrap().into()            
-        }
-        else
-        {
-            self.with_numerator(self.numerator() + rhs.numerator()).with_denominator(self.denominator())
-        }
-        
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 impl Sub<Rational8> for Rational8
@@ -132,14 +216,7 @@ impl Sub<Rational8> for Rational8
     type Output=Rational8;
     #[inline]
     fn sub(self, rhs: Rational8) -> Self::Output {
-        if self.is_fraction() || rhs.is_fraction() || self.denominator() != rhs.denominator()
-        {            
-            Ratio8::approximate_float(self.fraction() - rhs.fraction()).unwrap().into()            
-        }
-        else
-        {
-            self.with_numerator(self.numerator() - rhs.numerator()).with_denominator(self.denominator())
-        }
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 impl Mul<i8> for Rational8
@@ -147,14 +224,7 @@ impl Mul<i8> for Rational8
     type Output=Rational8;
     #[inline]
     fn mul(self, rhs: i8) -> Self::Output {        
-        if self.is_fraction()
-        {            
-            Ratio8::approximate_float(self.fraction() * rhs as f64).unwrap().into()
-        }
-        else
-        {
-            self.with_numerator(self.numerator() * rhs).with_denominator(1)
-        }
+        self.checked_mul(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -163,8 +233,7 @@ impl Mul<f64> for Rational8
     type Output=Rational8;
     #[inline]
     fn mul(self, rhs: f64) -> Self::Output {        
-        let power = self.fraction() * rhs;
-        Ratio8::approximate_float(power).unwrap().into()                    
+        self.checked_mul_f64(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
@@ -172,10 +241,12 @@ impl Neg for Rational8
 {
     type Output=Rational8;
 
+    /// Panics if the negated power cannot be represented (e.g. -8, see `NUMERATORS`).
     fn neg(self) -> Self::Output {
-        self.with_numerator(-self.numerator())
+        Rational8::try_new(-(self.numerator() as i64), self.denominator() as i64).unwrap_or_else(|error| panic!("{error}"))
     }
 }
+#[cfg(not(feature="wide_dimensions"))]
 #[bitfield(u64, default=false)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Eq, Hash)]
@@ -199,149 +270,243 @@ pub struct UnitBase
     #[bits(8)]
     _unused: Rational8,
 }
+#[cfg(feature="wide_dimensions")]
+#[bitfield(u128, default=false)]
+#[derive(PartialEq, Eq, Hash)]
+#[doc="Storage of primitives used to define a given unit, with wider powers and extra dimensions for angles, information and counts."]
+pub struct UnitBase
+{    
+    #[bits(12)]
+    meter: Rational8, 
+    #[bits(12)]
+    second: Rational8,
+    #[bits(12)]
+    kilogram: Rational8,
+    #[bits(12)]
+    ampere: Rational8,
+    #[bits(12)]
+    candela: Rational8,
+    #[bits(12)]
+    kelvin: Rational8,
+    #[bits(12)]
+    mole: Rational8,
+    #[bits(12)]
+    radian: Rational8,
+    #[bits(12)]
+    bit: Rational8,
+    #[bits(12)]
+    count: Rational8,
+    #[bits(8)]
+    __: u8,
+}
+
+/// Serialized as a map of the non-zero powers (e.g. `{"Length": [2, 1]}`), as many formats do not support 128-bit integers.
+#[cfg(all(feature="wide_dimensions", feature="serde"))]
+impl serde::Serialize for UnitBase
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let powers = BaseDimension::ALL.iter().map(|&dimension| (dimension, self.power(dimension)))
+            .filter(|(_, power)| power.numerator() != 0)
+            .map(|(dimension, power)| (dimension, (power.numerator(), power.denominator())));
+        serializer.collect_map(powers)
+    }
+}
+
+#[cfg(all(feature="wide_dimensions", feature="serde"))]
+impl<'de> serde::Deserialize<'de> for UnitBase
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let powers = std::collections::HashMap::<BaseDimension, (i8, i8)>::deserialize(deserializer)?;
+        powers.into_iter().try_fold(UnitBase::default(), |base, (dimension, (numerator, denominator))|
+        {
+            let power = Rational8::try_new(numerator.into(), denominator.into()).map_err(serde::de::Error::custom)?;
+            Ok(base.with_power(dimension, power))
+        })
+    }
+}
+
+/// Base dimensions of a `UnitBase`. The `Angle`, `Information` and `Count` dimensions are only available with the 
+/// `wide_dimensions` feature; without it, angles and information are dimensionless, distinguished by their `QuantityKind`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BaseDimension
+{
+    Length,
+    Time,
+    Mass,
+    ElectricCurrent,
+    LuminousIntensity,
+    Temperature,
+    AmountOfSubstance,
+    #[cfg(feature="wide_dimensions")]
+    Angle,
+    #[cfg(feature="wide_dimensions")]
+    Information,
+    /// Counts of discrete items (e.g. currency units, events or cycles).
+    #[cfg(feature="wide_dimensions")]
+    Count,
+}
+
+impl BaseDimension
+{
+    /// All base dimensions, in the order they are displayed.
+    #[cfg(not(feature="wide_dimensions"))]
+    pub const ALL: [BaseDimension; 7] = [BaseDimension::Length, BaseDimension::Time, BaseDimension::Mass, BaseDimension::ElectricCurrent, 
+        BaseDimension::LuminousIntensity, BaseDimension::Temperature, BaseDimension::AmountOfSubstance];
+    /// All base dimensions, in the order they are displayed.
+    #[cfg(feature="wide_dimensions")]
+    pub const ALL: [BaseDimension; 10] = [BaseDimension::Length, BaseDimension::Time, BaseDimension::Mass, BaseDimension::ElectricCurrent, 
+        BaseDimension::LuminousIntensity, BaseDimension::Temperature, BaseDimension::AmountOfSubstance, BaseDimension::Angle, 
+        BaseDimension::Information, BaseDimension::Count];
+
+    /// Symbol of the base unit of this dimension (e.g. `m` or `kg`).
+    pub fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            BaseDimension::Length => "m",
+            BaseDimension::Time => "s",
+            BaseDimension::Mass => "kg",
+            BaseDimension::ElectricCurrent => "A",
+            BaseDimension::LuminousIntensity => "cd",
+            BaseDimension::Temperature => "K",
+            BaseDimension::AmountOfSubstance => "mol",
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Angle => "rad",
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Information => "bit",
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Count => "count",
+        }
+    }
+}
+
 impl Default for UnitBase
 {
     fn default() -> Self 
     {
-        UnitBase::new().
-        with_meter(Rational8::default()).
-        with_second(Rational8::default()).
-        with_kilogram(Rational8::default()).    
-        with_ampere(Rational8::default()).
-        with_candela(Rational8::default()).
-        with_kelvin(Rational8::default()).
-        with_mole(Rational8::default())    
+        BaseDimension::ALL.iter().fold(UnitBase::new(), |base, &dimension| base.with_power(dimension, Rational8::default()))
     }
 }
 impl UnitBase
 {   
-    /// A method to get the power of a given unit component.    
-    fn get_element(&self, index: u8) -> Rational8
+    /// Get the power of a given base `dimension` (e.g. 2 for the length of an area).
+    pub fn power(&self, dimension: BaseDimension) -> Rational8
     {
-        match index
+        match dimension
         {
-            0 => self.meter(),
-            1 => self.second(),
-            2 => self.kilogram(),
-            3 => self.ampere(),
-            4 => self.candela(),
-            5 => self.kelvin(),
-            6 => self.mole(),
-            _ => panic!("Unsupported index {} provided to get_element", index)
+            BaseDimension::Length => self.meter(),
+            BaseDimension::Time => self.second(),
+            BaseDimension::Mass => self.kilogram(),
+            BaseDimension::ElectricCurrent => self.ampere(),
+            BaseDimension::LuminousIntensity => self.candela(),
+            BaseDimension::Temperature => self.kelvin(),
+            BaseDimension::AmountOfSubstance => self.mole(),
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Angle => self.radian(),
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Information => self.bit(),
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Count => self.count(),
         }
     }
-    /// Get unit name for a given index from the `get_element` method.
-    fn unit_name(&self, index: u8) -> &'static str
+    /// Return a copy with the power of a given base `dimension` replaced.
+    pub fn with_power(self, dimension: BaseDimension, power: Rational8) -> Self
     {
-        match index
+        match dimension
         {
-            0 => "m",
-            1 => "s",
-            2 => "kg",
-            3 => "A",
-            4 => "cd",
-            5 => "K",
-            6 => "mol",
-            _ => panic!("Unsupported index {} provided to get_element", index)
+            BaseDimension::Length => self.with_meter(power),
+            BaseDimension::Time => self.with_second(power),
+            BaseDimension::Mass => self.with_kilogram(power),
+            BaseDimension::ElectricCurrent => self.with_ampere(power),
+            BaseDimension::LuminousIntensity => self.with_candela(power),
+            BaseDimension::Temperature => self.with_kelvin(power),
+            BaseDimension::AmountOfSubstance => self.with_mole(power),
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Angle => self.with_radian(power),
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Information => self.with_bit(power),
+            #[cfg(feature="wide_dimensions")]
+            BaseDimension::Count => self.with_count(power),
         }
     }
     ///
-    /// Generate inverse of current unit (e.g. m -> 1/m).
-    /// This is effectively setting the numerator to the negative of the current value
-    pub fn inv(&self) -> Self
-    {
-        self.with_ampere(-self.ampere()).
-        with_candela(-self.candela()).
-        with_kelvin(-self.kelvin()).
-        with_kilogram(-self.kilogram()).
-        with_meter(-self.meter()).
-        with_mole(-self.mole()).
-        with_second(-self.second())
-    }
-    
-}
-impl Display for UnitBase
-{
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result 
+    /// Format the base units in a given `style` (e.g. `m/s^2`, `m·s⁻²` or `\mathrm{m\,s^{-2}}`). Dimensionless units are empty.
+    /// 
+    pub fn to_string_styled(&self, style: UnitStyle) -> String
     {
-        let numerator = (0..7).map(|i| 
+        // Numerators are widened, as the most negative power (e.g. m⁻⁸) cannot be negated within a `Rational8`.
+        let factor = |dimension: &BaseDimension, numerator: i16, denominator: i8|
+        {
+            if numerator == 1 && denominator == 1
             {
-                let value =  self.get_element(i as u8);
-                if value.numerator().is_positive()
-                {         
-                    if !value.is_fraction()
-                    {
-                        format!("{}", self.unit_name(i as u8)).to_owned()
-                    }
-                    else
-                    {
-                        format!("{}^{}", self.unit_name(i as u8), value).to_owned() 
-                    }
-                }
-                else
-                {
-                    "".to_owned()
-                }
-                
-            }).filter(|x|!x.is_empty()).collect::<Vec<String>>().join("*");
-            let denominator = (0..7).map(|i| 
-                {
-                    let value =  self.get_element(i as u8);
-                    if value.numerator().is_negative()
-                    {
-                        let value = -value;
-                        if !value.is_fraction()
-                        {
-                            format!("{}", self.unit_name(i as u8)).to_owned()
-                        }
-                        else
-                        {
-                            format!("{}^{}", self.unit_name(i as u8), value).to_owned() 
-                        }
-                    }
-                    else
-                    {
-                        "".to_owned()
-                    }
-                }).filter(|x|!x.is_empty()).collect::<Vec<String>>().join("*");
-                
-            let result = if numerator.is_empty() 
+                dimension.symbol().to_owned()
+            }
+            else
             {
-                if denominator.is_empty()
+                style.power(dimension.symbol(), numerator, denominator)
+            }
+        };
+        let factor_of = |dimension: &BaseDimension, sign: i16| factor(dimension, sign * i16::from(self.power(*dimension).numerator()), self.power(*dimension).denominator());
+        let positive = BaseDimension::ALL.iter().filter(|&&d| self.power(d).numerator().is_positive()).map(|d| factor_of(d, 1));
+        match style
+        {
+            UnitStyle::Ascii =>
+            {
+                let numerator = positive.collect::<Vec<String>>();
+                let denominator = BaseDimension::ALL.iter().filter(|&&d| self.power(d).numerator().is_negative()).map(|d| factor_of(d, -1)).collect::<Vec<String>>();
+                let denominator = match denominator.len()
+                {
+                    0 => return numerator.join("*"),
+                    1 => denominator[0].clone(),
+                    _ => format!("({})", denominator.join("*"))
+                };
+                if numerator.is_empty()
                 {
-                    "dimensionless".to_owned()
+                    format!("1/{denominator}")
                 }
                 else
-                {        
-                    if denominator.contains("*")
-                    {
-                        format!("1/({denominator})")
-                    }
-                    else
-                    {
-                        format!("1/{denominator}")
-                    }
-                }            
+                {
+                    format!("{}/{denominator}", numerator.join("*"))
+                }
             }
-            else
+            UnitStyle::Unicode | UnitStyle::Latex =>
             {
-                if denominator.is_empty()
+                let negative = BaseDimension::ALL.iter().filter(|&&d| self.power(d).numerator().is_negative()).map(|d| factor_of(d, 1));
+                let factors = positive.chain(negative).collect::<Vec<String>>();
+                if style == UnitStyle::Latex && !factors.is_empty()
                 {
-                    numerator
+                    format!("\\mathrm{{{}}}", factors.join("\\,"))
                 }
                 else
                 {
-                    if denominator.contains("*")
-                    {
-                        format!("{numerator}/({denominator})")
-                    }
-                    else
-                    {
-                        format!("{numerator}/{denominator}")
-                    }
+                    factors.join("·")
                 }
-            };
+            }
+        }
+    }
+    ///
+    /// Generate inverse of current unit (e.g. m -> 1/m).
+    /// This is effectively setting the numerator to the negative of the current value
+    pub fn inv(&self) -> Self
+    {
+        BaseDimension::ALL.iter().fold(*self, |base, &dimension| base.with_power(dimension, -self.power(dimension)))
+    }
+    
+}
+impl Display for UnitBase
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result 
+    {
+        let result = self.to_string_styled(UnitStyle::Ascii);
+        if result.is_empty()
+        {
+            write!(f, "dimensionless")
+        }
+        else
+        {
             write!(f, "{result}")
+        }
     }
 }
 
@@ -350,26 +515,13 @@ impl Mul for UnitBase
     type Output = UnitBase;
 
     fn mul(self, rhs: Self) -> Self::Output {        
-        UnitBase::default().
-        with_meter(self.meter() + rhs.meter()).
-        with_second(self.second() + rhs.second()).
-        with_kilogram(self.kilogram() + rhs.kilogram()).
-        with_ampere(self.ampere() + rhs.ampere()).
-        with_candela(self.candela() + rhs.candela()).
-        with_kelvin(self.kelvin() + rhs.kelvin()).
-        with_mole(self.mole() + rhs.mole())
+        self.checked_mul(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 impl MulAssign for UnitBase
 {
     fn mul_assign(&mut self, rhs: Self) {
-        self.set_meter(self.meter() + rhs.meter());
-        self.set_second(self.second() + rhs.second());
-        self.set_kilogram(self.kilogram() + rhs.kilogram());
-        self.set_ampere(self.ampere() + rhs.ampere());
-        self.set_candela(self.candela() + rhs.candela());
-        self.set_kelvin(self.kelvin() + rhs.kelvin());
-        self.set_mole(self.mole() + rhs.mole());
+        *self = *self * rhs;
     }
 }
 
@@ -378,27 +530,14 @@ impl Div for UnitBase
     type Output = UnitBase;
 
     fn div(self, rhs: Self) -> Self::Output {
-        UnitBase::new().
-        with_meter(self.meter() - rhs.meter()).
-        with_second(self.second() - rhs.second()).
-        with_kilogram(self.kilogram() - rhs.kilogram()).
-        with_ampere(self.ampere() - rhs.ampere()).
-        with_candela(self.candela() - rhs.candela()).
-        with_kelvin(self.kelvin() - rhs.kelvin()).
-        with_mole(self.mole() - rhs.mole())
+        self.checked_div(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
 impl DivAssign for UnitBase
 {
     fn div_assign(&mut self, rhs: Self) {        
-        self.set_meter(self.meter() - rhs.meter());
-        self.set_second(self.second() - rhs.second());
-        self.set_kilogram(self.kilogram() - rhs.kilogram());
-        self.set_ampere(self.ampere() - rhs.ampere());
-        self.set_candela(self.candela() - rhs.candela());
-        self.set_kelvin(self.kelvin() - rhs.kelvin());
-        self.set_mole(self.mole() - rhs.mole());
+        *self = *self / rhs;
     }
 }
 
@@ -440,38 +579,91 @@ impl UnitBase
     {
         UnitBase::default()
     }
-    
-    pub(crate) fn powi(&self, power: i8) -> Self
+
+    ///
+    /// Multiply two units, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented.
+    /// 
+    pub fn checked_mul(&self, rhs: UnitBase) -> Result<Self, RuntimeUnitError>
     {
-        UnitBase::default().
-        with_meter(self.meter()*power).
-        with_second(self.second()*power).
-        with_kilogram(self.kilogram()*power).
-        with_ampere(self.ampere()*power).
-        with_candela(self.candela()*power).
-        with_kelvin(self.kelvin()*power).
-        with_mole(self.mole()*power)
+        self.try_zip(rhs, Rational8::checked_add)
     }
-    pub(crate) fn powf(&self, power: f64) -> Self
+    ///
+    /// Divide two units, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented.
+    /// 
+    pub fn checked_div(&self, rhs: UnitBase) -> Result<Self, RuntimeUnitError>
     {
-        UnitBase::default().
-        with_meter(self.meter()*power).
-        with_second(self.second()*power).
-        with_kilogram(self.kilogram()*power).
-        with_ampere(self.ampere()*power).
-        with_candela(self.candela()*power).
-        with_kelvin(self.kelvin()*power).
-        with_mole(self.mole()*power)
+        self.try_zip(rhs, Rational8::checked_sub)
+    }
+    ///
+    /// Raise a unit to an integer power, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented.
+    /// 
+    pub fn checked_powi(&self, power: i8) -> Result<Self, RuntimeUnitError>
+    {
+        self.try_zip(*self, |value, _| value.checked_mul(power))
+    }
+    ///
+    /// Raise a unit to a floating point power, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented.
+    /// 
+    pub fn checked_powf(&self, power: f64) -> Result<Self, RuntimeUnitError>
+    {
+        self.try_zip(*self, |value, _| value.checked_mul_f64(power))
+    }
+    /// Combine the powers of each base unit of this unit and `rhs`.
+    fn try_zip(&self, rhs: UnitBase, op: impl Fn(Rational8, Rational8) -> Result<Rational8, RuntimeUnitError>) -> Result<Self, RuntimeUnitError>
+    {
+        BaseDimension::ALL.iter().try_fold(UnitBase::default(), |base, &dimension| Ok(base.with_power(dimension, op(self.power(dimension), rhs.power(dimension))?)))
+    }
+}
+
+/// Kind of a quantity, distinguishing quantities that share the same dimensions (e.g. angles and information, which
+/// are both dimensionless, or torque and energy).
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuantityKind
+{
+    Angle,
+    SolidAngle,
+    Ratio,
+    Information,
+    Energy,
+    Torque,
+    AbsorbedDose,
+    DoseEquivalent,
+    PressureImpulse,
+    Frequency,
+    ConstituentConcentration,
+}
+
+impl QuantityKind
+{
+    /// Check whether this kind is kept by products and quotients of units. Angles, solid angles and information behave
+    /// like additional dimensions (e.g. radians per second, bytes per second), while the other kinds only distinguish a single quantity.
+    pub fn is_propagated(&self) -> bool
+    {
+        matches!(self, QuantityKind::Angle | QuantityKind::SolidAngle | QuantityKind::Information)
     }
 }
 
 /// Storage for a single unit and its multiplier to convert it to the base unit.
+/// 
+/// Units on an absolute scale (e.g. degrees Celsius) additionally carry an `offset`. A value `v` in such a unit
+/// is converted to the base unit as `(v + offset) * multiplier`. Units without an offset are relative (interval) units.
+/// Products, quotients and powers of units are always relative.
+/// 
+/// Units of quantities sharing their dimensions with other quantities carry a `kind` (e.g. radians and bytes), and
+/// cannot be converted to or compared with units of another kind. Units without a kind are compatible with units of any kind.
+/// Products and quotients keep a propagated kind (see `QuantityKind::is_propagated`) where only one of their operands 
+/// has one (e.g. radians per second), and have no kind otherwise. Use `as_kind` to explicitly change the kind of a unit.
 #[derive(PartialEq, Debug, Clone, Copy)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UnitDefinition
 {
     pub(crate) base: UnitBase,
-    pub(crate) multiplier: f64
+    pub(crate) multiplier: f64,
+    #[cfg_attr(feature="serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub(crate) offset: Option<f64>,
+    #[cfg_attr(feature="serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub(crate) kind: Option<QuantityKind>
 }
 impl Eq for UnitDefinition{}
 impl UnitDefinition
@@ -479,34 +671,98 @@ impl UnitDefinition
     #[doc="Create a new `UnitDefinition` manually by specifying powers of each base unit, as well as the multiplier."]      
     pub fn new(multiplier: f64, meter: i8, kilogram: i8, second: i8, ampere: i8, kelvin: i8, mole: i8, candela: i8) -> Self
     {
-        UnitDefinition { base: UnitBase::default().with_meter(meter.into()).with_kilogram(kilogram.into()).with_second(second.into()).with_ampere(ampere.into()).with_kelvin(kelvin.into()).with_mole(mole.into()).with_candela(candela.into()), multiplier }
+        UnitDefinition { base: UnitBase::default().with_meter(meter.into()).with_kilogram(kilogram.into()).with_second(second.into()).with_ampere(ampere.into()).with_kelvin(kelvin.into()).with_mole(mole.into()).with_candela(candela.into()), multiplier, offset: None, kind: None }
     }
     #[doc="Returns a dimensionless `UnitDefinition`."]      
     pub fn dimensionless() -> Self
     {
-        UnitDefinition { base: UnitBase::default(), multiplier: 1.0 }
+        UnitDefinition { base: UnitBase::default(), multiplier: 1.0, offset: None, kind: None }
+    }
+    #[doc="Return a copy of this unit on an absolute scale, with the given `offset` (in this unit) to the zero of the base unit."]
+    pub fn with_offset(&self, offset: f64) -> Self
+    {
+        UnitDefinition { offset: Some(offset), ..*self }
+    }
+    #[doc="Return a copy of this unit with the power of a given base `dimension` replaced (e.g. `BaseDimension::Count` for currencies, with the `wide_dimensions` feature)."]
+    pub fn with_power(&self, dimension: BaseDimension, power: Rational8) -> Self
+    {
+        UnitDefinition { base: self.base.with_power(dimension, power), ..*self }
+    }
+    #[doc="Return a copy of this unit with the given `kind` (e.g. to treat a torque as an energy, or a ratio as an angle)."]
+    pub fn as_kind(&self, kind: Option<QuantityKind>) -> Self
+    {
+        UnitDefinition { kind, ..*self }
+    }
+    #[doc="Retrieve the kind of the quantity of this unit, or `None` if it is compatible with any kind."]
+    pub fn kind(&self) -> Option<QuantityKind>
+    {
+        self.kind
+    }
+    #[doc="Check whether the kinds of this unit and a given `unit` are compatible (i.e. equal, or either has no kind)."]
+    pub fn is_same_kind(&self, unit: UnitDefinition) -> bool
+    {
+        match (self.kind, unit.kind)
+        {
+            (Some(kind), Some(other)) => kind == other,
+            _ => true
+        }
     }
     #[doc="Check whether this unit can be converted to a a given `unit`."]      
     pub fn is_convertible(&self, unit: UnitDefinition) -> bool
     {
-        self.base == unit.base
+        self.base == unit.base && self.is_absolute() == unit.is_absolute() && self.is_same_kind(unit)
     }
-    #[doc="Raise a unit to an integer power."]
+    #[doc="Raise a unit to an integer power. Panics if a resulting power cannot be represented, see `checked_powi`."]
     pub fn powi(&self, power: i8) -> UnitDefinition
     {
-        UnitDefinition { base: self.base.powi(power), multiplier: self.multiplier.powi(power as i32) }
+        self.checked_powi(power).unwrap_or_else(|error| panic!("{error}"))
     }
 
-    #[doc="Raise a unit to an floating point power."]
+    #[doc="Raise a unit to an floating point power. Panics if a resulting power cannot be represented, see `checked_powf`."]
     pub fn powf(&self, power: f64) -> UnitDefinition
     {        
-        UnitDefinition { base: self.base.powf(power), multiplier: self.multiplier.powf(power) }
+        self.checked_powf(power).unwrap_or_else(|error| panic!("{error}"))
+    }
+    #[doc="Raise a unit to an integer power, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented."]
+    pub fn checked_powi(&self, power: i8) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        Ok(UnitDefinition { base: self.base.checked_powi(power)?, multiplier: self.multiplier.powi(power as i32), offset: None, kind: if power == 0 { None } else { self.kind.filter(QuantityKind::is_propagated) } })
+    }
+    #[doc="Raise a unit to a floating point power, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented."]
+    pub fn checked_powf(&self, power: f64) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        Ok(UnitDefinition { base: self.base.checked_powf(power)?, multiplier: self.multiplier.powf(power), offset: None, kind: if power == 0.0 { None } else { self.kind.filter(QuantityKind::is_propagated) } })
+    }
+    #[doc="Multiply two units, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented."]
+    pub fn checked_mul(&self, rhs: UnitDefinition) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        Ok(UnitDefinition { multiplier: self.multiplier*rhs.multiplier, base: self.base.checked_mul(rhs.base)?, offset: None, kind: combined_kind(self.kind, rhs.kind, false) })
+    }
+    #[doc="Divide two units, failing with `RuntimeUnitError::ExponentOverflow` if a resulting power cannot be represented."]
+    pub fn checked_div(&self, rhs: UnitDefinition) -> Result<UnitDefinition, RuntimeUnitError>
+    {
+        Ok(UnitDefinition { multiplier: self.multiplier/rhs.multiplier, base: self.base.checked_div(rhs.base)?, offset: None, kind: combined_kind(self.kind, rhs.kind, true) })
     }
     #[doc="Retrieve multiplier that converts this unit to its base quantity."]
     pub fn multiplier(&self) -> f64
     {
         self.multiplier
     }
+    #[doc="Retrieve the offset of an absolute unit (e.g. 273.15 for degrees Celsius), or `None` for relative units."]
+    pub fn offset(&self) -> Option<f64>
+    {
+        self.offset
+    }
+    #[doc="Check whether this unit is on an absolute scale (e.g. thermodynamic temperature)."]
+    pub fn is_absolute(&self) -> bool
+    {
+        self.offset.is_some()
+    }
+    #[doc="Return the relative unit used for differences of this unit (e.g. degrees Celsius -> Celsius intervals)."]
+    pub fn interval(&self) -> UnitDefinition
+    {
+        UnitDefinition { offset: None, ..*self }
+    }
     #[doc="Get the string representation of the base unit."]
     pub fn unit_string(&self) -> String
     {
@@ -515,18 +771,26 @@ impl UnitDefinition
     #[doc="Approximate equality for two units, given some relative error `rel_error`"]
     pub fn approx_eq(&self, other: UnitDefinition, rel_error: f64) -> bool
     {
-        other.base == self.base && if self.multiplier == 0.0 { (self.multiplier-other.multiplier).abs()} else { (1.0-other.multiplier/self.multiplier).abs() } <= rel_error 
+        other.base == self.base && other.offset == self.offset && self.is_same_kind(other) && if self.multiplier == 0.0 { (self.multiplier-other.multiplier).abs()} else { (1.0-other.multiplier/self.multiplier).abs() } <= rel_error 
     }
-    #[doc="Compute the conversion factor required to convert current `UnitDefinition` to another"]
+    #[doc="Compute the conversion factor required to convert current `UnitDefinition` to another. Offsets of absolute units are not included, see `convert_value_unchecked`."]
     pub fn try_convert(&self, unit: Self) -> Result<f64, RuntimeUnitError>
     {
-        if unit.base == self.base
+        if self.is_convertible(unit)
         {
             Ok(self.convert_unchecked(unit))
         }
         else
         {
-            Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from base units of {} to {}", self.unit_string(), unit.unit_string())))
+            if self.base != unit.base
+            {
+                return Err(RuntimeUnitError::IncompatibleDimensions { from: self.base, to: unit.base });
+            }
+            if !self.is_same_kind(unit)
+            {
+                return Err(RuntimeUnitError::IncompatibleKinds { from: self.kind, to: unit.kind });
+            }
+            Err(RuntimeUnitError::AbsoluteRelativeMismatch { from: *self, to: unit })
         }
     }
     #[doc="Compute the conversion factor required to convert current `UnitDefinition` to another"]
@@ -541,10 +805,70 @@ impl UnitDefinition
             self.multiplier / unit.multiplier()
         }
     }
+    #[doc="Compute the scale and shift (`value * scale + shift`) required to convert a value in the current `UnitDefinition` to another, including offsets of absolute units."]
+    #[inline]
+    pub fn affine_conversion_unchecked(&self, unit: Self) -> (f64, f64)
+    {
+        let scale = self.convert_unchecked(unit);
+        (scale, self.offset.unwrap_or(0.0) * scale - unit.offset.unwrap_or(0.0))
+    }
+    #[doc="Convert a `value` expressed in the current `UnitDefinition` to another, including offsets of absolute units (no check of unit compatibility is made)."]
+    #[inline]
+    pub fn convert_value_unchecked(&self, value: f64, unit: Self) -> f64
+    {
+        if *self == unit
+        {
+            value
+        }
+        else
+        {
+            let (scale, shift) = self.affine_conversion_unchecked(unit);
+            value * scale + shift
+        }
+    }
+    /// Conversion factor from `rhs` to this unit for adding or subtracting quantities component-wise (e.g. complex or
+    /// vector quantities), failing if the units are incompatible or either is absolute.
+    pub(crate) fn additive_factor(&self, rhs: UnitDefinition) -> Result<f64, RuntimeUnitError>
+    {
+        if self.is_absolute() || rhs.is_absolute()
+        {
+            let absolute = if rhs.is_absolute() { rhs } else { *self };
+            return Err(RuntimeUnitError::AbsoluteRelativeMismatch { from: absolute, to: absolute.interval() });
+        }
+        rhs.try_convert(*self)
+    }
+    /// Convert a `value` expressed in this unit to a value measured from the zero of its scale, in the relative unit
+    /// `interval()` (e.g. 20 °C -> 293.15). Products, quotients and powers of quantities are computed from such values, as
+    /// their units are relative.
+    #[inline]
+    pub(crate) fn relative_value<T: Numeric>(&self, value: T) -> T
+    {
+        match self.offset
+        {
+            Some(offset) => value.scale(1.0, offset),
+            None => value
+        }
+    }
+    /// Convert a `value` expressed in this unit to the base unit.
+    #[inline]
+    pub(crate) fn base_value(&self, value: f64) -> f64
+    {
+        (value + self.offset.unwrap_or(0.0)) * self.multiplier
+    }
     #[doc="Compute inverse of the current units"]
     pub fn inv(&self) -> Self
     {
-        Self { base: self.base.inv(), multiplier: 1.0 /self.multiplier }
+        Self { base: self.base.inv(), multiplier: 1.0 /self.multiplier, offset: None, kind: self.kind.filter(QuantityKind::is_propagated) }
+    }
+}
+/// Kind of the product (or quotient) of two units of the given kinds.
+fn combined_kind(lhs: Option<QuantityKind>, rhs: Option<QuantityKind>, quotient: bool) -> Option<QuantityKind>
+{
+    match (lhs.filter(QuantityKind::is_propagated), rhs.filter(QuantityKind::is_propagated))
+    {
+        (Some(kind), None) | (None, Some(kind)) => Some(kind),
+        (Some(lhs), Some(rhs)) if lhs == rhs && !quotient => Some(lhs),
+        _ => None
     }
 }
 impl Mul<UnitDefinition> for UnitDefinition
@@ -552,10 +876,7 @@ impl Mul<UnitDefinition> for UnitDefinition
     type Output = UnitDefinition;
 
     fn mul(self, rhs: UnitDefinition) -> Self::Output {
-        UnitDefinition{
-            multiplier: self.multiplier*rhs.multiplier,
-            base: self.base*rhs.base
-        }
+        self.checked_mul(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 impl Div<UnitDefinition> for UnitDefinition
@@ -563,26 +884,21 @@ impl Div<UnitDefinition> for UnitDefinition
     type Output=UnitDefinition;
 
     fn div(self, rhs: UnitDefinition) -> Self::Output {
-        UnitDefinition{
-            multiplier: self.multiplier/rhs.multiplier,
-            base: self.base/rhs.base
-        }
+        self.checked_div(rhs).unwrap_or_else(|error| panic!("{error}"))
     }
 }
 
 impl DivAssign for UnitDefinition
 {
     fn div_assign(&mut self, rhs: UnitDefinition) {
-        self.multiplier /= rhs.multiplier;
-        self.base /= rhs.base;
+        *self = *self / rhs;
     }
 }
 
 impl MulAssign for UnitDefinition
 {
     fn mul_assign(&mut self, rhs: Self) {
-        self.multiplier *= rhs.multiplier;
-        self.base *=  rhs.base
+        *self = *self * rhs;
     }
 }
 
@@ -600,10 +916,41 @@ pub(crate) fn to_unit_base(value: (f64, f64, f64, f64, f64, f64, f64)) -> UnitBa
     with_candela(value.6.into())
 }
 
+/// Add the base dimensions of angles and information to the `base` of a quantity of a given `kind` (e.g. rad/s for an
+/// angular velocity), with the `wide_dimensions` feature. Without it, these quantities are only distinguished by their kind.
+pub(crate) fn with_kind_dimensions(base: UnitBase, kind: Option<QuantityKind>) -> UnitBase
+{
+    #[cfg(feature="wide_dimensions")]
+    match kind
+    {
+        Some(QuantityKind::Angle) => return base.with_radian(Rational8::unity()),
+        Some(QuantityKind::SolidAngle) => return base.with_radian(Rational8::from(2i8)),
+        Some(QuantityKind::Information) => return base.with_bit(Rational8::unity()),
+        _ => ()
+    }
+    #[cfg(not(feature="wide_dimensions"))]
+    let _ = kind;
+    base
+}
+
 
 impl Display for UnitDefinition
 {
+    /// Display the base units, or the name of a matching unit in the global registry when no built-in unit matches. Absolute
+    /// units (e.g. degrees Celsius) display the abbreviation of the matching built-in unit, or their base units and offset.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.base)
+        if let Some(offset) = self.offset
+        {
+            return match crate::Units::find(*self, 1e-12)
+            {
+                Some(unit) => write!(f, "{}", unit.abbreviation()),
+                None => write!(f, "{} (offset {})", self.base, offset)
+            };
+        }
+        match crate::registry::global().display_name(*self)
+        {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{}", self.base)
+        }
     }
 }
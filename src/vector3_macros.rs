@@ -0,0 +1,218 @@
+#[macro_export]
+macro_rules! create_vector3_quantity {
+    ($quantity:ident) =>
+    {
+        paste::paste!
+        {
+        use $crate::vector3_quantity::Vector3Quantity;
+        #[derive(Copy, Clone, Debug)]
+        #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+        #[doc = "Three components (e.g. a position or a force) and [`" [<$quantity Unit>]"`]."]
+        pub struct [<$quantity Vector3>]
+        {
+            pub(crate) value: [f64; 3],
+            pub(crate) unit: [<$quantity Unit>]
+        }
+
+        impl [<$quantity Vector3>]
+        {
+            #[doc = "Create a new [`" [<$quantity Vector3>]"`]."]
+            pub fn new(value: [f64; 3], unit: [<$quantity Unit>]) -> Self
+            {
+                Self { value, unit }
+            }
+
+            #[doc = "Create a new [`" [<$quantity Vector3>]"`] from its components, in the units of `x`."]
+            pub fn from_components(x: $quantity, y: $quantity, z: $quantity) -> Self
+            {
+                Self { value: [x.value, y.convert(x.unit).value, z.convert(x.unit).value], unit: x.unit }
+            }
+
+            #[doc = "Retrieve the components associated with this [`" [<$quantity Vector3>]"`]."]
+            #[inline]
+            pub fn value(&self) -> [f64; 3]
+            {
+                self.value
+            }
+
+            #[doc = "Retrieve the unit associated with this [`" [<$quantity Vector3>]"`]."]
+            #[inline]
+            pub fn unit(&self) -> [<$quantity Unit>]
+            {
+                self.unit
+            }
+
+            #[doc = "First component of this [`" [<$quantity Vector3>]"`]."]
+            #[inline]
+            pub fn x(&self) -> $quantity
+            {
+                $quantity::new(self.value[0], self.unit)
+            }
+
+            #[doc = "Second component of this [`" [<$quantity Vector3>]"`]."]
+            #[inline]
+            pub fn y(&self) -> $quantity
+            {
+                $quantity::new(self.value[1], self.unit)
+            }
+
+            #[doc = "Third component of this [`" [<$quantity Vector3>]"`]."]
+            #[inline]
+            pub fn z(&self) -> $quantity
+            {
+                $quantity::new(self.value[2], self.unit)
+            }
+
+            #[doc = "Euclidean norm (magnitude) of this [`" [<$quantity Vector3>]"`]."]
+            #[inline]
+            pub fn norm(&self) -> $quantity
+            {
+                $quantity::new($crate::vector3_quantity::norm(self.value), self.unit)
+            }
+
+            #[doc = "Dot product with another vector quantity, in the product of their units."]
+            pub fn dot(&self, rhs: impl Into<Vector3Quantity>) -> Quantity
+            {
+                Vector3Quantity::from(*self).dot(rhs)
+            }
+
+            #[doc = "Cross product with another vector quantity, in the product of their units (e.g. a `LengthVector3` crossed with a `ForceVector3` converts to a `TorqueVector3`)."]
+            pub fn cross(&self, rhs: impl Into<Vector3Quantity>) -> Vector3Quantity
+            {
+                Vector3Quantity::from(*self).cross(rhs)
+            }
+
+            #[doc = "Convert to another [`" [<$quantity Unit>]"`] (offsets of absolute units are not applied)."]
+            pub fn convert(&self, unit: [<$quantity Unit>]) -> Self
+            {
+                let factor = UnitDefinition::from(self.unit).convert_unchecked(unit.into());
+                Self { value: self.value.map(|value| value * factor), unit }
+            }
+
+            #[doc = "Create a new [`" [<$quantity Vector3>]"`] from a `nalgebra` vector."]
+            #[cfg(feature="nalgebra")]
+            pub fn from_nalgebra(vector: nalgebra::Vector3<f64>, unit: [<$quantity Unit>]) -> Self
+            {
+                Self { value: vector.into(), unit }
+            }
+
+            #[doc = "Components of this [`" [<$quantity Vector3>]"`] as a `nalgebra` vector."]
+            #[cfg(feature="nalgebra")]
+            pub fn to_nalgebra(&self) -> nalgebra::Vector3<f64>
+            {
+                self.value.into()
+            }
+
+            #[doc = "Create a new [`" [<$quantity Vector3>]"`] from a `glam` vector."]
+            #[cfg(feature="glam")]
+            pub fn from_glam(vector: glam::DVec3, unit: [<$quantity Unit>]) -> Self
+            {
+                Self { value: vector.to_array(), unit }
+            }
+
+            #[doc = "Components of this [`" [<$quantity Vector3>]"`] as a `glam` vector."]
+            #[cfg(feature="glam")]
+            pub fn to_glam(&self) -> glam::DVec3
+            {
+                glam::DVec3::from_array(self.value)
+            }
+        }
+
+        impl From<[<$quantity Vector3>]> for Vector3Quantity
+        {
+            fn from(quantity: [<$quantity Vector3>]) -> Self
+            {
+                Vector3Quantity::new(quantity.value, quantity.unit.into())
+            }
+        }
+
+        impl TryFrom<Vector3Quantity> for [<$quantity Vector3>]
+        {
+            type Error = RuntimeUnitError;
+            fn try_from(quantity: Vector3Quantity) -> Result<Self, Self::Error>
+            {
+                Ok(Self { value: quantity.value, unit: [<$quantity Unit>]::try_from(quantity.unit)? })
+            }
+        }
+
+        impl core::ops::Add for [<$quantity Vector3>]
+        {
+            type Output = [<$quantity Vector3>];
+
+            /// Panics on absolute units (e.g. degrees Celsius).
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { value: (Vector3Quantity::from(self) + Vector3Quantity::from(rhs)).value, unit: self.unit }
+            }
+        }
+
+        impl core::ops::Sub for [<$quantity Vector3>]
+        {
+            type Output = [<$quantity Vector3>];
+
+            /// Panics on absolute units (e.g. degrees Celsius).
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { value: (Vector3Quantity::from(self) - Vector3Quantity::from(rhs)).value, unit: self.unit }
+            }
+        }
+
+        impl core::ops::Neg for [<$quantity Vector3>]
+        {
+            type Output = [<$quantity Vector3>];
+
+            fn neg(self) -> Self::Output {
+                Self { value: self.value.map(|value| -value), unit: self.unit }
+            }
+        }
+
+        impl core::ops::Mul<f64> for [<$quantity Vector3>]
+        {
+            type Output = [<$quantity Vector3>];
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                Self { value: self.value.map(|value| value * rhs), unit: self.unit }
+            }
+        }
+
+        impl core::ops::Div<f64> for [<$quantity Vector3>]
+        {
+            type Output = [<$quantity Vector3>];
+
+            fn div(self, rhs: f64) -> Self::Output {
+                Self { value: self.value.map(|value| value / rhs), unit: self.unit }
+            }
+        }
+
+        impl<Q: $crate::traits::IsScalarQuantity<Value = f64>> core::ops::Mul<Q> for [<$quantity Vector3>]
+        {
+            type Output = Vector3Quantity;
+
+            fn mul(self, rhs: Q) -> Self::Output {
+                Vector3Quantity::from(self) * rhs
+            }
+        }
+
+        impl<Q: $crate::traits::IsScalarQuantity<Value = f64>> core::ops::Div<Q> for [<$quantity Vector3>]
+        {
+            type Output = Vector3Quantity;
+
+            fn div(self, rhs: Q) -> Self::Output {
+                Vector3Quantity::from(self) / rhs
+            }
+        }
+
+        impl PartialEq for [<$quantity Vector3>]
+        {
+            fn eq(&self, other: &Self) -> bool {
+                Vector3Quantity::from(*self) == Vector3Quantity::from(*other)
+            }
+        }
+
+        impl core::fmt::Display for [<$quantity Vector3>]
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "({}, {}, {}) {}", self.value[0], self.value[1], self.value[2], self.unit.abbreviation())
+            }
+        }
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//! Quantities with three components, e.g. positions, velocities and forces.
+//!
+//! `Vector3Quantity` holds three `f64` components and a `UnitDefinition`, and vector quantities (e.g. lengths, velocities,
+//! forces, torques and fields) have a typed variant holding their unit enumeration (e.g. `LengthVector3`). Dot products yield a `Quantity` and cross products a `Vector3Quantity`
+//! with the product of the units (e.g. a position crossed with a force is a torque). Conversions scale the components by
+//! the unit multipliers: offsets of absolute units (e.g. degrees Celsius) are not applied.
+//!
+//! The `nalgebra` and `glam` features convert the components to and from `nalgebra::Vector3<f64>` and `glam::DVec3`.
+use core::fmt::Display;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::errors::RuntimeUnitError;
+use crate::quantity::Quantity;
+use crate::traits::{CheckedAdditive, IsScalarQuantity};
+use crate::units_base::UnitDefinition;
+
+/// Dot product of the components of two vectors.
+pub(crate) fn dot(lhs: [f64; 3], rhs: [f64; 3]) -> f64
+{
+    lhs[0] * rhs[0] + lhs[1] * rhs[1] + lhs[2] * rhs[2]
+}
+
+/// Cross product of the components of two vectors.
+pub(crate) fn cross(lhs: [f64; 3], rhs: [f64; 3]) -> [f64; 3]
+{
+    [lhs[1] * rhs[2] - lhs[2] * rhs[1], lhs[2] * rhs[0] - lhs[0] * rhs[2], lhs[0] * rhs[1] - lhs[1] * rhs[0]]
+}
+
+/// Euclidean norm of the components of a vector.
+pub(crate) fn norm(value: [f64; 3]) -> f64
+{
+    dot(value, value).sqrt()
+}
+
+#[doc = "A quantity with three components (e.g. a position or a force), supports converting from one unit to another."]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug)]
+pub struct Vector3Quantity
+{
+    pub(crate) value: [f64; 3],
+    pub(crate) unit: UnitDefinition
+}
+
+impl Vector3Quantity
+{
+    ///
+    /// Create a new `Vector3Quantity` from its components in a given `unit`
+    ///
+    pub fn new(value: [f64; 3], unit: UnitDefinition) -> Self
+    {
+        Self { value, unit }
+    }
+
+    ///
+    /// Create a new `Vector3Quantity` from three scalar quantities, in the units of `x`. Fails if the units are incompatible.
+    ///
+    pub fn from_components(x: impl Into<Quantity>, y: impl Into<Quantity>, z: impl Into<Quantity>) -> Result<Self, RuntimeUnitError>
+    {
+        let x: Quantity = x.into();
+        let component = |quantity: Quantity| Ok::<f64, RuntimeUnitError>(quantity.value * quantity.unit.try_convert(x.unit)?);
+        Ok(Self { value: [x.value, component(y.into())?, component(z.into())?], unit: x.unit })
+    }
+
+    /// Components of this quantity.
+    #[inline]
+    pub fn value(&self) -> [f64; 3]
+    {
+        self.value
+    }
+
+    /// Unit of this quantity.
+    #[inline]
+    pub fn unit(&self) -> UnitDefinition
+    {
+        self.unit
+    }
+
+    /// First component of this quantity.
+    pub fn x(&self) -> Quantity
+    {
+        Quantity::new(self.value[0], self.unit)
+    }
+
+    /// Second component of this quantity.
+    pub fn y(&self) -> Quantity
+    {
+        Quantity::new(self.value[1], self.unit)
+    }
+
+    /// Third component of this quantity.
+    pub fn z(&self) -> Quantity
+    {
+        Quantity::new(self.value[2], self.unit)
+    }
+
+    /// Euclidean norm (magnitude) of this quantity.
+    pub fn norm(&self) -> Quantity
+    {
+        Quantity::new(norm(self.value), self.unit)
+    }
+
+    /// Dot product with another vector quantity, in the product of their units (e.g. force · displacement is a work).
+    /// Components of absolute units are taken from their zero (e.g. 20 °C as 293.15).
+    pub fn dot(&self, rhs: impl Into<Vector3Quantity>) -> Quantity
+    {
+        let rhs = rhs.into();
+        Quantity::new(dot(self.relative_value(), rhs.relative_value()), self.unit * rhs.unit)
+    }
+
+    /// Cross product with another vector quantity, in the product of their units (e.g. position × force is a torque).
+    /// Components of absolute units are taken from their zero (e.g. 20 °C as 293.15).
+    pub fn cross(&self, rhs: impl Into<Vector3Quantity>) -> Vector3Quantity
+    {
+        let rhs = rhs.into();
+        Vector3Quantity { value: cross(self.relative_value(), rhs.relative_value()), unit: self.unit * rhs.unit }
+    }
+
+    /// Convert to a given `unit`, failing if the units are incompatible.
+    pub fn try_convert(&self, unit: UnitDefinition) -> Result<Self, RuntimeUnitError>
+    {
+        let factor = self.unit.try_convert(unit)?;
+        Ok(Self { value: self.value.map(|value| value * factor), unit })
+    }
+
+    /// Convert to a given `unit` in place, failing if the units are incompatible.
+    pub fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), RuntimeUnitError>
+    {
+        *self = self.try_convert(unit)?;
+        Ok(())
+    }
+
+    /// Convert to a given `unit` (no check is made to ensure the destination unit is valid).
+    pub fn convert(&self, unit: UnitDefinition) -> Self
+    {
+        let factor = self.unit.convert_unchecked(unit);
+        Self { value: self.value.map(|value| value * factor), unit }
+    }
+
+    /// Create a new `Vector3Quantity` from a `nalgebra` vector in a given `unit`.
+    #[cfg(feature="nalgebra")]
+    pub fn from_nalgebra(vector: nalgebra::Vector3<f64>, unit: UnitDefinition) -> Self
+    {
+        Self { value: vector.into(), unit }
+    }
+
+    /// Components of this quantity as a `nalgebra` vector.
+    #[cfg(feature="nalgebra")]
+    pub fn to_nalgebra(&self) -> nalgebra::Vector3<f64>
+    {
+        self.value.into()
+    }
+
+    /// Create a new `Vector3Quantity` from a `glam` vector in a given `unit`.
+    #[cfg(feature="glam")]
+    pub fn from_glam(vector: glam::DVec3, unit: UnitDefinition) -> Self
+    {
+        Self { value: vector.to_array(), unit }
+    }
+
+    /// Components of this quantity as a `glam` vector.
+    #[cfg(feature="glam")]
+    pub fn to_glam(&self) -> glam::DVec3
+    {
+        glam::DVec3::from_array(self.value)
+    }
+
+    /// Components measured from the zero of an absolute unit, for products.
+    fn relative_value(&self) -> [f64; 3]
+    {
+        self.value.map(|value| self.unit.relative_value(value))
+    }
+
+    /// Components of `rhs` in the units of this quantity, for an addition or a subtraction.
+    fn additive_operand(&self, rhs: Vector3Quantity) -> Result<[f64; 3], RuntimeUnitError>
+    {
+        let factor = self.unit.additive_factor(rhs.unit)?;
+        Ok(rhs.value.map(|value| value * factor))
+    }
+}
+
+impl CheckedAdditive for Vector3Quantity
+{
+    /// Add two vector quantities (result in the units of this quantity). Quantities of absolute units cannot be added.
+    fn checked_add(self, rhs: Vector3Quantity) -> Result<Self, RuntimeUnitError> {
+        let rhs = self.additive_operand(rhs)?;
+        Ok(Self { value: [self.value[0] + rhs[0], self.value[1] + rhs[1], self.value[2] + rhs[2]], unit: self.unit })
+    }
+
+    /// Subtract two vector quantities (result in the units of this quantity). Quantities of absolute units cannot be subtracted.
+    fn checked_sub(self, rhs: Vector3Quantity) -> Result<Self, RuntimeUnitError> {
+        let rhs = self.additive_operand(rhs)?;
+        Ok(Self { value: [self.value[0] - rhs[0], self.value[1] - rhs[1], self.value[2] - rhs[2]], unit: self.unit })
+    }
+
+    fn try_add_assign(&mut self, rhs: Vector3Quantity) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_add(rhs)?;
+        Ok(())
+    }
+
+    fn try_sub_assign(&mut self, rhs: Vector3Quantity) -> Result<(), RuntimeUnitError> {
+        *self = self.checked_sub(rhs)?;
+        Ok(())
+    }
+}
+
+impl Add<Vector3Quantity> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    /// Add two vector quantities, see [`CheckedAdditive::checked_add`]. Panics on incompatible units.
+    fn add(self, rhs: Vector3Quantity) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl Sub<Vector3Quantity> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    /// Subtract two vector quantities, see [`CheckedAdditive::checked_sub`]. Panics on incompatible units.
+    fn sub(self, rhs: Vector3Quantity) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
+
+impl AddAssign for Vector3Quantity
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Vector3Quantity
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    fn neg(self) -> Self::Output {
+        Self { value: self.value.map(|value| -value), unit: self.unit }
+    }
+}
+
+impl Mul<f64> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self { value: self.value.map(|value| value * rhs), unit: self.unit }
+    }
+}
+
+impl Div<f64> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self { value: self.value.map(|value| value / rhs), unit: self.unit }
+    }
+}
+
+impl MulAssign<f64> for Vector3Quantity
+{
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Vector3Quantity
+{
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<Quantity> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    /// Scale by a scalar quantity (e.g. an acceleration times a mass is a force).
+    fn mul(self, rhs: Quantity) -> Self::Output {
+        let rhs_value = rhs.relative_value();
+        Self { value: self.value.map(|value| self.unit.relative_value(value) * rhs_value), unit: self.unit * rhs.unit }
+    }
+}
+
+impl Div<Quantity> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    /// Divide by a scalar quantity (e.g. a displacement divided by a time is a velocity).
+    fn div(self, rhs: Quantity) -> Self::Output {
+        let rhs_value = rhs.relative_value();
+        Self { value: self.value.map(|value| self.unit.relative_value(value) / rhs_value), unit: self.unit / rhs.unit }
+    }
+}
+
+impl<Q: IsScalarQuantity<Value = f64>> Mul<Q> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    fn mul(self, rhs: Q) -> Self::Output {
+        self * Quantity::new(rhs.value(), rhs.unit())
+    }
+}
+
+impl<Q: IsScalarQuantity<Value = f64>> Div<Q> for Vector3Quantity
+{
+    type Output = Vector3Quantity;
+
+    fn div(self, rhs: Q) -> Self::Output {
+        self / Quantity::new(rhs.value(), rhs.unit())
+    }
+}
+
+impl PartialEq for Vector3Quantity
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.unit.is_convertible(other.unit) && self.convert(other.unit).value == other.value
+    }
+}
+
+impl Display for Vector3Quantity
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {}) {}", self.value[0], self.value[1], self.value[2], self.unit)
+    }
+}
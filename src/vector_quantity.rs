@@ -1,4 +1,4 @@
-use crate::{errors::RuntimeUnitError, units_base::UnitDefinition};
+use crate::{errors::RuntimeUnitError, traits::{CheckedAdditive, Numeric}, units_base::UnitDefinition};
 use core::ops::{AddAssign, Div, DivAssign, Mul, MulAssign, SubAssign };
 use std::ops::{Deref, DerefMut};
 
@@ -6,28 +6,70 @@ use std::ops::{Deref, DerefMut};
 #[derive(Clone)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 #[doc ="Data structure to hold a unit and array of data"]
-pub struct VecQuantity
+pub struct VecQuantity<T = f64>
 {
     pub unit: UnitDefinition,
-    pub values: Vec<f64>
+    pub values: Vec<T>
 }
 
-impl Deref for VecQuantity
+impl<T: Numeric> Deref for VecQuantity<T>
 {
-    type Target = Vec<f64>;
-    fn deref(&self) -> &Vec<f64> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Vec<T> {
         &self.values
     }
 }
-impl DerefMut for VecQuantity
+impl<T: Numeric> DerefMut for VecQuantity<T>
 {
-    fn deref_mut(&mut self) -> &mut Vec<f64> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
         &mut self.values
     }
 }
 
+impl<T: Numeric> VecQuantity<T>
+{
+    /// Multiply two quantities element-wise, failing if their lengths differ or if a resulting power of the units cannot be
+    /// represented.
+    pub fn checked_mul(&self, rhs: &VecQuantity<T>) -> Result<VecQuantity<T>, RuntimeUnitError>
+    {
+        self.zip_with(rhs, self.unit.checked_mul(rhs.unit)?, |lhs, rhs| lhs * rhs)
+    }
+
+    /// Divide two quantities element-wise, failing if their lengths differ or if a resulting power of the units cannot be
+    /// represented.
+    pub fn checked_div(&self, rhs: &VecQuantity<T>) -> Result<VecQuantity<T>, RuntimeUnitError>
+    {
+        self.zip_with(rhs, self.unit.checked_div(rhs.unit)?, |lhs, rhs| lhs / rhs)
+    }
+
+    ///
+    /// Convert to the `QuantitiesVec` variant matching the dimensions of this quantity, using the best matching named unit
+    /// (see [`crate::Units::find`]), or the base unit of the first matching quantity if no unit matches the multiplier.
+    ///
+    pub fn try_into_quantities_vec(&self) -> Result<crate::QuantitiesVec, RuntimeUnitError>
+    {
+        let unit = match crate::Units::find(self.unit, 1e-9)
+        {
+            Some(unit) => unit,
+            None => crate::UnitTypes::matching(self.unit).iter().map(crate::UnitTypes::base_unit)
+                .find(|&unit| UnitDefinition::from(unit).is_absolute() == self.unit.is_absolute())
+                .ok_or_else(|| RuntimeUnitError::IncompatibleUnitConversion(format!("No quantity found with base units of {}", self.unit.unit_string())))?
+        };
+        let (scale, shift) = self.unit.affine_conversion_unchecked(unit.into());
+        Ok(crate::QuantitiesVec::new(self.values.iter().map(|value| value.to_f64() * scale + shift).collect(), unit))
+    }
+
+    fn zip_with(&self, rhs: &VecQuantity<T>, unit: UnitDefinition, op: impl Fn(T, T) -> T) -> Result<VecQuantity<T>, RuntimeUnitError>
+    {
+        crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
+        // Values of absolute units are measured from the zero of their scale, as products and quotients are relative.
+        let values = self.values.iter().zip(&rhs.values).map(|(&lhs, &value)| op(self.unit.relative_value(lhs), rhs.unit.relative_value(value)));
+        Ok(VecQuantity { unit, values: values.collect() })
+    }
+}
+
 // Defining ArbitraryQuantity for our VecQuantity
-impl crate::traits::ArbitraryQuantity for VecQuantity
+impl<T: Numeric> crate::traits::ArbitraryQuantity for VecQuantity<T>
 {
     fn unit(&self) -> UnitDefinition {
         self.unit
@@ -41,35 +83,22 @@ impl crate::traits::ArbitraryQuantity for VecQuantity
     }
 
     fn convert_mut(&mut self, unit: UnitDefinition) {
-        let factor = self.unit.convert_unchecked(unit);
-        for val in self.values.as_mut_slice().iter_mut()
-        {
-            *val *= factor;
-        }            
+        let (scale, shift) = self.unit.affine_conversion_unchecked(unit);
+        crate::slice::scale_in_place(&mut self.values, scale, shift);
+        self.unit = unit;
     }
     
     fn try_convert(&self, unit: UnitDefinition) -> Result<Self, crate::errors::RuntimeUnitError> {
-        if self.unit.base != unit.base
-        {
-            Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from base units of {} to {}", self.unit.unit_string(), unit.unit_string())))
-        }
-        else
-        {
-            Ok(self.convert(unit))
-        }
-
+        self.unit.try_convert(unit)?;
+        crate::slice::check_representable(&self.values, self.unit, unit)?;
+        Ok(self.convert(unit))
     }
     
     fn try_convert_mut(&mut self, unit: UnitDefinition) -> Result<(), crate::errors::RuntimeUnitError> {
-        if self.unit.base != unit.base
-        {
-            Err(RuntimeUnitError::IncompatibleUnitConversion(format!("Could not convert from base units of {} to {}", self.unit.unit_string(), unit.unit_string())))
-        }
-        else
-        {
-            self.convert_mut(unit);
-            Ok(())
-        }
+        self.unit.try_convert(unit)?;
+        crate::slice::check_representable(&self.values, self.unit, unit)?;
+        self.convert_mut(unit);
+        Ok(())
     }
     
     fn unit_mut(&mut self) -> &mut UnitDefinition {
@@ -80,24 +109,24 @@ impl crate::traits::ArbitraryQuantity for VecQuantity
 
 
 
-impl Div<f64> for VecQuantity
+impl<T: Numeric> Div<f64> for VecQuantity<T>
 {
-    type Output = VecQuantity;
+    type Output = VecQuantity<T>;
 
     fn div(self, rhs: f64) -> Self::Output {
         let mut result = self.clone();
         for val in result.values.as_mut_slice()
         {  
-           *val /= rhs;
+           *val = val.div_f64(rhs);
         }
         result
     }
 }
-impl Div<VecQuantity> for VecQuantity
+impl<T: Numeric> Div<VecQuantity<T>> for VecQuantity<T>
 {
-    type Output = VecQuantity;
+    type Output = VecQuantity<T>;
 
-    fn div(self, rhs: VecQuantity) -> Self::Output {
+    fn div(self, rhs: VecQuantity<T>) -> Self::Output {
         let mut result = self.clone();
         if rhs.values.len() != self.values.len()
         {
@@ -105,16 +134,16 @@ impl Div<VecQuantity> for VecQuantity
         }
         for (val, &rhs) in result.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val /= rhs;
+           *val = *val / rhs;
         }
         result
     }
 }
-impl Mul<VecQuantity> for VecQuantity
+impl<T: Numeric> Mul<VecQuantity<T>> for VecQuantity<T>
 {
-    type Output = VecQuantity;
+    type Output = VecQuantity<T>;
 
-    fn mul(self, rhs: VecQuantity) -> Self::Output {
+    fn mul(self, rhs: VecQuantity<T>) -> Self::Output {
         let mut result = self.clone();
         if rhs.values.len() != self.values.len()
         {
@@ -122,86 +151,107 @@ impl Mul<VecQuantity> for VecQuantity
         }
         for (val, &rhs) in result.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val *= rhs;
+           *val = *val * rhs;
         }
         result
     }
 }
-impl Mul<f64> for VecQuantity
+impl<T: Numeric> Mul<f64> for VecQuantity<T>
 {
-    type Output = VecQuantity;
+    type Output = VecQuantity<T>;
 
     fn mul(self, rhs: f64) -> Self::Output {
         let mut result = self.clone();
         for val in result.values.as_mut_slice()
         {  
-           *val *= rhs;
+           *val = val.mul_f64(rhs);
         }
         result
     }
 }
-impl DivAssign<f64> for VecQuantity
+impl<T: Numeric> DivAssign<f64> for VecQuantity<T>
 {
 
     fn div_assign(&mut self, rhs: f64) {        
         for val in self.values.as_mut_slice()
         {  
-           *val /= rhs;
+           *val = val.div_f64(rhs);
         }
     }
 }
-impl MulAssign<f64> for VecQuantity
+impl<T: Numeric> MulAssign<f64> for VecQuantity<T>
 {
 
     fn mul_assign(&mut self, rhs: f64) {        
         for val in self.values.as_mut_slice()
         {  
-           *val *= rhs;
+           *val = val.mul_f64(rhs);
         }
     }
 }
-impl DivAssign<VecQuantity> for VecQuantity
+impl<T: Numeric> DivAssign<VecQuantity<T>> for VecQuantity<T>
 {
 
-    fn div_assign(&mut self, rhs: VecQuantity) {            
+    fn div_assign(&mut self, rhs: VecQuantity<T>) {            
         if rhs.values.len() != self.values.len()
         {
             panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
         }
         for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val /= rhs;
+           *val = *val / rhs;
         }
     }
 }
-impl AddAssign<VecQuantity> for VecQuantity
+impl<T: Numeric> CheckedAdditive for VecQuantity<T>
 {
+    fn checked_add(mut self, rhs: VecQuantity<T>) -> Result<Self, RuntimeUnitError> {
+        self.try_add_assign(rhs)?;
+        Ok(self)
+    }
 
-    fn add_assign(&mut self, rhs: VecQuantity) {            
-        if rhs.values.len() != self.values.len()
-        {
-            panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-        }
-        let factor = rhs.unit.try_convert(self.unit).unwrap_or_else(|_| panic!("Addition failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
+    fn checked_sub(mut self, rhs: VecQuantity<T>) -> Result<Self, RuntimeUnitError> {
+        self.try_sub_assign(rhs)?;
+        Ok(self)
+    }
+
+    fn try_add_assign(&mut self, rhs: VecQuantity<T>) -> Result<(), RuntimeUnitError> {
+        crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
+        let factor = rhs.unit.try_convert(self.unit.interval())?;
         for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val += rhs*factor;
+           *val = *val + rhs.mul_f64(factor);
         }
+        Ok(())
     }
-}
-impl SubAssign<VecQuantity> for VecQuantity
-{
 
-    fn sub_assign(&mut self, rhs: VecQuantity) {            
-        if rhs.values.len() != self.values.len()
-        {
-            panic!("Slice dimensions do not match: {} != {}", rhs.values.len(), self.values.len());
-        }
-        let factor = rhs.unit.try_convert(self.unit).unwrap_or_else(|_| panic!("Subtraction failed due to incompatible units `{}` and `{}`", self.unit, rhs.unit));
+    fn try_sub_assign(&mut self, rhs: VecQuantity<T>) -> Result<(), RuntimeUnitError> {
+        crate::slice::check_lengths(rhs.values.len(), self.values.len())?;
+        // The difference of two absolute quantities (e.g. temperatures) is relative.
+        let (target, unit) = if rhs.unit.is_absolute() { (self.unit, self.unit.interval()) } else { (self.unit.interval(), self.unit) };
+        rhs.unit.try_convert(target)?;
+        let (scale, shift) = rhs.unit.affine_conversion_unchecked(target);
         for (val, &rhs) in self.values.as_mut_slice().iter_mut().zip(rhs.values.as_slice())
         {  
-           *val -= rhs*factor;
+           *val = *val - rhs.scale(scale, shift);
         }
+        self.unit = unit;
+        Ok(())
+    }
+}
+
+impl<T: Numeric> AddAssign<VecQuantity<T>> for VecQuantity<T>
+{
+    /// Panics on incompatible units or lengths, see [`CheckedAdditive::try_add_assign`].
+    fn add_assign(&mut self, rhs: VecQuantity<T>) {            
+        self.try_add_assign(rhs).unwrap_or_else(|error| panic!("{error}"));
+    }
+}
+impl<T: Numeric> SubAssign<VecQuantity<T>> for VecQuantity<T>
+{
+    /// Panics on incompatible units or lengths, see [`CheckedAdditive::try_sub_assign`].
+    fn sub_assign(&mut self, rhs: VecQuantity<T>) {            
+        self.try_sub_assign(rhs).unwrap_or_else(|error| panic!("{error}"));
     }
 }
 